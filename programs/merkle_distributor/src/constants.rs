@@ -0,0 +1,5 @@
+pub const DISTRIBUTOR_SEED: &str = "MerkleDistributor";
+pub const TOKEN_VAULT_SEED: &str = "TokenVault";
+/// Maximum number of leaves of a distributor, bounded by the size of the claimed bitmap
+/// that can be allocated in a single instruction.
+pub const MAX_NUM_NODES: u64 = 8 * 8_192;
@@ -0,0 +1,5 @@
+mod claim;
+mod new_distributor;
+
+pub use claim::*;
+pub use new_distributor::*;
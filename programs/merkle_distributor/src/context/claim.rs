@@ -0,0 +1,160 @@
+use crate::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use num_traits::ToPrimitive;
+use ve_honey::program::VeHoney;
+use ve_honey::state::{Escrow, Locker};
+
+#[derive(Accounts)]
+pub struct Claim<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub claimant: Signer<'info>,
+    #[account(
+        mut,
+        has_one = locker @ ProtocolError::InvalidParams,
+        has_one = token_mint @ ProtocolError::InvalidMint,
+        has_one = token_vault @ ProtocolError::InvalidOwner,
+    )]
+    pub distributor: Box<Account<'info, MerkleDistributor>>,
+    #[account(mut)]
+    pub token_vault: Box<Account<'info, TokenAccount>>,
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// veHoney locker
+    #[account(mut)]
+    pub locker: Box<Account<'info, Locker>>,
+    /// CHECK: signer proving the CPI into the locker program comes from this program.
+    #[account(
+        seeds = [
+            ve_honey::constants::LOCK_AUTHORITY_SEED.as_bytes(),
+            locker.key().as_ref()
+        ],
+        bump
+    )]
+    pub lock_authority: UncheckedAccount<'info>,
+    /// CHECK: veHoney escrow of the claimant, created by the locker program if needed.
+    #[account(mut)]
+    pub escrow: UncheckedAccount<'info>,
+    /// CHECK: locked tokens of the escrow.
+    #[account(mut)]
+    pub locked_tokens: UncheckedAccount<'info>,
+    pub locker_program: Program<'info, VeHoney>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> Validate<'info> for Claim<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<'info> Claim<'info> {
+    pub fn process<'c>(
+        &mut self,
+        index: u64,
+        amount: u64,
+        duration: i64,
+        proof: Vec<[u8; 32]>,
+        lock_authority_bump: u8,
+        ra: &'c [AccountInfo<'info>],
+    ) -> Result<()> {
+        let leaf = merkle_proof::leaf_hash(index, &self.claimant.key(), amount, duration);
+        invariant!(
+            merkle_proof::verify(&proof, &self.distributor.root, leaf),
+            ProtocolError::InvalidProof
+        );
+        self.distributor.set_claimed(index, amount)?;
+
+        let lock_duration = self.lock_duration(duration)?;
+
+        let distributor = &self.distributor;
+        let seeds = distributor_seeds!(base = distributor.base, bump = distributor.bump);
+        let locker_key = self.locker.key();
+        let lock_authority_seeds =
+            lock_authority_seeds!(locker = locker_key, bump = lock_authority_bump);
+        let mut lock_authority = self.lock_authority.to_account_info();
+        lock_authority.is_signer = true;
+        let mut remaining_accounts = vec![lock_authority];
+        remaining_accounts.extend_from_slice(ra);
+
+        ve_honey::cpi::lock_for(
+            CpiContext::new(
+                self.locker_program.to_account_info(),
+                ve_honey::cpi::accounts::LockFor {
+                    payer: self.payer.to_account_info(),
+                    locker: self.locker.to_account_info(),
+                    escrow: self.escrow.to_account_info(),
+                    escrow_owner: self.claimant.to_account_info(),
+                    locked_tokens: self.locked_tokens.to_account_info(),
+                    token_mint: self.token_mint.to_account_info(),
+                    funder: self.distributor.to_account_info(),
+                    source_tokens: self.token_vault.to_account_info(),
+                    token_program: self.token_program.to_account_info(),
+                    associated_token_program: self.associated_token_program.to_account_info(),
+                    system_program: self.system_program.to_account_info(),
+                    rent: self.rent.to_account_info(),
+                },
+            )
+            .with_signer(&[&seeds[..], &lock_authority_seeds[..]])
+            .with_remaining_accounts(remaining_accounts),
+            amount,
+            lock_duration,
+        )?;
+
+        emit!(ClaimedEvent {
+            distributor: self.distributor.key(),
+            index,
+            claimant: self.claimant.key(),
+            amount,
+            duration: lock_duration,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Duration the claimed tokens are locked for.
+    ///
+    /// Leaves shorter than the `min_stake_duration` of the [Locker] are locked for the
+    /// minimum instead, and a claimant already locked for longer keeps their end time,
+    /// since the locker never shortens an [Escrow].
+    fn lock_duration(&self, duration: i64) -> Result<i64> {
+        let min_stake_duration = unwrap_int!(self.locker.params.min_stake_duration.to_i64());
+        let mut lock_duration = duration.max(min_stake_duration);
+
+        let escrow_info = self.escrow.to_account_info();
+        if !escrow_info.data_is_empty() {
+            let escrow = Account::<Escrow>::try_from(&escrow_info)?;
+            let remaining = unwrap_int!(escrow
+                .escrow_ends_at
+                .checked_sub(Clock::get()?.unix_timestamp));
+            lock_duration = lock_duration.max(remaining);
+        }
+
+        Ok(lock_duration)
+    }
+}
+
+#[event]
+/// Event called in [merkle_distributor::claim].
+pub struct ClaimedEvent {
+    /// The [MerkleDistributor] claimed from.
+    #[index]
+    pub distributor: Pubkey,
+    /// Index of the claimed leaf.
+    pub index: u64,
+    /// The claimant, owner of the [Escrow] the tokens are locked into.
+    #[index]
+    pub claimant: Pubkey,
+    /// Amount of tokens claimed.
+    pub amount: u64,
+    /// Duration the tokens are locked for.
+    pub duration: i64,
+    /// Timestamp for the event.
+    pub timestamp: i64,
+}
@@ -0,0 +1,106 @@
+use crate::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use ve_honey::state::Locker;
+
+#[derive(Accounts)]
+#[instruction(max_num_nodes: u64)]
+pub struct NewDistributor<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub base: Signer<'info>,
+    #[account(
+        init,
+        seeds = [
+            DISTRIBUTOR_SEED.as_bytes(),
+            base.key().as_ref()
+        ],
+        bump,
+        space = 8 + MerkleDistributor::space(max_num_nodes),
+        payer = payer
+    )]
+    pub distributor: Box<Account<'info, MerkleDistributor>>,
+    #[account(
+        init,
+        token::mint = token_mint,
+        token::authority = distributor,
+        seeds = [
+            TOKEN_VAULT_SEED.as_bytes(),
+            distributor.key().as_ref()
+        ],
+        bump,
+        payer = payer
+    )]
+    pub token_vault: Box<Account<'info, TokenAccount>>,
+    pub token_mint: Box<Account<'info, Mint>>,
+    pub locker: Box<Account<'info, Locker>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> Validate<'info> for NewDistributor<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.locker.token_mint,
+            self.token_mint,
+            ProtocolError::InvalidMint
+        );
+
+        Ok(())
+    }
+}
+
+impl<'info> NewDistributor<'info> {
+    pub fn process(
+        &mut self,
+        bump: u8,
+        max_num_nodes: u64,
+        max_total_claim: u64,
+        root: [u8; 32],
+    ) -> Result<()> {
+        invariant!(
+            max_num_nodes > 0 && max_num_nodes <= MAX_NUM_NODES,
+            ProtocolError::InvalidParams
+        );
+
+        let distributor = &mut self.distributor;
+        distributor.base = self.base.key();
+        distributor.bump = bump;
+        distributor.locker = self.locker.key();
+        distributor.token_mint = self.token_mint.key();
+        distributor.token_vault = self.token_vault.key();
+        distributor.root = root;
+        distributor.max_total_claim = max_total_claim;
+        distributor.max_num_nodes = max_num_nodes;
+        distributor.total_amount_claimed = 0;
+        distributor.num_nodes_claimed = 0;
+        distributor.claimed_bitmap = vec![0; MerkleDistributor::bitmap_len(max_num_nodes)];
+
+        emit!(NewDistributorEvent {
+            distributor: distributor.key(),
+            locker: distributor.locker,
+            root,
+            max_total_claim,
+            max_num_nodes,
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event called in [merkle_distributor::new_distributor].
+pub struct NewDistributorEvent {
+    /// The [MerkleDistributor] being created.
+    #[index]
+    pub distributor: Pubkey,
+    /// The locker claims are locked into.
+    pub locker: Pubkey,
+    /// Root of the merkle tree.
+    pub root: [u8; 32],
+    /// Maximum amount of tokens that can be claimed.
+    pub max_total_claim: u64,
+    /// Number of leaves of the tree.
+    pub max_num_nodes: u64,
+}
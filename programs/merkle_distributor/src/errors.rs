@@ -0,0 +1,16 @@
+use crate::*;
+
+#[error_code]
+pub enum ProtocolError {
+    InvalidParams = 100,
+    InvalidMint = 101,
+    InvalidOwner = 102,
+
+    InvalidProof = 200,
+    AlreadyClaimed = 201,
+    IndexOutOfBounds = 202,
+    ExceededMaxClaim = 203,
+    ExceededNumNodes = 204,
+
+    MathOverflow = 300,
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use vipers::*;
+
+pub mod constants;
+pub mod context;
+pub mod errors;
+pub mod merkle_proof;
+pub mod state;
+pub mod utils;
+
+use constants::*;
+use context::*;
+use errors::*;
+use state::*;
+
+declare_id!("HpZZafZSTe3tbNYGTQSh9S4WcTHiWJEKyNmN7piHnzik");
+
+#[program]
+pub mod merkle_distributor {
+    use super::*;
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn new_distributor(
+        ctx: Context<NewDistributor>,
+        max_num_nodes: u64,
+        max_total_claim: u64,
+        root: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.process(
+            unwrap_bump!(ctx, "distributor"),
+            max_num_nodes,
+            max_total_claim,
+            root,
+        )?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn claim<'info>(
+        ctx: Context<'_, '_, '_, 'info, Claim<'info>>,
+        index: u64,
+        amount: u64,
+        duration: i64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        ctx.accounts.process(
+            index,
+            amount,
+            duration,
+            proof,
+            unwrap_bump!(ctx, "lock_authority"),
+            ctx.remaining_accounts,
+        )?;
+        Ok(())
+    }
+}
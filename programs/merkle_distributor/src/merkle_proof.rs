@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+pub use ve_honey::merkle::{node_hash, verify, MerkleTree};
+
+/// Hash of a leaf of the distribution tree.
+pub fn leaf_hash(index: u64, claimant: &Pubkey, amount: u64, duration: i64) -> [u8; 32] {
+    hashv(&[
+        &index.to_le_bytes(),
+        claimant.as_ref(),
+        &amount.to_le_bytes(),
+        &duration.to_le_bytes(),
+    ])
+    .to_bytes()
+}
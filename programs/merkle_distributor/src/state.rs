@@ -0,0 +1,64 @@
+use crate::*;
+
+#[account]
+#[derive(Default)]
+pub struct MerkleDistributor {
+    /// Base key used to derive the distributor address.
+    pub base: Pubkey,
+    /// bump seed
+    pub bump: u8,
+    /// The veHONEY locker the claimed tokens are locked into.
+    pub locker: Pubkey,
+    /// Mint of the distributed token.
+    pub token_mint: Pubkey,
+    /// Token account holding the tokens to distribute.
+    pub token_vault: Pubkey,
+    /// Root of the merkle tree of (index, claimant, amount, duration) leaves.
+    pub root: [u8; 32],
+    /// Maximum amount of tokens that can be claimed.
+    pub max_total_claim: u64,
+    /// Number of leaves of the tree.
+    pub max_num_nodes: u64,
+    /// Total amount of tokens claimed.
+    pub total_amount_claimed: u64,
+    /// Number of leaves claimed.
+    pub num_nodes_claimed: u64,
+    /// One bit per leaf, set once the leaf is claimed.
+    pub claimed_bitmap: Vec<u8>,
+}
+
+impl MerkleDistributor {
+    pub const LEN: usize = 32 + 1 + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 8 + 4;
+
+    pub fn space(max_num_nodes: u64) -> usize {
+        Self::LEN + Self::bitmap_len(max_num_nodes)
+    }
+
+    pub fn bitmap_len(max_num_nodes: u64) -> usize {
+        ((max_num_nodes as usize) + 7) >> 3
+    }
+
+    pub fn is_claimed(&self, index: u64) -> bool {
+        self.claimed_bitmap[(index / 8) as usize] & (1 << (index % 8)) != 0
+    }
+
+    pub fn set_claimed(&mut self, index: u64, amount: u64) -> Result<()> {
+        invariant!(index < self.max_num_nodes, ProtocolError::IndexOutOfBounds);
+        invariant!(!self.is_claimed(index), ProtocolError::AlreadyClaimed);
+
+        self.claimed_bitmap[(index / 8) as usize] |= 1 << (index % 8);
+
+        self.total_amount_claimed = unwrap_int!(self.total_amount_claimed.checked_add(amount));
+        invariant!(
+            self.total_amount_claimed <= self.max_total_claim,
+            ProtocolError::ExceededMaxClaim
+        );
+        self.num_nodes_claimed = unwrap_int!(self.num_nodes_claimed.checked_add(1));
+        invariant!(
+            self.num_nodes_claimed <= self.max_num_nodes,
+            ProtocolError::ExceededNumNodes
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+#[macro_export]
+macro_rules! distributor_seeds {
+    (
+        base = $base:expr,
+        bump = $bump:expr
+    ) => {
+        &[
+            constants::DISTRIBUTOR_SEED.as_bytes(),
+            &$base.to_bytes()[..],
+            &[$bump],
+        ]
+    };
+}
+
+#[macro_export]
+macro_rules! lock_authority_seeds {
+    (
+        locker = $locker:expr,
+        bump = $bump:expr
+    ) => {
+        &[
+            ve_honey::constants::LOCK_AUTHORITY_SEED.as_bytes(),
+            &$locker.to_bytes()[..],
+            &[$bump],
+        ]
+    };
+}
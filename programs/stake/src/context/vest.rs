@@ -40,6 +40,15 @@ pub struct Vest<'info> {
     /// CHECK: veHoney locker
     #[account(mut)]
     pub locker: UncheckedAccount<'info>,
+    /// CHECK: signer proving the CPI into the locker program comes from this program.
+    #[account(
+        seeds = [
+            ve_honey::constants::LOCK_AUTHORITY_SEED.as_bytes(),
+            locker.key().as_ref()
+        ],
+        bump
+    )]
+    pub lock_authority: UncheckedAccount<'info>,
     /// CHECK: veHoney escrow that belongs to user wallets.
     #[account(mut)]
     pub escrow: UncheckedAccount<'info>,
@@ -87,6 +96,7 @@ impl<'info> Vest<'info> {
         &mut self,
         amount: u64,
         duration: i64,
+        lock_authority_bump: u8,
         ra: &'c [AccountInfo<'info>],
     ) -> Result<()> {
         invariant!(
@@ -125,6 +135,14 @@ impl<'info> Vest<'info> {
 
         self.token_vault.reload()?;
 
+        let locker_key = self.locker.key();
+        let lock_authority_seeds =
+            lock_authority_seeds!(locker = locker_key, bump = lock_authority_bump);
+        let mut lock_authority = self.lock_authority.to_account_info();
+        lock_authority.is_signer = true;
+        let mut remaining_accounts = vec![lock_authority];
+        remaining_accounts.extend_from_slice(ra);
+
         ve_honey::cpi::lock(
             CpiContext::new(
                 self.locker_program.to_account_info(),
@@ -138,8 +156,8 @@ impl<'info> Vest<'info> {
                     token_program: self.token_program.to_account_info(),
                 },
             )
-            .with_signer(&[&seeds[..]])
-            .with_remaining_accounts(ra.to_vec()),
+            .with_signer(&[&seeds[..], &lock_authority_seeds[..]])
+            .with_remaining_accounts(remaining_accounts),
             amount_to_mint,
             duration,
         )?;
@@ -77,8 +77,12 @@ pub mod stake {
         amount: u64,
         duration: i64,
     ) -> Result<()> {
-        ctx.accounts
-            .process(amount, duration, ctx.remaining_accounts)?;
+        ctx.accounts.process(
+            amount,
+            duration,
+            unwrap_bump!(ctx, "lock_authority"),
+            ctx.remaining_accounts,
+        )?;
         Ok(())
     }
 }
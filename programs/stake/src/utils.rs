@@ -30,6 +30,20 @@ macro_rules! authority_seeds {
     };
 }
 
+#[macro_export]
+macro_rules! lock_authority_seeds {
+    (
+        locker = $locker:expr,
+        bump = $bump:expr
+    ) => {
+        &[
+            ve_honey::constants::LOCK_AUTHORITY_SEED.as_bytes(),
+            &$locker.to_bytes()[..],
+            &[$bump],
+        ]
+    };
+}
+
 pub fn conversion_ratio(duration: i64) -> Result<u64> {
     match duration {
         // 3 months option 89 - 92 days
@@ -4,3 +4,24 @@ pub const TREASURY_SEED: &str = "Treasury";
 pub const PROOF_SEED: &str = "Proof";
 pub const NFT_RECEIPT_SEED: &str = "Receipt";
 pub const WHITELIST_ENTRY_SEED: &str = "LockerWhitelistEntry";
+pub const LOCK_AUTHORITY_SEED: &str = "LockAuthority";
+pub const NFT_VAULT_SEED: &str = "NftVault";
+pub const REWARD_SCHEDULE_SEED: &str = "RewardSchedule";
+pub const RECEIPT_LEDGER_SEED: &str = "ReceiptLedger";
+pub const CAMPAIGN_SEED: &str = "Campaign";
+pub const COLLECTION_BURNS_SEED: &str = "CollectionBurns";
+pub const ESCROW_BURNS_SEED: &str = "EscrowBurns";
+pub const WL_OFFER_SEED: &str = "WlOffer";
+pub const WL_REDEMPTION_SEED: &str = "WlRedemption";
+pub const VEST_TIERS_SEED: &str = "VestTiers";
+pub const ESCROW_TRANSFER_SEED: &str = "EscrowTransfer";
+pub const OPERATOR_APPROVAL_SEED: &str = "OperatorApproval";
+
+/// Basis points denominator of the NFT reward rates.
+pub const MAX_BPS: u16 = 10_000;
+/// Number of WL tokens minted against burning an NFT, unless its [Proof] overrides it.
+pub const DEFAULT_WL_TOKEN_AMOUNT: u64 = 1;
+/// Maximum number of segments of a [RewardSchedule].
+pub const MAX_REWARD_SEGMENTS: usize = 16;
+/// Maximum number of tiers of [VestTiers].
+pub const MAX_VEST_TIERS: usize = 8;
@@ -1,33 +1,77 @@
 pub mod activate_proposal;
+pub mod campaign;
 pub mod cast_vote;
 pub mod claim;
+pub mod claim_all;
+pub mod claim_and_relock;
 pub mod close_escrow;
 pub mod close_receipt;
+pub mod extend_lock;
 pub mod init_escrow;
 pub mod init_locker;
 pub mod init_treasury;
 pub mod lock;
+pub mod lock_cnft;
+pub mod lock_for;
 pub mod lock_nft;
+pub mod lock_nft_batch;
+pub mod lock_nft_custodial;
+pub mod lock_nft_to_ledger;
+pub mod migrate_escrow;
+pub mod migrate_locker;
+pub mod migrate_proof;
+pub mod migrate_receipt;
+pub mod migrate_whitelist_entry;
+pub mod operator_approval;
 pub mod proof;
+pub mod receipt_ledger;
+pub mod reward_schedule;
 pub mod set_params;
 pub mod set_vote_delegate;
+pub mod transfer_escrow;
+pub mod treasury;
 pub mod unlock;
+pub mod vest_tiers;
 pub mod whitelist;
+pub mod withdraw_nft;
+pub mod wl_offer;
 pub mod wl_token;
 
 pub use activate_proposal::*;
+pub use campaign::*;
 pub use cast_vote::*;
 pub use claim::*;
+pub use claim_all::*;
+pub use claim_and_relock::*;
 pub use close_escrow::*;
 pub use close_receipt::*;
+pub use extend_lock::*;
 pub use init_escrow::*;
 pub use init_locker::*;
 pub use init_treasury::*;
 pub use lock::*;
+pub use lock_cnft::*;
+pub use lock_for::*;
 pub use lock_nft::*;
+pub use lock_nft_batch::*;
+pub use lock_nft_custodial::*;
+pub use lock_nft_to_ledger::*;
+pub use migrate_escrow::*;
+pub use migrate_locker::*;
+pub use migrate_proof::*;
+pub use migrate_receipt::*;
+pub use migrate_whitelist_entry::*;
+pub use operator_approval::*;
 pub use proof::*;
+pub use receipt_ledger::*;
+pub use reward_schedule::*;
 pub use set_params::*;
 pub use set_vote_delegate::*;
+pub use transfer_escrow::*;
+pub use treasury::*;
 pub use unlock::*;
+pub use vest_tiers::*;
 pub use whitelist::*;
+pub use withdraw_nft::*;
+pub use wl_offer::*;
 pub use wl_token::*;
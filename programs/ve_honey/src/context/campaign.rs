@@ -0,0 +1,352 @@
+use crate::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+use anchor_spl::token::TokenAccount;
+use govern::Governor;
+use std::io::Cursor;
+
+#[derive(Accounts)]
+pub struct OpenCampaign<'info> {
+    /// payer of the initialization.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// the [Locker].
+    #[account(mut)]
+    pub locker: Box<Account<'info, Locker>>,
+    /// the [Campaign].
+    #[account(
+        init_if_needed,
+        seeds = [
+            CAMPAIGN_SEED.as_bytes(),
+            locker.key().as_ref(),
+        ],
+        bump,
+        space = 8 + Campaign::LEN,
+        payer = payer,
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+    /// treasury of the reward mint of the [Locker], backing the budget of the campaign.
+    #[account(
+        seeds = [
+            TREASURY_SEED.as_bytes(),
+            locker.key().as_ref(),
+            locker.reward_treasury_mint().as_ref(),
+        ],
+        bump,
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
+    /// the [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// the smart wallet on the [Governor].
+    pub smart_wallet: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> OpenCampaign<'info> {
+    /// Opens a new campaign, unless the current one is still open or paused, and reserves
+    /// its budget in the treasury. Burns counted in previous campaigns do not count against
+    /// the new one.
+    pub fn process(&mut self, params: CampaignParams) -> Result<()> {
+        params.validate()?;
+
+        let locker = &mut self.locker;
+        let reserved_rewards =
+            unwrap_int!(locker.reserved_rewards.checked_add(params.reward_budget));
+        invariant!(
+            self.treasury.amount >= reserved_rewards,
+            ProtocolError::InsufficientTreasuryFunds
+        );
+        locker.reserved_rewards = reserved_rewards;
+
+        let campaign = &mut self.campaign;
+        let initialized = campaign.locker != Pubkey::default();
+
+        invariant!(
+            !initialized || campaign.status == CampaignStatus::Closed,
+            ProtocolError::InvalidCampaignStatus
+        );
+
+        campaign.locker = locker.key();
+        campaign.campaign_id = unwrap_int!(campaign.campaign_id.checked_add(1));
+        campaign.status = CampaignStatus::Open;
+        campaign.params = params;
+        campaign.reward_spent = 0;
+        campaign.burn_count = 0;
+
+        emit!(CampaignStatusEvent {
+            locker: campaign.locker,
+            campaign_id: campaign.campaign_id,
+            status: campaign.status,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for OpenCampaign<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.locker.governor,
+            self.governor,
+            ProtocolError::GovernorMismatch
+        );
+        assert_keys_eq!(
+            self.governor.smart_wallet,
+            self.smart_wallet,
+            ProtocolError::SmartWalletMismatch
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct SetCampaignStatus<'info> {
+    /// the [Locker].
+    #[account(mut)]
+    pub locker: Box<Account<'info, Locker>>,
+    /// the [Campaign].
+    #[account(mut)]
+    pub campaign: Box<Account<'info, Campaign>>,
+    /// the [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// the smart wallet on the [Governor].
+    pub smart_wallet: Signer<'info>,
+}
+
+impl<'info> SetCampaignStatus<'info> {
+    /// Pauses an open campaign, resumes a paused one, or closes one that is not closed yet,
+    /// releasing the rest of its budget in the treasury.
+    pub fn process(&mut self, status: CampaignStatus) -> Result<()> {
+        let campaign = &mut self.campaign;
+
+        let valid = match status {
+            CampaignStatus::Open => campaign.status == CampaignStatus::Paused,
+            CampaignStatus::Paused => campaign.status == CampaignStatus::Open,
+            CampaignStatus::Closed => campaign.status != CampaignStatus::Closed,
+        };
+        invariant!(valid, ProtocolError::InvalidCampaignStatus);
+
+        if status == CampaignStatus::Closed {
+            let remaining_budget = unwrap_int!(campaign
+                .params
+                .reward_budget
+                .checked_sub(campaign.reward_spent));
+            let locker = &mut self.locker;
+            locker.reserved_rewards =
+                unwrap_int!(locker.reserved_rewards.checked_sub(remaining_budget));
+        }
+
+        campaign.status = status;
+
+        emit!(CampaignStatusEvent {
+            locker: campaign.locker,
+            campaign_id: campaign.campaign_id,
+            status,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for SetCampaignStatus<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.locker,
+            self.campaign.locker,
+            ProtocolError::InvalidLocker
+        );
+        assert_keys_eq!(
+            self.locker.governor,
+            self.governor,
+            ProtocolError::GovernorMismatch
+        );
+        assert_keys_eq!(
+            self.governor.smart_wallet,
+            self.smart_wallet,
+            ProtocolError::SmartWalletMismatch
+        );
+
+        Ok(())
+    }
+}
+
+/// Accounts counting an NFT lock against the [Campaign] of a [Locker], which may not exist.
+pub(crate) struct CampaignAccounts<'a, 'info> {
+    pub campaign: &'a AccountInfo<'info>,
+    pub collection_burns: &'a AccountInfo<'info>,
+    pub escrow_burns: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> CampaignAccounts<'a, 'info> {
+    /// Counts an NFT of `collection` paying `reward_amount` into `escrow` against the
+    /// campaign and its caps, creating its [BurnCounter]s on first use, and releases its
+    /// reward from the reserve of the [Locker]. Without a campaign, or once it is closed,
+    /// the lock is intentionally left unbounded, as documented on [Campaign].
+    pub fn record_burn(
+        &self,
+        locker: &mut Locker,
+        escrow: &Pubkey,
+        collection: &Pubkey,
+        reward_amount: u64,
+    ) -> Result<()> {
+        if self.campaign.owner != &crate::ID {
+            return Ok(());
+        }
+
+        let mut campaign = Account::<Campaign>::try_from(self.campaign)?;
+        if campaign.status == CampaignStatus::Closed {
+            return Ok(());
+        }
+
+        campaign.record_burn(reward_amount, Clock::get()?.unix_timestamp)?;
+        locker.reserved_rewards = unwrap_int!(locker.reserved_rewards.checked_sub(reward_amount));
+
+        let mut collection_burns = self.load_burn_counter(
+            self.collection_burns,
+            COLLECTION_BURNS_SEED,
+            &campaign,
+            collection,
+        )?;
+        collection_burns.burn_count = unwrap_int!(collection_burns.burn_count.checked_add(1));
+        invariant!(
+            collection_burns.burn_count <= campaign.params.collection_burn_cap,
+            ProtocolError::CollectionBurnCapReached
+        );
+        collection_burns.exit(&crate::ID)?;
+
+        let mut escrow_burns =
+            self.load_burn_counter(self.escrow_burns, ESCROW_BURNS_SEED, &campaign, escrow)?;
+        escrow_burns.burn_count = unwrap_int!(escrow_burns.burn_count.checked_add(1));
+        if let Some(escrow_burn_cap) = campaign.params.escrow_burn_cap {
+            invariant!(
+                escrow_burns.burn_count <= escrow_burn_cap,
+                ProtocolError::EscrowBurnCapReached
+            );
+        }
+        escrow_burns.exit(&crate::ID)?;
+
+        campaign.exit(&crate::ID)
+    }
+
+    /// The [BurnCounter] of `key` in the current campaign, created if it does not exist yet.
+    fn load_burn_counter(
+        &self,
+        info: &AccountInfo<'info>,
+        seed: &str,
+        campaign: &Account<'info, Campaign>,
+        key: &Pubkey,
+    ) -> Result<Account<'info, BurnCounter>> {
+        let campaign_key = campaign.key();
+        let campaign_id = campaign.campaign_id.to_le_bytes();
+        let (address, bump) = Pubkey::find_program_address(
+            &[
+                seed.as_bytes(),
+                campaign_key.as_ref(),
+                campaign_id.as_ref(),
+                key.as_ref(),
+            ],
+            &crate::ID,
+        );
+        assert_keys_eq!(address, info.key(), ProtocolError::InvalidBurnCounter);
+
+        if info.owner != &crate::ID {
+            let seeds: &[&[&[u8]]] = &[&[
+                seed.as_bytes(),
+                campaign_key.as_ref(),
+                campaign_id.as_ref(),
+                key.as_ref(),
+                &[bump],
+            ]];
+            self.create_account(info, 8 + BurnCounter::LEN, seeds)?;
+
+            let counter = BurnCounter {
+                campaign: campaign_key,
+                campaign_id: campaign.campaign_id,
+                key: *key,
+                burn_count: 0,
+            };
+            counter.try_serialize(&mut Cursor::new(&mut info.try_borrow_mut_data()?[..]))?;
+        }
+
+        Account::try_from(info)
+    }
+
+    /// Creates the program account `info` of `space` bytes at a PDA signed by `seeds`, even
+    /// if it was funded beforehand.
+    fn create_account(
+        &self,
+        info: &AccountInfo<'info>,
+        space: usize,
+        seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let rent = Rent::get()?.minimum_balance(space);
+
+        if info.lamports() == 0 {
+            return system_program::create_account(
+                CpiContext::new_with_signer(
+                    self.system_program.clone(),
+                    CreateAccount {
+                        from: self.payer.clone(),
+                        to: info.clone(),
+                    },
+                    seeds,
+                ),
+                rent,
+                space as u64,
+                &crate::ID,
+            );
+        }
+
+        let lamports = rent.saturating_sub(info.lamports());
+        if lamports > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    self.system_program.clone(),
+                    Transfer {
+                        from: self.payer.clone(),
+                        to: info.clone(),
+                    },
+                ),
+                lamports,
+            )?;
+        }
+
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                self.system_program.clone(),
+                Allocate {
+                    account_to_allocate: info.clone(),
+                },
+                seeds,
+            ),
+            space as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new_with_signer(
+                self.system_program.clone(),
+                Assign {
+                    account_to_assign: info.clone(),
+                },
+                seeds,
+            ),
+            &crate::ID,
+        )
+    }
+}
+
+#[event]
+/// Event called in [ve_honey::open_campaign], [ve_honey::pause_campaign],
+/// [ve_honey::resume_campaign] and [ve_honey::close_campaign].
+pub struct CampaignStatusEvent {
+    /// The [Locker].
+    #[index]
+    pub locker: Pubkey,
+    /// Id of the [Campaign].
+    pub campaign_id: u64,
+    /// New status of the [Campaign].
+    pub status: CampaignStatus,
+}
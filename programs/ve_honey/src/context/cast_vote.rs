@@ -54,20 +54,14 @@ impl<'info> CastVote<'info> {
     fn voting_power(&self) -> Result<u64> {
         self.escrow.voting_power(&self.locker.params)
     }
-}
 
-impl<'info> Validate<'info> for CastVote<'info> {
-    fn validate(&self) -> Result<()> {
+    /// Checks the accounts of the vote, apart from its signer.
+    fn assert_valid_vote(&self) -> Result<()> {
         assert_keys_eq!(
             self.escrow.locker,
             self.locker,
             ProtocolError::InvalidLocker
         );
-        assert_keys_eq!(
-            self.escrow.vote_delegate,
-            self.vote_delegate,
-            ProtocolError::InvalidVoteDelegate
-        );
         assert_keys_eq!(
             self.locker.governor,
             self.governor,
@@ -95,3 +89,44 @@ impl<'info> Validate<'info> for CastVote<'info> {
         Ok(())
     }
 }
+
+impl<'info> Validate<'info> for CastVote<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.escrow.vote_delegate,
+            self.vote_delegate,
+            ProtocolError::InvalidVoteDelegate
+        );
+        self.assert_valid_vote()
+    }
+}
+
+/// Vote with an [Escrow] as an operator approved to vote with it.
+#[derive(Accounts)]
+pub struct OperatorCastVote<'info> {
+    /// accounts of [ve_honey::cast_vote], signed by the operator in place of the vote
+    /// delegate.
+    pub cast_vote: CastVote<'info>,
+    /// CHECK: [OperatorApproval] of the operator on the [Escrow]; checked in validate.
+    pub operator_approval: UncheckedAccount<'info>,
+}
+
+impl<'info> OperatorCastVote<'info> {
+    pub fn process(&mut self, side: u8) -> Result<()> {
+        self.cast_vote.process(side)
+    }
+}
+
+impl<'info> Validate<'info> for OperatorCastVote<'info> {
+    fn validate(&self) -> Result<()> {
+        let cast_vote = &self.cast_vote;
+
+        assert_operator_approved(
+            &cast_vote.escrow,
+            &cast_vote.vote_delegate.key(),
+            &self.operator_approval,
+            OperatorActions::VOTE,
+        )?;
+        cast_vote.assert_valid_vote()
+    }
+}
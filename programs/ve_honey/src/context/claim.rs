@@ -12,7 +12,8 @@ pub struct Claim<'info> {
     pub escrow: Box<Account<'info, Escrow>>,
     /// authority of [Escrow].
     pub escrow_owner: Signer<'info>,
-    /// tokens locked up in the [Locker].
+    /// tokens locked up in the [Locker], or the tokens of the [Escrow] in the reward mint
+    /// of the [NftReceipt].
     #[account(mut)]
     pub locked_tokens: Box<Account<'info, TokenAccount>>,
     /// destination for the tokens claimed
@@ -30,7 +31,7 @@ impl<'info> Claim<'info> {
     pub fn process(&mut self) -> Result<()> {
         let claim_amount = unwrap_int!(self
             .nft_receipt
-            .calculate_reward_amount_at_time(&self.locker.params, Clock::get()?.unix_timestamp));
+            .calculate_reward_amount_at_time(Clock::get()?.unix_timestamp));
 
         invariant!(claim_amount > 0, ProtocolError::ClaimError);
 
@@ -62,10 +63,9 @@ impl<'info> Claim<'info> {
 
         Ok(())
     }
-}
 
-impl<'info> Validate<'info> for Claim<'info> {
-    fn validate(&self) -> Result<()> {
+    /// Checks the accounts of the claim, apart from its signer.
+    fn assert_valid_claim(&self) -> Result<()> {
         assert_keys_eq!(
             self.locker,
             self.escrow.locker,
@@ -76,21 +76,16 @@ impl<'info> Validate<'info> for Claim<'info> {
             self.nft_receipt.locker,
             ProtocolError::InvalidLocker
         );
-        assert_keys_eq!(
-            self.escrow.owner,
-            self.escrow_owner,
-            ProtocolError::InvalidAccountOwner
-        );
         assert_keys_eq!(
             self.nft_receipt.owner,
-            self.escrow_owner,
+            self.escrow.owner,
             ProtocolError::InvalidAccountOwner
         );
-        assert_keys_eq!(
-            self.escrow.tokens,
-            self.locked_tokens,
-            ProtocolError::InvalidToken
-        );
+        assert_reward_tokens(
+            &self.escrow,
+            self.nft_receipt.reward_mint,
+            &self.locked_tokens,
+        )?;
         assert_keys_neq!(
             self.locked_tokens,
             self.destination_tokens,
@@ -104,3 +99,45 @@ impl<'info> Validate<'info> for Claim<'info> {
         Ok(())
     }
 }
+
+impl<'info> Validate<'info> for Claim<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.escrow.owner,
+            self.escrow_owner,
+            ProtocolError::InvalidAccountOwner
+        );
+        self.assert_valid_claim()
+    }
+}
+
+/// Claim with [NftReceipt] account as an operator approved to claim for the owner of the
+/// [Escrow].
+#[derive(Accounts)]
+pub struct OperatorClaim<'info> {
+    /// accounts of [ve_honey::claim], signed by the operator in place of the owner.
+    pub claim: Claim<'info>,
+    /// CHECK: [OperatorApproval] of the operator on the [Escrow]; checked in validate.
+    pub operator_approval: UncheckedAccount<'info>,
+}
+
+impl<'info> OperatorClaim<'info> {
+    /// Claims the same way as [ve_honey::claim]; the tokens are paid to the owner.
+    pub fn process(&mut self) -> Result<()> {
+        self.claim.process()
+    }
+}
+
+impl<'info> Validate<'info> for OperatorClaim<'info> {
+    fn validate(&self) -> Result<()> {
+        let claim = &self.claim;
+
+        assert_claim_authority(
+            &claim.escrow,
+            &claim.escrow_owner.key(),
+            &self.operator_approval,
+            &claim.destination_tokens,
+        )?;
+        claim.assert_valid_claim()
+    }
+}
@@ -0,0 +1,158 @@
+use crate::*;
+use anchor_lang::AccountsClose;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+/// Claim with all the [NftReceipt]s of an [Escrow] at once.
+#[derive(Accounts)]
+pub struct ClaimAll<'info> {
+    /// [Locker].
+    #[account(mut)]
+    pub locker: Box<Account<'info, Locker>>,
+    /// [Escrow] that is being claimed
+    #[account(mut)]
+    pub escrow: Box<Account<'info, Escrow>>,
+    /// authority of [Escrow], or an operator approved to claim to its owner.
+    pub escrow_owner: Signer<'info>,
+    /// CHECK: [OperatorApproval] of the signer on the [Escrow], which may not exist.
+    #[account(
+        seeds = [
+            OPERATOR_APPROVAL_SEED.as_bytes(),
+            escrow.key().as_ref(),
+            escrow_owner.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub operator_approval: UncheckedAccount<'info>,
+    /// tokens locked up in the [Locker], or the tokens of the [Escrow] in the reward mint
+    /// of the [NftReceipt]s.
+    #[account(mut)]
+    pub locked_tokens: Box<Account<'info, TokenAccount>>,
+    /// destination for the tokens claimed
+    #[account(mut)]
+    pub destination_tokens: Box<Account<'info, TokenAccount>>,
+    /// CHECK: receiver of the rent of the closed [NftReceipt]s; the owner of the [Escrow]
+    /// when an operator claims.
+    #[account(mut)]
+    pub funds_receiver: UncheckedAccount<'info>,
+
+    /// token program
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ClaimAll<'info> {
+    /// Claims the vested rewards of the [NftReceipt]s given as the remaining accounts in a
+    /// single transfer, so they must all be paid in the same mint. With `close_ended`, the
+    /// receipts that end up fully claimed after their vest end are closed to the funds
+    /// receiver.
+    pub fn process(&mut self, receipts: &[AccountInfo<'info>], close_ended: bool) -> Result<()> {
+        invariant!(!receipts.is_empty(), ProtocolError::ClaimError);
+
+        let now = Clock::get()?.unix_timestamp;
+        let mut nft_receipts: Vec<Account<'info, NftReceipt>> = Vec::with_capacity(receipts.len());
+        let mut total_claim_amount: u64 = 0;
+
+        for info in receipts.iter() {
+            invariant!(
+                nft_receipts.iter().all(|r| r.key() != info.key()),
+                ProtocolError::DuplicateNftReceipt
+            );
+
+            let mut nft_receipt: Account<NftReceipt> = Account::try_from(info)?;
+            self.assert_valid_receipt(&nft_receipt)?;
+
+            let claim_amount = unwrap_int!(nft_receipt.calculate_reward_amount_at_time(now));
+            if claim_amount > 0 {
+                nft_receipt.update_receipt(&mut self.locker, &mut self.escrow, claim_amount)?;
+                total_claim_amount = unwrap_int!(total_claim_amount.checked_add(claim_amount));
+            }
+
+            nft_receipts.push(nft_receipt);
+        }
+
+        invariant!(total_claim_amount > 0, ProtocolError::ClaimError);
+
+        let seeds: &[&[&[u8]]] = escrow_seeds!(self.escrow);
+
+        token::transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::Transfer {
+                    from: self.locked_tokens.to_account_info(),
+                    to: self.destination_tokens.to_account_info(),
+                    authority: self.escrow.to_account_info(),
+                },
+            )
+            .with_signer(seeds),
+            total_claim_amount,
+        )?;
+
+        let escrow = &mut self.escrow;
+
+        if escrow.amount == 0 {
+            escrow.escrow_started_at = 0;
+            escrow.escrow_ends_at = 0;
+        }
+
+        for nft_receipt in nft_receipts.iter() {
+            if close_ended && nft_receipt.is_closable(now) {
+                nft_receipt.close(self.funds_receiver.to_account_info())?;
+                escrow.closed_receipt_count =
+                    unwrap_int!(escrow.closed_receipt_count.checked_add(1));
+            } else {
+                nft_receipt.exit(&crate::ID)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn assert_valid_receipt(&self, nft_receipt: &Account<NftReceipt>) -> Result<()> {
+        assert_keys_eq!(
+            self.locker,
+            nft_receipt.locker,
+            ProtocolError::InvalidLocker
+        );
+        assert_keys_eq!(
+            nft_receipt.owner,
+            self.escrow.owner,
+            ProtocolError::InvalidAccountOwner
+        );
+        invariant!(
+            self.escrow.receipt_count > nft_receipt.receipt_id,
+            ProtocolError::InvariantViolated
+        );
+        assert_reward_tokens(&self.escrow, nft_receipt.reward_mint, &self.locked_tokens)?;
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for ClaimAll<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.locker,
+            self.escrow.locker,
+            ProtocolError::InvalidLocker
+        );
+        assert_claim_authority(
+            &self.escrow,
+            &self.escrow_owner.key(),
+            &self.operator_approval,
+            &self.destination_tokens,
+        )?;
+        if self.escrow_owner.key() != self.escrow.owner {
+            assert_keys_eq!(
+                self.funds_receiver,
+                self.escrow.owner,
+                ProtocolError::InvalidAccountOwner
+            );
+        }
+        assert_keys_neq!(
+            self.locked_tokens,
+            self.destination_tokens,
+            ProtocolError::InvalidToken
+        );
+
+        Ok(())
+    }
+}
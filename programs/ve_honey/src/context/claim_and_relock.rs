@@ -0,0 +1,134 @@
+use crate::*;
+use num_traits::ToPrimitive;
+
+/// Claim with [NftReceipt] account that locked NFT, keeping the tokens locked in the
+/// [Escrow].
+#[derive(Accounts)]
+pub struct ClaimAndRelock<'info> {
+    /// [Locker].
+    pub locker: Box<Account<'info, Locker>>,
+    /// [Escrow] that is being claimed
+    #[account(mut)]
+    pub escrow: Box<Account<'info, Escrow>>,
+    /// authority of [Escrow], or an operator approved to relock it.
+    pub escrow_owner: Signer<'info>,
+    /// CHECK: [OperatorApproval] of the signer on the [Escrow], which may not exist.
+    #[account(
+        seeds = [
+            OPERATOR_APPROVAL_SEED.as_bytes(),
+            escrow.key().as_ref(),
+            escrow_owner.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub operator_approval: UncheckedAccount<'info>,
+    /// NFT receipt
+    #[account(mut)]
+    pub nft_receipt: Box<Account<'info, NftReceipt>>,
+}
+
+impl<'info> ClaimAndRelock<'info> {
+    /// Moves the vested reward of the receipt from the amount due to receipts into the
+    /// unlockable balance of the [Escrow], without moving any tokens. With `duration`,
+    /// the lock of the [Escrow] is refreshed to end `duration` seconds from now.
+    pub fn process(&mut self, duration: Option<i64>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let claim_amount = unwrap_int!(self.nft_receipt.calculate_reward_amount_at_time(now));
+
+        invariant!(claim_amount > 0, ProtocolError::ClaimError);
+
+        let escrow = &mut self.escrow;
+        let prev_escrow_ends_at = escrow.escrow_ends_at;
+
+        if let Some(duration) = duration {
+            let params = &self.locker.params;
+            invariant!(
+                unwrap_int!(duration.to_u64()) >= params.min_stake_duration,
+                ProtocolError::LockupDurationTooShort
+            );
+            invariant!(
+                unwrap_int!(duration.to_u64()) <= params.max_stake_duration,
+                ProtocolError::LockupDurationTooLong
+            );
+
+            let next_escrow_ends_at = unwrap_int!(now.checked_add(duration));
+            if prev_escrow_ends_at > next_escrow_ends_at {
+                return Err(ProtocolError::RefreshCannotShorten.into());
+            }
+
+            escrow.escrow_started_at = now;
+            escrow.escrow_ends_at = next_escrow_ends_at;
+        }
+
+        escrow.amount_to_receipt = unwrap_int!(escrow.amount_to_receipt.checked_sub(claim_amount));
+
+        let nft_receipt = &mut self.nft_receipt;
+        nft_receipt.claimed_amount =
+            unwrap_int!(nft_receipt.claimed_amount.checked_add(claim_amount));
+
+        emit!(ClaimAndRelockEvent {
+            locker: self.locker.key(),
+            escrow_owner: escrow.owner,
+            receipt_id: nft_receipt.receipt_id,
+            amount: claim_amount,
+            prev_escrow_ends_at,
+            next_escrow_ends_at: escrow.escrow_ends_at,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for ClaimAndRelock<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.locker,
+            self.escrow.locker,
+            ProtocolError::InvalidLocker
+        );
+        assert_keys_eq!(
+            self.locker,
+            self.nft_receipt.locker,
+            ProtocolError::InvalidLocker
+        );
+        assert_escrow_authority(
+            &self.escrow,
+            &self.escrow_owner.key(),
+            &self.operator_approval,
+            OperatorActions::RELOCK,
+        )?;
+        assert_keys_eq!(
+            self.nft_receipt.owner,
+            self.escrow.owner,
+            ProtocolError::InvalidAccountOwner
+        );
+        invariant!(
+            self.nft_receipt.reward_mint.is_none(),
+            ProtocolError::RewardMintNotSupported
+        );
+        invariant!(
+            self.escrow.receipt_count > self.nft_receipt.receipt_id,
+            ProtocolError::InvariantViolated
+        );
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event called in [ve_honey::claim_and_relock].
+pub struct ClaimAndRelockEvent {
+    /// [Locker] of the [Escrow].
+    #[index]
+    pub locker: Pubkey,
+    /// The owner of the [Escrow].
+    pub escrow_owner: Pubkey,
+    /// Id of the claimed [NftReceipt].
+    pub receipt_id: u64,
+    /// Amount of tokens relocked.
+    pub amount: u64,
+    /// The previous timestamp that the [Escrow] ended at.
+    pub prev_escrow_ends_at: i64,
+    /// The new [Escrow] end time.
+    pub next_escrow_ends_at: i64,
+}
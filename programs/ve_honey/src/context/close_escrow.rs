@@ -70,6 +70,10 @@ impl<'info> Validate<'info> for CloseEscrow<'info> {
             ProtocolError::EscrowNotEnded
         );
         invariant!(self.escrow.amount == 0, ProtocolError::EscrowInUse);
+        invariant!(
+            self.escrow.reward_amount_to_receipt == 0,
+            ProtocolError::EscrowInUse
+        );
 
         Ok(())
     }
@@ -20,6 +20,9 @@ pub struct CloseReceipt<'info> {
 
 impl<'info> CloseReceipt<'info> {
     pub fn process(&mut self) -> Result<()> {
+        let escrow = &mut self.escrow;
+        escrow.closed_receipt_count = unwrap_int!(escrow.closed_receipt_count.checked_add(1));
+
         Ok(())
     }
 }
@@ -47,20 +50,19 @@ impl<'info> Validate<'info> for CloseReceipt<'info> {
             ProtocolError::InvalidAccountOwner
         );
         let now = Clock::get()?.unix_timestamp;
-        msg!(
-            "now: {}; vest_ends_at: {}",
-            now,
-            self.nft_receipt.vest_ends_at
-        );
         invariant!(
             self.nft_receipt.vest_ends_at < now,
             ProtocolError::ReceiptNotEnded
         );
-        let max_reward_amount = self.locker.params.calculate_max_reward_amount();
+        let max_reward_amount = self.nft_receipt.calculate_max_reward_amount();
         invariant!(
             max_reward_amount == Some(self.nft_receipt.claimed_amount),
             ProtocolError::CloseNonZeroReceipt
         );
+        invariant!(
+            !self.nft_receipt.custodial || self.nft_receipt.withdrawn,
+            ProtocolError::NftNotWithdrawn
+        );
 
         Ok(())
     }
@@ -0,0 +1,98 @@
+use crate::*;
+use num_traits::ToPrimitive;
+
+/// Extend the lock of an [Escrow] without locking more tokens.
+#[derive(Accounts)]
+pub struct ExtendLock<'info> {
+    /// [Locker].
+    #[account(mut)]
+    pub locker: Box<Account<'info, Locker>>,
+    /// [Escrow].
+    #[account(mut)]
+    pub escrow: Box<Account<'info, Escrow>>,
+    /// Authority of the [Escrow], or an operator approved to extend it.
+    pub escrow_owner: Signer<'info>,
+    /// CHECK: [OperatorApproval] of the signer on the [Escrow], which may not exist.
+    #[account(
+        seeds = [
+            OPERATOR_APPROVAL_SEED.as_bytes(),
+            escrow.key().as_ref(),
+            escrow_owner.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub operator_approval: UncheckedAccount<'info>,
+}
+
+impl<'info> ExtendLock<'info> {
+    pub fn process(&mut self, duration: i64) -> Result<()> {
+        invariant!(
+            unwrap_int!(duration.to_u64()) >= self.locker.params.min_stake_duration,
+            ProtocolError::LockupDurationTooShort
+        );
+        invariant!(
+            unwrap_int!(duration.to_u64()) <= self.locker.params.max_stake_duration,
+            ProtocolError::LockupDurationTooLong
+        );
+
+        let prev_escrow_ends_at = self.escrow.escrow_ends_at;
+        let next_escrow_started_at = Clock::get()?.unix_timestamp;
+        let next_escrow_ends_at = unwrap_int!(next_escrow_started_at.checked_add(duration));
+        if prev_escrow_ends_at > next_escrow_ends_at {
+            return Err(ProtocolError::RefreshCannotShorten.into());
+        }
+
+        let locker = &mut self.locker;
+        let escrow = &mut self.escrow;
+
+        escrow.update_lock_event(locker, 0, next_escrow_started_at, next_escrow_ends_at, 0)?;
+
+        emit!(LockEvent {
+            locker: locker.key(),
+            locker_supply: locker.locked_supply,
+            escrow_owner: escrow.owner,
+            token_mint: locker.token_mint,
+            amount: 0,
+            duration,
+            prev_escrow_ends_at,
+            next_escrow_ends_at,
+            next_escrow_started_at
+        });
+
+        Ok(())
+    }
+
+    /// Checks that the extension is allowed while the whitelist is enabled, the same way
+    /// as a lock of no tokens by the owner of the [Escrow].
+    pub fn check_whitelisted(
+        &self,
+        remaining_accounts: &[AccountInfo<'info>],
+        duration: i64,
+    ) -> Result<()> {
+        check_whitelisted(
+            &self.locker.key(),
+            &self.escrow.owner,
+            remaining_accounts,
+            0,
+            duration,
+        )
+    }
+}
+
+impl<'info> Validate<'info> for ExtendLock<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.locker,
+            self.escrow.locker,
+            ProtocolError::InvalidLocker
+        );
+        assert_escrow_authority(
+            &self.escrow,
+            &self.escrow_owner.key(),
+            &self.operator_approval,
+            OperatorActions::EXTEND,
+        )?;
+
+        Ok(())
+    }
+}
@@ -28,24 +28,15 @@ pub struct InitEscrow<'info> {
 
 impl<'info> InitEscrow<'info> {
     pub fn process(&mut self, bump: u8) -> Result<()> {
+        let escrow_key = self.escrow.key();
         let escrow = &mut self.escrow;
-        let locker = &mut self.locker;
-
-        escrow.locker = locker.key();
-        escrow.owner = self.escrow_owner.key();
-        escrow.bump = bump;
-
-        escrow.tokens = anchor_spl::associated_token::get_associated_token_address(
-            &escrow.key(),
+        escrow.init(
+            &escrow_key,
+            &self.locker.key(),
             &self.locker.token_mint,
+            self.escrow_owner.key(),
+            bump,
         );
-        escrow.amount = 0;
-        escrow.escrow_started_at = 0;
-        escrow.escrow_ends_at = 0;
-        escrow.receipt_count = 0;
-        escrow.amount_to_receipt = 0;
-
-        escrow.vote_delegate = self.escrow_owner.key();
 
         emit!(InitEscrowEvent {
             escrow: escrow.key(),
@@ -34,6 +34,8 @@ pub struct InitLocker<'info> {
 
 impl<'info> InitLocker<'info> {
     pub fn process(&mut self, bump: u8, params: LockerParams) -> Result<()> {
+        params.validate()?;
+
         let locker = &mut self.locker;
         locker.token_mint = self.token_mint.key();
         locker.wl_token_mint = self.wl_token_mint.key();
@@ -23,7 +23,7 @@ pub struct InitTreasury<'info> {
         payer = payer,
     )]
     pub treasury: Box<Account<'info, TokenAccount>>,
-    /// Mint of the token that can be used to join the [Locker].
+    /// Mint of the token that can be used to join the [Locker], or its reward mint.
     pub token_mint: Box<Account<'info, Mint>>,
     /// The [Governor].
     pub governor: Box<Account<'info, Governor>>,
@@ -46,9 +46,9 @@ impl<'info> InitTreasury<'info> {
 
 impl<'info> Validate<'info> for InitTreasury<'info> {
     fn validate(&self) -> Result<()> {
-        assert_keys_eq!(
-            self.locker.token_mint,
-            self.token_mint,
+        let token_mint = self.token_mint.key();
+        invariant!(
+            token_mint == self.locker.token_mint || Some(token_mint) == self.locker.reward_mint,
             ProtocolError::InvalidLockerMint
         );
         assert_keys_eq!(
@@ -1,6 +1,6 @@
 use crate::*;
 use anchor_lang::solana_program::system_program;
-use anchor_lang::solana_program::sysvar::instructions::get_instruction_relative;
+use anchor_lang::solana_program::sysvar::instructions::{self, get_instruction_relative};
 use anchor_spl::token::{self, Token, TokenAccount};
 use num_traits::ToPrimitive;
 
@@ -68,7 +68,7 @@ impl<'info> Lock<'info> {
             amount,
             next_escrow_started_at,
             next_escrow_ends_at,
-            false,
+            0,
         )?;
 
         emit!(LockEvent {
@@ -86,36 +86,20 @@ impl<'info> Lock<'info> {
         Ok(())
     }
 
-    pub fn check_whitelisted(&self, remaining_accounts: &[AccountInfo]) -> Result<()> {
-        invariant!(
-            remaining_accounts.len() == 2,
-            ProtocolError::MustProvideWhitelist
-        );
-
-        let accounts_iter = &mut remaining_accounts.iter();
-        let ix_sysvar_account_info = next_account_info(accounts_iter)?;
-        let program_id = get_instruction_relative(0, ix_sysvar_account_info)?.program_id;
-        if program_id == crate::ID {
-            return Ok(());
-        }
-
-        let whitelist_entry_account_info = next_account_info(accounts_iter)?;
-        invariant!(
-            !whitelist_entry_account_info.data_is_empty(),
-            ProtocolError::ProgramNotWhitelisted
-        );
-        let whitelist_entry = Account::<WhitelistEntry>::try_from(whitelist_entry_account_info)?;
-        assert_keys_eq!(whitelist_entry.locker, self.locker);
-        assert_keys_eq!(whitelist_entry.program_id, program_id);
-        if whitelist_entry.owner != system_program::ID {
-            assert_keys_eq!(
-                whitelist_entry.owner,
-                self.escrow_owner,
-                ProtocolError::EscrowOwnerNotWhitelisted
-            );
-        }
-
-        Ok(())
+    /// Checks that the lock is allowed while the whitelist is enabled.
+    pub fn check_whitelisted(
+        &self,
+        remaining_accounts: &[AccountInfo<'info>],
+        amount: u64,
+        duration: i64,
+    ) -> Result<()> {
+        check_whitelisted(
+            &self.locker.key(),
+            &self.escrow_owner.key(),
+            remaining_accounts,
+            amount,
+            duration,
+        )
     }
 }
 
@@ -156,6 +140,74 @@ impl<'info> Validate<'info> for Lock<'info> {
     }
 }
 
+/// Checks that the lock is allowed while the whitelist is enabled.
+///
+/// Direct calls provide the instructions sysvar first, optionally followed by
+/// the [WhitelistEntry] as in the previous layout, and must be made at the top
+/// level of the transaction.
+/// Programs calling through CPI provide their lock authority as a signer and
+/// their [WhitelistEntry]. The lock authority is a PDA of [LOCK_AUTHORITY_SEED]
+/// and the [Locker] under the calling program, so only that program is able to
+/// sign for it.
+pub(crate) fn check_whitelisted(
+    locker: &Pubkey,
+    escrow_owner: &Pubkey,
+    remaining_accounts: &[AccountInfo],
+    amount: u64,
+    duration: i64,
+) -> Result<()> {
+    let accounts_iter = &mut remaining_accounts.iter();
+    let first_account_info = unwrap_opt!(accounts_iter.next(), ProtocolError::MustProvideWhitelist);
+
+    if first_account_info.key == &instructions::ID {
+        let program_id = get_instruction_relative(0, first_account_info)?.program_id;
+        assert_keys_eq!(program_id, crate::ID, ProtocolError::ProgramNotWhitelisted);
+
+        return Ok(());
+    }
+
+    let lock_authority_account_info = first_account_info;
+    invariant!(
+        lock_authority_account_info.is_signer,
+        ProtocolError::InvalidLockAuthority
+    );
+    let whitelist_entry_account_info =
+        unwrap_opt!(accounts_iter.next(), ProtocolError::MustProvideWhitelist);
+    invariant!(
+        !whitelist_entry_account_info.data_is_empty(),
+        ProtocolError::ProgramNotWhitelisted
+    );
+    invariant!(
+        whitelist_entry_account_info.is_writable,
+        ProtocolError::WhitelistEntryNotWritable
+    );
+    let mut whitelist_entry = Account::<WhitelistEntry>::try_from(whitelist_entry_account_info)?;
+    assert_keys_eq!(whitelist_entry.locker, *locker);
+
+    let (lock_authority, _bump) = Pubkey::find_program_address(
+        &[LOCK_AUTHORITY_SEED.as_bytes(), locker.as_ref()],
+        &whitelist_entry.program_id,
+    );
+    assert_keys_eq!(
+        lock_authority,
+        *lock_authority_account_info.key,
+        ProtocolError::InvalidLockAuthority
+    );
+
+    if whitelist_entry.owner != system_program::ID {
+        assert_keys_eq!(
+            whitelist_entry.owner,
+            *escrow_owner,
+            ProtocolError::EscrowOwnerNotWhitelisted
+        );
+    }
+
+    whitelist_entry.record_lock(amount, duration, Clock::get()?.unix_timestamp)?;
+    whitelist_entry.exit(&crate::ID)?;
+
+    Ok(())
+}
+
 #[event]
 /// Event called in [ve_honey::lock].
 pub struct LockEvent {
@@ -0,0 +1,331 @@
+use crate::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct LockCnft<'info> {
+    /// payer of the initialization of [NftReceipt].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// [Locker].
+    #[account(mut)]
+    pub locker: Box<Account<'info, Locker>>,
+    /// [Escrow].
+    #[account(mut)]
+    pub escrow: Box<Account<'info, Escrow>>,
+    /// [NftReceipt].
+    #[account(
+        init,
+        seeds = [
+            NFT_RECEIPT_SEED.as_bytes(),
+            locker.key().as_ref(),
+            escrow_owner.key().as_ref(),
+            escrow.receipt_count.to_le_bytes().as_ref(),
+        ],
+        bump,
+        space = 8 + NftReceipt::LEN,
+        payer = payer,
+    )]
+    pub receipt: Box<Account<'info, NftReceipt>>,
+    /// Authority of the [Escrow].
+    pub escrow_owner: Signer<'info>,
+    /// locked tokens, or the tokens of the [Escrow] in the reward mint of the [Locker].
+    #[account(mut)]
+    pub locked_tokens: Box<Account<'info, TokenAccount>>,
+    /// locker treasury of the reward mint.
+    #[account(mut)]
+    pub locker_treasury: Box<Account<'info, TokenAccount>>,
+    /// CHECK: [Proof] of the asset, one of its creators or its collection; checked in process.
+    pub proof: UncheckedAccount<'info>,
+    /// owner of the compressed nft.
+    pub leaf_owner: Signer<'info>,
+    /// CHECK: delegate of the compressed nft; validated by Bubblegum.
+    pub leaf_delegate: UncheckedAccount<'info>,
+    /// CHECK: tree config of the merkle tree; validated by Bubblegum.
+    pub tree_authority: UncheckedAccount<'info>,
+    /// CHECK: merkle tree holding the compressed nft; validated by account compression.
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: [RewardSchedule] of the [Locker], which may not exist.
+    #[account(
+        seeds = [REWARD_SCHEDULE_SEED.as_bytes(), locker.key().as_ref()],
+        bump,
+    )]
+    pub reward_schedule: UncheckedAccount<'info>,
+    /// CHECK: [VestTiers] of the [Locker], which may not exist.
+    #[account(
+        seeds = [VEST_TIERS_SEED.as_bytes(), locker.key().as_ref()],
+        bump,
+    )]
+    pub vest_tiers: UncheckedAccount<'info>,
+    /// CHECK: [Campaign] of the [Locker], which may not exist.
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_SEED.as_bytes(), locker.key().as_ref()],
+        bump,
+    )]
+    pub campaign: UncheckedAccount<'info>,
+    /// CHECK: [BurnCounter] of the collection of the NFT in the [Campaign]; checked against
+    /// the NFT when the [Campaign] exists.
+    #[account(mut)]
+    pub collection_burns: UncheckedAccount<'info>,
+    /// CHECK: [BurnCounter] of the [Escrow] in the [Campaign]; checked when the [Campaign]
+    /// exists.
+    #[account(mut)]
+    pub escrow_burns: UncheckedAccount<'info>,
+    /// WL token mint
+    #[account(mut)]
+    pub wl_token_mint: Box<Account<'info, Mint>>,
+    /// WL token destination.
+    #[account(mut)]
+    pub wl_destination: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: noop program
+    #[account(address = nft::spl_noop::ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+    /// CHECK: account compression program
+    #[account(address = nft::spl_account_compression::ID)]
+    pub compression_program: UncheckedAccount<'info>,
+    /// CHECK: bubblegum program
+    #[account(address = nft::mpl_bubblegum::ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+    /// system program
+    pub system_program: Program<'info, System>,
+    /// token program
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> LockCnft<'info> {
+    /// Locks the compressed nft at `index` of the merkle tree, with the nodes of its proof
+    /// path given as the remaining accounts, vesting its reward over the [VestTier] at
+    /// index `vest_tier`, if any.
+    ///
+    /// The leaf hashes are computed from `metadata`, so burning fails unless the given
+    /// creators and collection are those of the leaf.
+    #[allow(clippy::too_many_arguments)]
+    pub fn process(
+        &mut self,
+        duration: i64,
+        root: [u8; 32],
+        nonce: u64,
+        index: u32,
+        metadata: CnftMetadata,
+        vest_tier: Option<u8>,
+        proof_path: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let asset_id = get_asset_id(&self.merkle_tree.key(), nonce);
+
+        let provenance = self.assert_valid_cnft(&asset_id, &metadata)?;
+        let reward_terms =
+            nft_reward_terms(&self.proof, &self.reward_schedule, &self.locker.params)?;
+        let wl_token_amount = reward_terms.0.wl_token_amount;
+        let vest_tier = picked_vest_tier(&self.vest_tiers, vest_tier)?;
+
+        lock_nft_reward(
+            &mut self.locker,
+            &mut self.escrow,
+            &mut self.receipt,
+            &self.locker_treasury,
+            &self.locked_tokens,
+            &self.token_program,
+            &provenance,
+            reward_terms,
+            vest_tier,
+            false,
+            duration,
+        )?;
+        CampaignAccounts {
+            campaign: &self.campaign,
+            collection_burns: &self.collection_burns,
+            escrow_burns: &self.escrow_burns,
+            payer: &self.payer,
+            system_program: &self.system_program,
+        }
+        .record_burn(
+            &mut self.locker,
+            &self.escrow.key(),
+            &provenance.campaign_collection(),
+            unwrap_int!(self.receipt.calculate_max_reward_amount()),
+        )?;
+
+        self.burn_cnft(root, nonce, index, &metadata, proof_path)?;
+
+        mint_wl_token(
+            &self.locker,
+            &self.wl_token_mint,
+            &self.wl_destination,
+            &self.token_program,
+            wl_token_amount,
+        )?;
+
+        let receipt = &self.receipt;
+
+        emit!(LockCnftEvent {
+            locker: receipt.locker,
+            escrow_owner: receipt.owner,
+            receipt_id: receipt.receipt_id,
+            asset_id,
+            merkle_tree: self.merkle_tree.key(),
+            proof: receipt.proof,
+            proof_type: receipt.proof_type,
+            collection: receipt.collection,
+            reward_amount: unwrap_int!(receipt.calculate_max_reward_amount()),
+            wl_token_amount,
+            vest_ends_at: receipt.vest_ends_at,
+        });
+
+        Ok(())
+    }
+
+    /// Checks that the compressed nft is covered by a [Proof] of the [Locker], either by its
+    /// asset id, one of its verified creators or its verified collection.
+    fn assert_valid_cnft(
+        &self,
+        asset_id: &Pubkey,
+        metadata: &CnftMetadata,
+    ) -> Result<NftProvenance> {
+        let locker = self.locker.key();
+        let collection = metadata
+            .collection
+            .as_ref()
+            .filter(|collection| collection.verified)
+            .map(|collection| collection.key);
+
+        let provenance = |proof_type| NftProvenance {
+            nft_mint: *asset_id,
+            proof: self.proof.key(),
+            proof_type,
+            collection,
+            weight_bps: MAX_BPS,
+        };
+
+        if let Ok(()) =
+            assert_valid_proof(&self.proof, &locker, asset_id, &crate::ID, ProofType::MINT)
+        {
+            return Ok(provenance(ProofType::MINT));
+        }
+
+        for creator in metadata.creators.iter().filter(|c| c.verified) {
+            if let Ok(()) = assert_valid_proof(
+                &self.proof,
+                &locker,
+                &creator.address,
+                &crate::ID,
+                ProofType::CREATOR,
+            ) {
+                return Ok(provenance(ProofType::CREATOR));
+            }
+        }
+
+        match collection {
+            Some(collection) => {
+                assert_valid_proof(
+                    &self.proof,
+                    &locker,
+                    &collection,
+                    &crate::ID,
+                    ProofType::COLLECTION,
+                )?;
+                Ok(provenance(ProofType::COLLECTION))
+            }
+            None => Err(error!(ProtocolError::InvalidProof)),
+        }
+    }
+
+    fn burn_cnft(
+        &self,
+        root: [u8; 32],
+        nonce: u64,
+        index: u32,
+        metadata: &CnftMetadata,
+        proof_path: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let proof: Vec<Pubkey> = proof_path.iter().map(|node| node.key()).collect();
+
+        let mut account_infos: Vec<AccountInfo> = vec![
+            self.bubblegum_program.to_account_info(),
+            self.tree_authority.to_account_info(),
+            self.leaf_owner.to_account_info(),
+            self.leaf_delegate.to_account_info(),
+            self.merkle_tree.to_account_info(),
+            self.log_wrapper.to_account_info(),
+            self.compression_program.to_account_info(),
+            self.system_program.to_account_info(),
+        ];
+        account_infos.extend(proof_path.iter().cloned());
+
+        invoke(
+            &burn_compressed_nft(
+                self.tree_authority.key(),
+                self.leaf_owner.key(),
+                self.leaf_delegate.key(),
+                self.merkle_tree.key(),
+                &proof,
+                root,
+                metadata.data_hash()?,
+                metadata.creator_hash(),
+                nonce,
+                index,
+            ),
+            account_infos.as_slice(),
+        )?;
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for LockCnft<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.wl_token_mint,
+            self.locker.wl_token_mint,
+            ProtocolError::InvalidLockerWLMint
+        );
+        assert_keys_eq!(
+            self.locker,
+            self.escrow.locker,
+            ProtocolError::InvalidLocker
+        );
+        assert_reward_accounts(
+            &self.locker,
+            &self.escrow,
+            &self.locker_treasury,
+            &self.locked_tokens,
+        )?;
+        assert_keys_eq!(
+            self.escrow_owner,
+            self.escrow.owner,
+            ProtocolError::InvalidAccountOwner
+        );
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event called in [ve_honey::lock_cnft].
+pub struct LockCnftEvent {
+    /// [Locker] of the [Escrow].
+    #[index]
+    pub locker: Pubkey,
+    /// The owner of the [Escrow].
+    pub escrow_owner: Pubkey,
+    /// Id of the [NftReceipt].
+    pub receipt_id: u64,
+    /// Asset id of the burnt compressed NFT.
+    pub asset_id: Pubkey,
+    /// Merkle tree the compressed NFT was burnt from.
+    pub merkle_tree: Pubkey,
+    /// [Proof] that accepted the compressed NFT.
+    pub proof: Pubkey,
+    /// Type of the [Proof] that matched the compressed NFT.
+    pub proof_type: u8,
+    /// Verified collection of the compressed NFT.
+    pub collection: Option<Pubkey>,
+    /// Reward of the [NftReceipt] once fully vested.
+    pub reward_amount: u64,
+    /// Number of whole WL tokens minted.
+    pub wl_token_amount: u64,
+    /// When the reward of the [NftReceipt] is fully vested.
+    pub vest_ends_at: i64,
+}
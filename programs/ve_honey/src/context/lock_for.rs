@@ -0,0 +1,220 @@
+use crate::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use num_traits::ToPrimitive;
+
+#[derive(Accounts)]
+pub struct LockFor<'info> {
+    /// Payer of the [Escrow] and token account initialization.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// [Locker].
+    #[account(mut)]
+    pub locker: Box<Account<'info, Locker>>,
+    /// [Escrow] of the beneficiary, created if it does not exist yet.
+    #[account(
+        init_if_needed,
+        seeds = [
+            ESCROW_SEED.as_bytes(),
+            locker.key().as_ref(),
+            escrow_owner.key().as_ref(),
+        ],
+        bump,
+        space = 8 + Escrow::LEN,
+        payer = payer
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+    /// CHECK: The beneficiary of the lock, i.e. the [Escrow::owner].
+    pub escrow_owner: UncheckedAccount<'info>,
+    /// Token account held by the [Escrow].
+    #[account(
+        init_if_needed,
+        associated_token::mint = token_mint,
+        associated_token::authority = escrow,
+        payer = payer
+    )]
+    pub locked_tokens: Box<Account<'info, TokenAccount>>,
+    /// Mint of the token locked in the [Locker].
+    pub token_mint: Box<Account<'info, Mint>>,
+    /// The funder of the lock, authority of source_tokens.
+    pub funder: Signer<'info>,
+    /// The source of tokens.
+    #[account(mut)]
+    pub source_tokens: Box<Account<'info, TokenAccount>>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+    /// Associated token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+    /// Rent sysvar.
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> LockFor<'info> {
+    /// Locks the tokens of the funder in the [Escrow] of the beneficiary for `duration`,
+    /// which may extend the lock of the [Escrow] but never shorten it.
+    pub fn process(&mut self, bump: u8, amount: u64, duration: i64) -> Result<()> {
+        invariant!(amount > 0, ProtocolError::LockAmountZero);
+        invariant!(
+            unwrap_int!(duration.to_u64()) >= self.locker.params.min_stake_duration,
+            ProtocolError::LockupDurationTooShort
+        );
+        invariant!(
+            unwrap_int!(duration.to_u64()) <= self.locker.params.max_stake_duration,
+            ProtocolError::LockupDurationTooLong
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+
+        if self.escrow.locker == Pubkey::default() {
+            let escrow_key = self.escrow.key();
+            self.escrow.init(
+                &escrow_key,
+                &self.locker.key(),
+                &self.locker.token_mint,
+                self.escrow_owner.key(),
+                bump,
+            );
+
+            emit!(InitEscrowEvent {
+                escrow: escrow_key,
+                escrow_owner: self.escrow.owner,
+                locker: self.escrow.locker,
+                timestamp: now
+            });
+        }
+
+        let prev_escrow_ends_at = self.escrow.escrow_ends_at;
+        let next_escrow_started_at = now;
+        let next_escrow_ends_at = unwrap_int!(next_escrow_started_at.checked_add(duration));
+        if prev_escrow_ends_at > next_escrow_ends_at {
+            return Err(ProtocolError::RefreshCannotShorten.into());
+        }
+
+        token::transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::Transfer {
+                    from: self.source_tokens.to_account_info(),
+                    to: self.locked_tokens.to_account_info(),
+                    authority: self.funder.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let locker = &mut self.locker;
+        let escrow = &mut self.escrow;
+
+        escrow.update_lock_event(
+            locker,
+            amount,
+            next_escrow_started_at,
+            next_escrow_ends_at,
+            0,
+        )?;
+
+        emit!(LockForEvent {
+            locker: locker.key(),
+            locker_supply: locker.locked_supply,
+            escrow_owner: escrow.owner,
+            funder: self.funder.key(),
+            token_mint: locker.token_mint,
+            amount,
+            duration,
+            prev_escrow_ends_at,
+            next_escrow_ends_at,
+            next_escrow_started_at
+        });
+
+        Ok(())
+    }
+
+    /// Checks that the lock is allowed while the whitelist is enabled.
+    pub fn check_whitelisted(
+        &self,
+        remaining_accounts: &[AccountInfo<'info>],
+        amount: u64,
+        duration: i64,
+    ) -> Result<()> {
+        check_whitelisted(
+            &self.locker.key(),
+            &self.escrow_owner.key(),
+            remaining_accounts,
+            amount,
+            duration,
+        )
+    }
+}
+
+impl<'info> Validate<'info> for LockFor<'info> {
+    fn validate(&self) -> Result<()> {
+        if self.escrow.locker != Pubkey::default() {
+            assert_keys_eq!(
+                self.locker,
+                self.escrow.locker,
+                ProtocolError::InvalidLocker
+            );
+            assert_keys_eq!(
+                self.escrow.tokens,
+                self.locked_tokens,
+                ProtocolError::InvalidToken
+            );
+            assert_keys_eq!(
+                self.escrow.owner,
+                self.escrow_owner,
+                ProtocolError::InvalidAccountOwner
+            );
+        }
+        assert_keys_eq!(
+            self.token_mint,
+            self.locker.token_mint,
+            ProtocolError::InvalidLockerMint
+        );
+        assert_keys_neq!(
+            self.source_tokens,
+            self.locked_tokens,
+            ProtocolError::InvalidToken
+        );
+        assert_keys_eq!(
+            self.source_tokens.owner,
+            self.funder,
+            ProtocolError::InvalidTokenOwner
+        );
+        assert_keys_eq!(
+            self.source_tokens.mint,
+            self.locker.token_mint,
+            ProtocolError::InvalidLockerMint
+        );
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event called in [ve_honey::lock_for].
+pub struct LockForEvent {
+    /// [Locker] of the [Escrow].
+    #[index]
+    pub locker: Pubkey,
+    /// The owner of the [Escrow], i.e. the beneficiary of the lock.
+    pub escrow_owner: Pubkey,
+    /// The account that supplied the locked tokens.
+    pub funder: Pubkey,
+    /// Mint of the token that for the [Locker].
+    pub token_mint: Pubkey,
+    /// Amount of tokens locked.
+    pub amount: u64,
+    /// Amount of tokens locked inside the [Locker].
+    pub locker_supply: u64,
+    /// Duration of lock time.
+    pub duration: i64,
+    /// The previous timestamp that the [Escrow] ended at.
+    pub prev_escrow_ends_at: i64,
+    /// The new [Escrow] end time.
+    pub next_escrow_ends_at: i64,
+    /// The new [Escrow] start time.
+    pub next_escrow_started_at: i64,
+}
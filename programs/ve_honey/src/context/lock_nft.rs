@@ -31,17 +31,45 @@ pub struct LockNft<'info> {
     pub receipt: Box<Account<'info, NftReceipt>>,
     /// Authority of the [Escrow].
     pub escrow_owner: Signer<'info>,
-    /// locked tokens.
+    /// locked tokens, or the tokens of the [Escrow] in the reward mint of the [Locker].
     #[account(mut)]
     pub locked_tokens: Box<Account<'info, TokenAccount>>,
-    /// locker treasury.
+    /// locker treasury of the reward mint.
     #[account(mut)]
     pub locker_treasury: Box<Account<'info, TokenAccount>>,
-    /// nft source token account.
+    /// CHECK: nft source token account, or the asset for Metaplex Core; validated by the
+    /// burning program.
     #[account(mut)]
-    pub nft_source: Box<Account<'info, TokenAccount>>,
+    pub nft_source: UncheckedAccount<'info>,
     /// authority of the nft.
     pub nft_source_authority: Signer<'info>,
+    /// CHECK: [RewardSchedule] of the [Locker], which may not exist.
+    #[account(
+        seeds = [REWARD_SCHEDULE_SEED.as_bytes(), locker.key().as_ref()],
+        bump,
+    )]
+    pub reward_schedule: UncheckedAccount<'info>,
+    /// CHECK: [VestTiers] of the [Locker], which may not exist.
+    #[account(
+        seeds = [VEST_TIERS_SEED.as_bytes(), locker.key().as_ref()],
+        bump,
+    )]
+    pub vest_tiers: UncheckedAccount<'info>,
+    /// CHECK: [Campaign] of the [Locker], which may not exist.
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_SEED.as_bytes(), locker.key().as_ref()],
+        bump,
+    )]
+    pub campaign: UncheckedAccount<'info>,
+    /// CHECK: [BurnCounter] of the collection of the NFT in the [Campaign]; checked against
+    /// the NFT when the [Campaign] exists.
+    #[account(mut)]
+    pub collection_burns: UncheckedAccount<'info>,
+    /// CHECK: [BurnCounter] of the [Escrow] in the [Campaign]; checked when the [Campaign]
+    /// exists.
+    #[account(mut)]
+    pub escrow_burns: UncheckedAccount<'info>,
     /// WL token mint
     #[account(mut)]
     pub wl_token_mint: Box<Account<'info, Mint>>,
@@ -56,84 +84,388 @@ pub struct LockNft<'info> {
 }
 
 impl<'info> LockNft<'info> {
-    pub fn process(&mut self, duration: i64) -> Result<()> {
-        let max_stake_duration = unwrap_int!(self.locker.params.calculate_nft_max_stake_duration());
+    pub fn process(
+        &mut self,
+        duration: i64,
+        provenance: &NftProvenance,
+        reward_terms: (NftRewardParams, Option<NftRewardSchedule>),
+        vest_tier: Option<VestTier>,
+    ) -> Result<()> {
+        lock_nft_reward(
+            &mut self.locker,
+            &mut self.escrow,
+            &mut self.receipt,
+            &self.locker_treasury,
+            &self.locked_tokens,
+            &self.token_program,
+            provenance,
+            reward_terms,
+            vest_tier,
+            false,
+            duration,
+        )
+    }
 
-        invariant!(
-            duration >= max_stake_duration,
-            ProtocolError::LockupDurationTooShort
-        );
+    pub(crate) fn nft_burn_accounts<'a>(
+        &'a self,
+        remaining_accounts: &'a [AccountInfo<'info>],
+    ) -> NftBurnAccounts<'a, 'info> {
+        NftBurnAccounts {
+            locker: self.locker.key(),
+            payer: &self.payer,
+            nft_source: &self.nft_source,
+            nft_source_authority: &self.nft_source_authority,
+            system_program: &self.system_program,
+            token_program: &self.token_program,
+            remaining_accounts,
+        }
+    }
 
-        let receipt = &mut self.receipt;
+    /// Counts the locked NFT against the [Campaign] of the [Locker], if any.
+    pub fn record_campaign_burn(&mut self, provenance: &NftProvenance) -> Result<()> {
+        CampaignAccounts {
+            campaign: &self.campaign,
+            collection_burns: &self.collection_burns,
+            escrow_burns: &self.escrow_burns,
+            payer: &self.payer,
+            system_program: &self.system_program,
+        }
+        .record_burn(
+            &mut self.locker,
+            &self.escrow.key(),
+            &provenance.campaign_collection(),
+            unwrap_int!(self.receipt.calculate_max_reward_amount()),
+        )
+    }
 
-        receipt.receipt_id = self.escrow.receipt_count;
-        receipt.locker = self.locker.key();
-        receipt.owner = self.escrow_owner.key();
-        receipt.vest_started_at = Clock::get()?.unix_timestamp;
-        receipt.vest_ends_at = unwrap_int!(receipt.vest_started_at.checked_add(max_stake_duration));
-        receipt.claimed_amount = 0;
+    pub fn mint_wl_token(&self, amount: u64) -> Result<()> {
+        mint_wl_token(
+            &self.locker,
+            &self.wl_token_mint,
+            &self.wl_destination,
+            &self.token_program,
+            amount,
+        )
+    }
+}
 
-        let prev_escrow_ends_at = self.escrow.escrow_ends_at;
-        let next_escrow_started_at = receipt.vest_started_at;
-        let next_escrow_ends_at = unwrap_int!(receipt
-            .vest_started_at
-            .checked_add(unwrap_int!(self.locker.params.max_stake_duration.to_i64())));
+/// Mints `amount` whole WL tokens of the [Locker] for a burnt NFT.
+pub(crate) fn mint_wl_token<'info>(
+    locker: &Account<'info, Locker>,
+    wl_token_mint: &Account<'info, Mint>,
+    wl_destination: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    amount: u64,
+) -> Result<()> {
+    let amount = unwrap_int!(amount.checked_mul(10u64.pow(wl_token_mint.decimals as u32)));
+    let seeds: &[&[&[u8]]] = locker_seeds!(locker);
+
+    token::mint_to(
+        CpiContext::new(
+            token_program.to_account_info(),
+            token::MintTo {
+                to: wl_destination.to_account_info(),
+                mint: wl_token_mint.to_account_info(),
+                authority: locker.to_account_info(),
+            },
+        )
+        .with_signer(seeds),
+        amount,
+    )?;
 
-        if prev_escrow_ends_at > next_escrow_ends_at {
-            return Err(error!(ProtocolError::RefreshCannotShorten));
-        }
+    Ok(())
+}
 
-        let max_reward_amount = unwrap_int!(self.locker.params.calculate_max_reward_amount());
-        let seeds: &[&[&[u8]]] = locker_seeds!(self.locker);
-
-        if max_reward_amount > 0 {
-            token::transfer(
-                CpiContext::new(
-                    self.token_program.to_account_info(),
-                    Transfer {
-                        from: self.locker_treasury.to_account_info(),
-                        to: self.locked_tokens.to_account_info(),
-                        authority: self.locker.to_account_info(),
-                    },
-                )
-                .with_signer(seeds),
-                max_reward_amount,
-            )?;
-        }
+/// Which NFT was locked and the [Proof] that accepted it.
+#[derive(Clone, Copy, Debug)]
+pub struct NftProvenance {
+    /// mint of the NFT, or its asset id for Metaplex Core and compressed NFTs.
+    pub nft_mint: Pubkey,
+    /// [Proof] that accepted the NFT.
+    pub proof: Pubkey,
+    /// Type of the [Proof] that matched the NFT.
+    pub proof_type: ProofType,
+    /// Verified collection of the NFT.
+    pub collection: Option<Pubkey>,
+    /// Reward weight of the NFT, in basis points; [MAX_BPS] unless set by a merkle
+    /// allowlist.
+    pub weight_bps: u16,
+}
 
-        let locker = &mut self.locker;
-        let escrow = &mut self.escrow;
+impl NftProvenance {
+    /// Collection of the NFT counted by a [Campaign]: its verified collection, or the
+    /// [Proof] that accepted it for NFTs without one.
+    pub fn campaign_collection(&self) -> Pubkey {
+        self.collection.unwrap_or(self.proof)
+    }
+}
 
-        escrow.update_lock_event(
-            locker,
-            max_reward_amount,
-            next_escrow_started_at,
-            next_escrow_ends_at,
-            true,
-        )?;
+/// Fills the [NftReceipt] of a locked NFT and moves its max reward from the
+/// treasury into the [Escrow].
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn lock_nft_reward<'info>(
+    locker: &mut Account<'info, Locker>,
+    escrow: &mut Account<'info, Escrow>,
+    receipt: &mut NftReceipt,
+    locker_treasury: &Account<'info, TokenAccount>,
+    locked_tokens: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    provenance: &NftProvenance,
+    reward_terms: (NftRewardParams, Option<NftRewardSchedule>),
+    vest_tier: Option<VestTier>,
+    custodial: bool,
+    duration: i64,
+) -> Result<()> {
+    let vest_started_at = Clock::get()?.unix_timestamp;
+    let max_reward_amount = fill_nft_receipt(
+        locker,
+        escrow,
+        receipt,
+        escrow.receipt_count,
+        vest_started_at,
+        provenance,
+        reward_terms,
+        vest_tier,
+        custodial,
+        duration,
+    )?;
+    // a tier never shortens the lock of the escrow, its tokens stay locked until the later end
+    let escrow_duration = match vest_tier {
+        Some(vest_tier) => vest_tier.escrow_duration.max(unwrap_int!(escrow
+            .escrow_ends_at
+            .checked_sub(vest_started_at))),
+        None => unwrap_int!(locker.params.max_stake_duration.to_i64()),
+    };
 
-        Ok(())
+    lock_nft_rewards(
+        locker,
+        escrow,
+        locker_treasury,
+        locked_tokens,
+        token_program,
+        vest_started_at,
+        escrow_duration,
+        1,
+        max_reward_amount,
+    )
+}
+
+/// Fills the [NftReceipt] of a locked NFT, returning its max reward.
+///
+/// Without a [VestTier], the reward vests over the max stake duration of its terms.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn fill_nft_receipt(
+    locker: &Account<Locker>,
+    escrow: &Escrow,
+    receipt: &mut NftReceipt,
+    receipt_id: u64,
+    vest_started_at: i64,
+    provenance: &NftProvenance,
+    (reward_params, reward_schedule): (NftRewardParams, Option<NftRewardSchedule>),
+    vest_tier: Option<VestTier>,
+    custodial: bool,
+    duration: i64,
+) -> Result<u64> {
+    receipt.reward_params = reward_params;
+    receipt.reward_schedule = reward_schedule;
+    receipt.vest_tier = vest_tier;
+
+    let vest_duration = match vest_tier {
+        Some(vest_tier) => vest_tier.vest_duration,
+        None => unwrap_int!(receipt.calculate_nft_max_stake_duration()),
+    };
+
+    invariant!(
+        duration >= vest_duration,
+        ProtocolError::LockupDurationTooShort
+    );
+
+    receipt.receipt_id = receipt_id;
+    receipt.locker = locker.key();
+    receipt.owner = escrow.owner;
+    receipt.vest_started_at = vest_started_at;
+    receipt.vest_ends_at = unwrap_int!(receipt.vest_started_at.checked_add(vest_duration));
+    receipt.claimed_amount = 0;
+    receipt.nft_mint = provenance.nft_mint;
+    receipt.proof = provenance.proof;
+    receipt.proof_type = provenance.proof_type.bits();
+    receipt.collection = provenance.collection;
+    receipt.reward_mint = locker.reward_mint;
+    receipt.custodial = custodial;
+    let reward_bps = if custodial {
+        locker.params.nft_custodial_reward_bps
+    } else {
+        MAX_BPS
+    };
+    let tier_bps = vest_tier.map_or(MAX_BPS, |vest_tier| vest_tier.reward_bps);
+    receipt.reward_bps = unwrap_int!((reward_bps as u64)
+        .checked_mul(provenance.weight_bps.into())
+        .and_then(|bps| bps.checked_mul(tier_bps.into()))
+        .and_then(|bps| bps.checked_div(MAX_BPS as u64 * MAX_BPS as u64))
+        .and_then(|bps| bps.to_u16()));
+    receipt.withdrawn = false;
+
+    Ok(unwrap_int!(receipt.calculate_max_reward_amount()))
+}
+
+/// Moves the max rewards of `receipt_count` NFTs locked at `vest_started_at` from the
+/// treasury into the [Escrow], in one transfer, extending its lock to `escrow_duration`.
+///
+/// Rewards in [Locker::token_mint] are locked into the [Escrow], adding to its voting
+/// power; rewards in the reward mint of the [Locker] are only held for the receipts.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn lock_nft_rewards<'info>(
+    locker: &mut Account<'info, Locker>,
+    escrow: &mut Account<'info, Escrow>,
+    locker_treasury: &Account<'info, TokenAccount>,
+    locked_tokens: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    vest_started_at: i64,
+    escrow_duration: i64,
+    receipt_count: u64,
+    max_reward_amount: u64,
+) -> Result<()> {
+    let prev_escrow_ends_at = escrow.escrow_ends_at;
+    let next_escrow_started_at = vest_started_at;
+    let next_escrow_ends_at = unwrap_int!(vest_started_at.checked_add(escrow_duration));
+
+    if locker.reward_mint.is_none() && prev_escrow_ends_at > next_escrow_ends_at {
+        return Err(error!(ProtocolError::RefreshCannotShorten));
     }
 
-    pub fn mint_wl_token(&self, amount: u64) -> Result<()> {
-        let amount = unwrap_int!(amount.checked_mul(10u64.pow(self.wl_token_mint.decimals as u32)));
-        let seeds: &[&[&[u8]]] = locker_seeds!(self.locker);
+    invariant!(
+        locker_treasury.amount >= max_reward_amount,
+        ProtocolError::InsufficientTreasuryFunds
+    );
+
+    let seeds: &[&[&[u8]]] = locker_seeds!(locker);
 
-        token::mint_to(
+    if max_reward_amount > 0 {
+        token::transfer(
             CpiContext::new(
-                self.token_program.to_account_info(),
-                token::MintTo {
-                    to: self.wl_destination.to_account_info(),
-                    mint: self.wl_token_mint.to_account_info(),
-                    authority: self.locker.to_account_info(),
+                token_program.to_account_info(),
+                Transfer {
+                    from: locker_treasury.to_account_info(),
+                    to: locked_tokens.to_account_info(),
+                    authority: locker.to_account_info(),
                 },
             )
             .with_signer(seeds),
-            amount,
+            max_reward_amount,
         )?;
+    }
 
-        Ok(())
+    if locker.reward_mint.is_some() {
+        escrow.receipt_count = unwrap_int!(escrow.receipt_count.checked_add(receipt_count));
+        escrow.reward_amount_to_receipt = unwrap_int!(escrow
+            .reward_amount_to_receipt
+            .checked_add(max_reward_amount));
+
+        return Ok(());
     }
+
+    escrow.update_lock_event(
+        locker,
+        max_reward_amount,
+        next_escrow_started_at,
+        next_escrow_ends_at,
+        receipt_count,
+    )?;
+
+    Ok(())
+}
+
+/// Checks that the NFT rewards move from the treasury of the reward mint of the [Locker]
+/// into the token account of the [Escrow] holding them.
+pub(crate) fn assert_reward_accounts(
+    locker: &Locker,
+    escrow: &Account<Escrow>,
+    locker_treasury: &TokenAccount,
+    reward_tokens: &Account<TokenAccount>,
+) -> Result<()> {
+    assert_keys_eq!(
+        locker_treasury.mint,
+        locker.reward_treasury_mint(),
+        ProtocolError::InvalidToken
+    );
+
+    assert_reward_tokens(escrow, locker.reward_mint, reward_tokens)
+}
+
+/// Checks that `reward_tokens` hold the rewards of `reward_mint` for the receipts of the
+/// [Escrow]: its locked tokens when unset.
+pub(crate) fn assert_reward_tokens(
+    escrow: &Account<Escrow>,
+    reward_mint: Option<Pubkey>,
+    reward_tokens: &Account<TokenAccount>,
+) -> Result<()> {
+    match reward_mint {
+        Some(reward_mint) => {
+            assert_keys_eq!(reward_tokens.mint, reward_mint, ProtocolError::InvalidToken);
+            assert_keys_eq!(
+                reward_tokens.owner,
+                escrow.key(),
+                ProtocolError::InvalidToken
+            );
+        }
+        None => assert_keys_eq!(
+            reward_tokens.key(),
+            escrow.tokens,
+            ProtocolError::InvalidToken
+        ),
+    }
+
+    Ok(())
+}
+
+/// Reward terms and schedule of the NFTs accepted by the already validated [Proof].
+///
+/// Terms set on the [Proof] come with their own halving, otherwise the [RewardSchedule]
+/// of the [Locker] applies when it has one.
+pub(crate) fn nft_reward_terms(
+    proof_info: &AccountInfo,
+    reward_schedule_info: &AccountInfo,
+    locker: &LockerParams,
+) -> Result<(NftRewardParams, Option<NftRewardSchedule>)> {
+    let proof = Account::<Proof>::try_from(proof_info)?;
+
+    if let Some(reward_params) = proof.reward_params {
+        return Ok((reward_params, None));
+    }
+
+    let reward_schedule = if reward_schedule_info.owner == &crate::ID {
+        Some(
+            Account::<RewardSchedule>::try_from(reward_schedule_info)?
+                .schedule
+                .clone(),
+        )
+    } else {
+        None
+    };
+
+    Ok((locker.nft_reward_params(), reward_schedule))
+}
+
+/// The tier at `index` of the [VestTiers] of the [Locker], if one is picked.
+pub(crate) fn picked_vest_tier(
+    vest_tiers_info: &AccountInfo,
+    index: Option<u8>,
+) -> Result<Option<VestTier>> {
+    let index = match index {
+        Some(index) => index as usize,
+        None => return Ok(None),
+    };
+
+    invariant!(
+        vest_tiers_info.owner == &crate::ID,
+        ProtocolError::InvalidVestTier
+    );
+    let vest_tiers = Account::<VestTiers>::try_from(vest_tiers_info)?;
+
+    Ok(Some(unwrap_opt!(
+        vest_tiers.tiers.get(index).copied(),
+        ProtocolError::InvalidVestTier
+    )))
 }
 
 fn assert_valid_metadata(
@@ -141,6 +473,11 @@ fn assert_valid_metadata(
     metadata_program: &Pubkey,
     mint: &Pubkey,
 ) -> Result<Metadata> {
+    assert_keys_eq!(
+        *metadata_program,
+        mpl_token_metadata::ID,
+        ProtocolError::InvalidProgramId
+    );
     assert_keys_eq!(
         *metadata.owner,
         *metadata_program,
@@ -163,7 +500,7 @@ fn assert_valid_metadata(
     Metadata::from_account_info(metadata).map_err(Into::into)
 }
 
-fn assert_valid_proof(
+pub(crate) fn assert_valid_proof(
     proof_info: &AccountInfo,
     locker: &Pubkey,
     proof_address: &Pubkey,
@@ -187,103 +524,421 @@ fn assert_valid_proof(
     proof.contains_type(expected_proof_type)
 }
 
-fn check_accounts(ctx: &Context<LockNft>) -> Result<()> {
-    if ctx.remaining_accounts.len() < 5 {
-        return Err(error!(ProtocolError::InvalidRemainingAccounts));
+/// Accounts of an NFT being burnt against a lock.
+pub(crate) struct NftBurnAccounts<'a, 'info> {
+    pub locker: Pubkey,
+    pub payer: &'a AccountInfo<'info>,
+    pub nft_source: &'a AccountInfo<'info>,
+    pub nft_source_authority: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> NftBurnAccounts<'a, 'info> {
+    /// Checks the remaining accounts and the [Proof] of the NFT, returning its standard.
+    pub fn check_accounts(
+        &self,
+        merkle_proof: Option<&MintMerkleProof>,
+    ) -> Result<(NftStandard, NftProvenance)> {
+        if self.remaining_accounts.len() < 2 {
+            return Err(error!(ProtocolError::InvalidRemainingAccounts));
+        }
+
+        let accounts_iter = &mut self.remaining_accounts.iter();
+        let proof_info = next_account_info(accounts_iter)?;
+        let nft_program = next_account_info(accounts_iter)?;
+
+        if nft_program.key() == nft::mpl_core::ID {
+            let asset = CoreAsset::from_account_info(self.nft_source)?;
+            assert_keys_eq!(
+                asset.owner,
+                self.nft_source_authority.key(),
+                ProtocolError::InvalidTokenOwner
+            );
+            let provenance = assert_valid_core_asset(
+                &self.locker,
+                &crate::ID,
+                proof_info,
+                &self.nft_source.key(),
+                &asset,
+                merkle_proof,
+            )?;
+
+            return Ok((NftStandard::Core, provenance));
+        }
+
+        if self.remaining_accounts.len() < 5 {
+            return Err(error!(ProtocolError::InvalidRemainingAccounts));
+        }
+
+        let nft_metadata = next_account_info(accounts_iter)?;
+        let nft_mint = next_account_info(accounts_iter)?;
+
+        let provenance = assert_valid_nft(
+            &self.locker,
+            &crate::ID,
+            proof_info,
+            nft_program,
+            nft_metadata,
+            nft_mint.key,
+            merkle_proof,
+        )?;
+
+        let standard = NftStandard::from_metadata(nft_metadata)?;
+        if standard == NftStandard::ProgrammableNonFungible && self.remaining_accounts.len() < 7 {
+            return Err(error!(ProtocolError::InvalidRemainingAccounts));
+        }
+
+        Ok((standard, provenance))
     }
 
-    let accounts_iter = &mut ctx.remaining_accounts.iter();
-    let proof_info = next_account_info(accounts_iter)?;
-    let metaplex_metadata_program = next_account_info(accounts_iter)?;
-    let nft_metadata = next_account_info(accounts_iter)?;
-    let nft_mint = next_account_info(accounts_iter)?;
+    /// The [Proof] of the NFT.
+    pub fn proof(&self) -> &'a AccountInfo<'info> {
+        &self.remaining_accounts[0]
+    }
 
-    if let Ok(()) = assert_valid_proof(
+    pub fn burn(&self, standard: NftStandard) -> Result<()> {
+        match standard {
+            NftStandard::NonFungible => self.burn_legacy_nft(),
+            NftStandard::ProgrammableNonFungible => self.burn_programmable_nft(),
+            NftStandard::Core => self.burn_core_asset(),
+        }
+    }
+}
+
+/// Checks that the Metaplex Core asset is covered by a [Proof] of the [Locker], either
+/// by its address or its collection. An update authority set to an address is not a
+/// verified creator of the asset, so it is not matched against creator proofs.
+fn assert_valid_core_asset(
+    locker: &Pubkey,
+    program_id: &Pubkey,
+    proof_info: &AccountInfo,
+    asset_address: &Pubkey,
+    asset: &CoreAsset,
+    merkle_proof: Option<&MintMerkleProof>,
+) -> Result<NftProvenance> {
+    if let Some(merkle_proof) = merkle_proof {
+        assert_valid_merkle_proof(proof_info, locker, program_id, asset_address, merkle_proof)?;
+
+        return Ok(NftProvenance {
+            nft_mint: *asset_address,
+            proof: proof_info.key(),
+            proof_type: ProofType::MERKLE,
+            collection: asset.collection(),
+            weight_bps: merkle_proof.weight_bps,
+        });
+    }
+
+    let proof_type = if let Ok(()) = assert_valid_proof(
         proof_info,
-        &ctx.accounts.locker.key(),
-        &nft_mint.key(),
-        ctx.program_id,
+        locker,
+        asset_address,
+        program_id,
         ProofType::MINT,
     ) {
-        return Ok(());
+        ProofType::MINT
+    } else {
+        let collection = unwrap_opt!(asset.collection(), ProtocolError::InvalidProof);
+        assert_valid_proof(
+            proof_info,
+            locker,
+            &collection,
+            program_id,
+            ProofType::COLLECTION,
+        )?;
+        ProofType::COLLECTION
+    };
+
+    Ok(NftProvenance {
+        nft_mint: *asset_address,
+        proof: proof_info.key(),
+        proof_type,
+        collection: asset.collection(),
+        weight_bps: MAX_BPS,
+    })
+}
+
+/// Checks that the mint is in the allowlist of a [ProofType::MERKLE] [Proof] of the
+/// [Locker], according to `merkle_proof`.
+fn assert_valid_merkle_proof(
+    proof_info: &AccountInfo,
+    locker: &Pubkey,
+    program_id: &Pubkey,
+    nft_mint: &Pubkey,
+    merkle_proof: &MintMerkleProof,
+) -> Result<()> {
+    let proof = Account::<Proof>::try_from(proof_info)?;
+    assert_valid_proof(
+        proof_info,
+        locker,
+        &proof.proof_address,
+        program_id,
+        ProofType::MERKLE,
+    )?;
+
+    invariant!(
+        merkle_proof.weight_bps <= MAX_BPS
+            && merkle::verify(
+                &merkle_proof.path,
+                &proof.proof_address.to_bytes(),
+                leaf_hash(nft_mint, merkle_proof.weight_bps),
+            ),
+        ProtocolError::InvalidMerkleProof
+    );
+
+    Ok(())
+}
+
+/// Checks that the NFT is covered by a [Proof] of the [Locker], either by its mint,
+/// one of its verified creators or its verified collection, or by the merkle allowlist
+/// of the [Proof] when `merkle_proof` is passed.
+pub(crate) fn assert_valid_nft(
+    locker: &Pubkey,
+    program_id: &Pubkey,
+    proof_info: &AccountInfo,
+    metaplex_metadata_program: &AccountInfo,
+    nft_metadata: &AccountInfo,
+    nft_mint: &Pubkey,
+    merkle_proof: Option<&MintMerkleProof>,
+) -> Result<NftProvenance> {
+    let metadata = assert_valid_metadata(nft_metadata, metaplex_metadata_program.key, nft_mint)?;
+    let collection = verified_collection(nft_metadata)?;
+
+    let provenance = |proof_type| NftProvenance {
+        nft_mint: *nft_mint,
+        proof: proof_info.key(),
+        proof_type,
+        collection,
+        weight_bps: MAX_BPS,
+    };
+
+    if let Some(merkle_proof) = merkle_proof {
+        assert_valid_merkle_proof(proof_info, locker, program_id, nft_mint, merkle_proof)?;
+
+        return Ok(NftProvenance {
+            weight_bps: merkle_proof.weight_bps,
+            ..provenance(ProofType::MERKLE)
+        });
     }
 
-    let metadata =
-        assert_valid_metadata(nft_metadata, metaplex_metadata_program.key, nft_mint.key)?;
+    if let Ok(()) = assert_valid_proof(proof_info, locker, nft_mint, program_id, ProofType::MINT) {
+        return Ok(provenance(ProofType::MINT));
+    }
 
-    for creator in metadata.data.creators.unwrap() {
+    for creator in metadata.data.creators.iter().flatten() {
         if !creator.verified {
             continue;
         }
 
         if let Ok(()) = assert_valid_proof(
             proof_info,
-            &ctx.accounts.locker.key(),
+            locker,
             &creator.address,
-            ctx.program_id,
+            program_id,
             ProofType::CREATOR,
         ) {
-            return Ok(());
+            return Ok(provenance(ProofType::CREATOR));
         }
     }
 
-    Err(error!(ProtocolError::InvalidProof))
+    match collection {
+        Some(collection) => {
+            assert_valid_proof(
+                proof_info,
+                locker,
+                &collection,
+                program_id,
+                ProofType::COLLECTION,
+            )?;
+            Ok(provenance(ProofType::COLLECTION))
+        }
+        None => Err(error!(ProtocolError::InvalidProof)),
+    }
 }
 
-fn burn_nft<'info>(ctx: &Context<'_, '_, '_, 'info, LockNft<'info>>) -> Result<()> {
-    let accounts_iter = &mut ctx.remaining_accounts.iter();
-    let _proof_info = next_account_info(accounts_iter)?;
-    let metaplex_metadata_program = next_account_info(accounts_iter)?;
-    let nft_metadata = next_account_info(accounts_iter)?;
-    let nft_mint = next_account_info(accounts_iter)?;
-    let nft_edition = next_account_info(accounts_iter)?;
-
-    let mut account_infos: Vec<AccountInfo> = vec![
-        metaplex_metadata_program.to_account_info(),
-        nft_metadata.to_account_info(),
-        ctx.accounts.nft_source_authority.to_account_info(),
-        nft_mint.to_account_info(),
-        ctx.accounts.nft_source.to_account_info(),
-        nft_edition.to_account_info(),
-        ctx.accounts.token_program.to_account_info(),
-    ];
+impl<'a, 'info> NftBurnAccounts<'a, 'info> {
+    fn burn_legacy_nft(&self) -> Result<()> {
+        let accounts_iter = &mut self.remaining_accounts.iter();
+        let _proof_info = next_account_info(accounts_iter)?;
+        let metaplex_metadata_program = next_account_info(accounts_iter)?;
+        let nft_metadata = next_account_info(accounts_iter)?;
+        let nft_mint = next_account_info(accounts_iter)?;
+        let nft_edition = next_account_info(accounts_iter)?;
+
+        let mut account_infos: Vec<AccountInfo> = vec![
+            metaplex_metadata_program.to_account_info(),
+            nft_metadata.to_account_info(),
+            self.nft_source_authority.clone(),
+            nft_mint.to_account_info(),
+            self.nft_source.clone(),
+            nft_edition.to_account_info(),
+            self.token_program.clone(),
+        ];
+
+        let nft_collection_metadata = if self.remaining_accounts.len() == 6 {
+            let nft_collection_metadata_info = next_account_info(accounts_iter)?;
+            account_infos.append(&mut vec![nft_collection_metadata_info.to_account_info()]);
+            Some(nft_collection_metadata_info.key())
+        } else {
+            None
+        };
+
+        invoke(
+            &mpl_token_metadata::instruction::burn_nft(
+                metaplex_metadata_program.key(),
+                nft_metadata.key(),
+                self.nft_source_authority.key(),
+                nft_mint.key(),
+                self.nft_source.key(),
+                nft_edition.key(),
+                self.token_program.key(),
+                nft_collection_metadata,
+            ),
+            account_infos.as_slice(),
+        )?;
 
-    let nft_collection_metadata = if ctx.remaining_accounts.len() == 6 {
-        let nft_collection_metadata_info = next_account_info(accounts_iter)?;
-        account_infos.append(&mut vec![nft_collection_metadata_info.to_account_info()]);
-        Some(nft_collection_metadata_info.key())
-    } else {
-        None
-    };
+        Ok(())
+    }
 
-    invoke(
-        &mpl_token_metadata::instruction::burn_nft(
-            metaplex_metadata_program.key(),
-            nft_metadata.key(),
-            ctx.accounts.nft_source_authority.key(),
-            nft_mint.key(),
-            ctx.accounts.nft_source.key(),
-            nft_edition.key(),
-            ctx.accounts.token_program.key(),
-            nft_collection_metadata,
-        ),
-        account_infos.as_slice(),
-    )?;
+    fn burn_programmable_nft(&self) -> Result<()> {
+        let accounts_iter = &mut self.remaining_accounts.iter();
+        let _proof_info = next_account_info(accounts_iter)?;
+        let metaplex_metadata_program = next_account_info(accounts_iter)?;
+        let nft_metadata = next_account_info(accounts_iter)?;
+        let nft_mint = next_account_info(accounts_iter)?;
+        let nft_edition = next_account_info(accounts_iter)?;
+        let nft_token_record = next_account_info(accounts_iter)?;
+        let sysvar_instructions = next_account_info(accounts_iter)?;
+
+        let mut account_infos: Vec<AccountInfo> = vec![
+            metaplex_metadata_program.to_account_info(),
+            self.nft_source_authority.clone(),
+            nft_metadata.to_account_info(),
+            nft_edition.to_account_info(),
+            nft_mint.to_account_info(),
+            self.nft_source.clone(),
+            nft_token_record.to_account_info(),
+            self.system_program.clone(),
+            sysvar_instructions.to_account_info(),
+            self.token_program.clone(),
+        ];
+
+        let nft_collection_metadata = if self.remaining_accounts.len() == 8 {
+            let nft_collection_metadata_info = next_account_info(accounts_iter)?;
+            account_infos.append(&mut vec![nft_collection_metadata_info.to_account_info()]);
+            Some(nft_collection_metadata_info.key())
+        } else {
+            None
+        };
+
+        invoke(
+            &nft::burn_programmable_nft(
+                self.nft_source_authority.key(),
+                nft_collection_metadata,
+                nft_metadata.key(),
+                nft_edition.key(),
+                nft_mint.key(),
+                self.nft_source.key(),
+                nft_token_record.key(),
+                self.token_program.key(),
+            ),
+            account_infos.as_slice(),
+        )?;
 
-    Ok(())
+        Ok(())
+    }
+
+    fn burn_core_asset(&self) -> Result<()> {
+        let accounts_iter = &mut self.remaining_accounts.iter();
+        let _proof_info = next_account_info(accounts_iter)?;
+        let mpl_core_program = next_account_info(accounts_iter)?;
+
+        let mut account_infos: Vec<AccountInfo> = vec![
+            mpl_core_program.to_account_info(),
+            self.nft_source.clone(),
+            self.payer.clone(),
+            self.nft_source_authority.clone(),
+            self.system_program.clone(),
+        ];
+
+        let asset = CoreAsset::from_account_info(self.nft_source)?;
+        let collection = match asset.collection() {
+            Some(collection) => {
+                let collection_info = next_account_info(accounts_iter)?;
+                assert_keys_eq!(
+                    collection_info.key(),
+                    collection,
+                    ProtocolError::MetadataMismatch
+                );
+                account_infos.append(&mut vec![collection_info.to_account_info()]);
+                Some(collection)
+            }
+            None => None,
+        };
+
+        invoke(
+            &nft::burn_core_asset(
+                self.nft_source.key(),
+                collection,
+                self.payer.key(),
+                self.nft_source_authority.key(),
+            ),
+            account_infos.as_slice(),
+        )?;
+
+        Ok(())
+    }
 }
 
+/// Remaining accounts by the standard of the NFT:
+/// - legacy: `[proof, token_metadata_program, metadata, mint, edition, (collection_metadata)]`
+/// - programmable: `[proof, token_metadata_program, metadata, mint, edition, token_record,
+///   sysvar_instructions, (collection_metadata)]`
+/// - Metaplex Core: `[proof, mpl_core_program, (collection)]`, with the asset as `nft_source`
+///
+/// With `merkle_proof`, `proof` is a [ProofType::MERKLE] [Proof] whose allowlist must
+/// contain the mint, or the asset for Metaplex Core.
 pub fn handler<'info>(
     ctx: Context<'_, '_, '_, 'info, LockNft<'info>>,
     duration: i64,
+    merkle_proof: Option<MintMerkleProof>,
+    vest_tier: Option<u8>,
 ) -> Result<()> {
-    check_accounts(&ctx)?;
-
-    ctx.accounts.process(duration)?;
-
-    burn_nft(&ctx)?;
-
-    ctx.accounts.mint_wl_token(1)?;
+    let nft = ctx.accounts.nft_burn_accounts(ctx.remaining_accounts);
+    let (standard, provenance) = nft.check_accounts(merkle_proof.as_ref())?;
+    let reward_terms = nft_reward_terms(
+        nft.proof(),
+        &ctx.accounts.reward_schedule,
+        &ctx.accounts.locker.params,
+    )?;
+    let wl_token_amount = reward_terms.0.wl_token_amount;
+    let vest_tier = picked_vest_tier(&ctx.accounts.vest_tiers, vest_tier)?;
+
+    ctx.accounts
+        .process(duration, &provenance, reward_terms, vest_tier)?;
+    ctx.accounts.record_campaign_burn(&provenance)?;
+
+    ctx.accounts
+        .nft_burn_accounts(ctx.remaining_accounts)
+        .burn(standard)?;
+
+    ctx.accounts.mint_wl_token(wl_token_amount)?;
+
+    let receipt = &ctx.accounts.receipt;
+
+    emit!(LockNftEvent {
+        locker: receipt.locker,
+        escrow_owner: receipt.owner,
+        receipt_id: receipt.receipt_id,
+        nft_mint: receipt.nft_mint,
+        proof: receipt.proof,
+        proof_type: receipt.proof_type,
+        collection: receipt.collection,
+        reward_amount: unwrap_int!(receipt.calculate_max_reward_amount()),
+        wl_token_amount,
+        vest_ends_at: receipt.vest_ends_at,
+    });
 
     Ok(())
 }
@@ -300,11 +955,12 @@ impl<'info> Validate<'info> for LockNft<'info> {
             self.escrow.locker,
             ProtocolError::InvalidLocker
         );
-        assert_keys_eq!(
-            self.locked_tokens,
-            self.escrow.tokens,
-            ProtocolError::InvalidToken
-        );
+        assert_reward_accounts(
+            &self.locker,
+            &self.escrow,
+            &self.locker_treasury,
+            &self.locked_tokens,
+        )?;
         assert_keys_eq!(
             self.escrow_owner,
             self.escrow.owner,
@@ -314,3 +970,29 @@ impl<'info> Validate<'info> for LockNft<'info> {
         Ok(())
     }
 }
+
+#[event]
+/// Event called in [ve_honey::lock_nft].
+pub struct LockNftEvent {
+    /// [Locker] of the [Escrow].
+    #[index]
+    pub locker: Pubkey,
+    /// The owner of the [Escrow].
+    pub escrow_owner: Pubkey,
+    /// Id of the [NftReceipt].
+    pub receipt_id: u64,
+    /// Mint of the burnt NFT, or its asset for Metaplex Core.
+    pub nft_mint: Pubkey,
+    /// [Proof] that accepted the NFT.
+    pub proof: Pubkey,
+    /// Type of the [Proof] that matched the NFT.
+    pub proof_type: u8,
+    /// Verified collection of the NFT.
+    pub collection: Option<Pubkey>,
+    /// Reward of the [NftReceipt] once fully vested.
+    pub reward_amount: u64,
+    /// Number of whole WL tokens minted.
+    pub wl_token_amount: u64,
+    /// When the reward of the [NftReceipt] is fully vested.
+    pub vest_ends_at: i64,
+}
@@ -0,0 +1,254 @@
+use crate::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use num_traits::ToPrimitive;
+
+/// Number of remaining accounts of each NFT of [LockNftBatch].
+pub const LOCK_NFT_BATCH_STRIDE: usize = 7;
+
+/// Locks several legacy NFTs at once, recording their receipts in the [ReceiptLedger] of
+/// the [Escrow].
+#[derive(Accounts)]
+pub struct LockNftBatch<'info> {
+    /// payer of the rent of the grown [ReceiptLedger].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// [Locker].
+    #[account(mut)]
+    pub locker: Box<Account<'info, Locker>>,
+    /// [Escrow].
+    #[account(mut)]
+    pub escrow: Box<Account<'info, Escrow>>,
+    /// [ReceiptLedger] of the [Escrow].
+    #[account(mut)]
+    pub ledger: AccountLoader<'info, ReceiptLedger>,
+    /// Authority of the [Escrow].
+    pub escrow_owner: Signer<'info>,
+    /// locked tokens.
+    #[account(mut)]
+    pub locked_tokens: Box<Account<'info, TokenAccount>>,
+    /// locker treasury.
+    #[account(mut)]
+    pub locker_treasury: Box<Account<'info, TokenAccount>>,
+    /// authority of the nfts.
+    pub nft_source_authority: Signer<'info>,
+    /// CHECK: [RewardSchedule] of the [Locker], which may not exist.
+    #[account(
+        seeds = [REWARD_SCHEDULE_SEED.as_bytes(), locker.key().as_ref()],
+        bump,
+    )]
+    pub reward_schedule: UncheckedAccount<'info>,
+    /// CHECK: [Campaign] of the [Locker], which may not exist.
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_SEED.as_bytes(), locker.key().as_ref()],
+        bump,
+    )]
+    pub campaign: UncheckedAccount<'info>,
+    /// CHECK: [BurnCounter] of the [Escrow] in the [Campaign]; checked when the [Campaign]
+    /// exists.
+    #[account(mut)]
+    pub escrow_burns: UncheckedAccount<'info>,
+    /// WL token mint
+    #[account(mut)]
+    pub wl_token_mint: Box<Account<'info, Mint>>,
+    /// WL token destination.
+    #[account(mut)]
+    pub wl_destination: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: token metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+    /// system program
+    pub system_program: Program<'info, System>,
+    /// token program
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> LockNftBatch<'info> {
+    /// [NftBurnAccounts] of each NFT, laid out as for a legacy NFT in [LockNft].
+    fn nft_burn_accounts<'a>(
+        &'a self,
+        nfts: &'a [Vec<AccountInfo<'info>>],
+        nft_sources: &'a [AccountInfo<'info>],
+    ) -> impl Iterator<Item = NftBurnAccounts<'a, 'info>> {
+        nfts.iter()
+            .zip(nft_sources.iter())
+            .map(move |(remaining_accounts, nft_source)| NftBurnAccounts {
+                locker: self.locker.key(),
+                payer: &self.payer,
+                nft_source,
+                nft_source_authority: &self.nft_source_authority,
+                system_program: &self.system_program,
+                token_program: &self.token_program,
+                remaining_accounts,
+            })
+    }
+}
+
+/// Remaining accounts: `[proof, metadata, mint, edition, collection_metadata,
+/// collection_burns, token_account]` for each NFT. An NFT without a verified collection
+/// takes the token metadata program in its `collection_metadata` slot. `collection_burns`
+/// is the [BurnCounter] of the collection of the NFT in the [Campaign], as in [LockNft].
+///
+/// Programmable NFTs need more accounts to be burnt, so they are locked with [LockNft]
+/// instead. The receipts are recorded in the [ReceiptLedger], which must be initialized
+/// with [ve_honey::init_receipt_ledger] first. Ledger records hold no reward mint nor vest
+/// tier, so a [Locker] vesting its rewards in a reward mint locks NFTs with [LockNft], as
+/// do NFTs locked with a vest tier.
+pub fn batch_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, LockNftBatch<'info>>,
+    duration: i64,
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+
+    invariant!(
+        !remaining_accounts.is_empty()
+            && remaining_accounts
+                .chunks_exact(LOCK_NFT_BATCH_STRIDE)
+                .remainder()
+                .is_empty(),
+        ProtocolError::InvalidRemainingAccounts
+    );
+
+    let token_metadata_program = ctx.accounts.token_metadata_program.to_account_info();
+    let mut nfts = Vec::with_capacity(remaining_accounts.len() / LOCK_NFT_BATCH_STRIDE);
+    let mut collection_burns = Vec::with_capacity(nfts.capacity());
+    let mut nft_sources = Vec::with_capacity(nfts.capacity());
+    for nft in remaining_accounts.chunks(LOCK_NFT_BATCH_STRIDE) {
+        let mut accounts = vec![
+            nft[0].clone(),
+            token_metadata_program.clone(),
+            nft[1].clone(),
+            nft[2].clone(),
+            nft[3].clone(),
+        ];
+        if nft[4].key() != mpl_token_metadata::ID {
+            accounts.push(nft[4].clone());
+        }
+
+        nfts.push(accounts);
+        collection_burns.push(nft[5].clone());
+        nft_sources.push(nft[6].clone());
+    }
+
+    let vest_started_at = Clock::get()?.unix_timestamp;
+    let mut receipts = Vec::with_capacity(nfts.len());
+    let mut burns = Vec::with_capacity(nfts.len());
+    let mut max_reward_amount: u64 = 0;
+    let mut wl_token_amount: u64 = 0;
+
+    for nft in ctx.accounts.nft_burn_accounts(&nfts, &nft_sources) {
+        let (standard, provenance) = nft.check_accounts(None)?;
+        invariant!(
+            standard == NftStandard::NonFungible,
+            ProtocolError::InvalidRemainingAccounts
+        );
+
+        let reward_terms = nft_reward_terms(
+            nft.proof(),
+            &ctx.accounts.reward_schedule,
+            &ctx.accounts.locker.params,
+        )?;
+        wl_token_amount = unwrap_int!(wl_token_amount.checked_add(reward_terms.0.wl_token_amount));
+
+        let mut receipt = NftReceipt::default();
+        let receipt_id = unwrap_int!(ctx
+            .accounts
+            .escrow
+            .receipt_count
+            .checked_add(receipts.len() as u64));
+        let reward_amount = fill_nft_receipt(
+            &ctx.accounts.locker,
+            &ctx.accounts.escrow,
+            &mut receipt,
+            receipt_id,
+            vest_started_at,
+            &provenance,
+            reward_terms,
+            None,
+            false,
+            duration,
+        )?;
+        max_reward_amount = unwrap_int!(max_reward_amount.checked_add(reward_amount));
+
+        receipts.push(receipt);
+        burns.push((provenance.campaign_collection(), reward_amount));
+    }
+
+    let accounts = ctx.accounts;
+    let escrow = accounts.escrow.key();
+    for ((collection, reward_amount), collection_burns) in burns.iter().zip(&collection_burns) {
+        CampaignAccounts {
+            campaign: &accounts.campaign,
+            collection_burns,
+            escrow_burns: &accounts.escrow_burns,
+            payer: &accounts.payer,
+            system_program: &accounts.system_program,
+        }
+        .record_burn(&mut accounts.locker, &escrow, collection, *reward_amount)?;
+    }
+    let escrow_duration = unwrap_int!(accounts.locker.params.max_stake_duration.to_i64());
+
+    lock_nft_rewards(
+        &mut accounts.locker,
+        &mut accounts.escrow,
+        &accounts.locker_treasury,
+        &accounts.locked_tokens,
+        &accounts.token_program,
+        vest_started_at,
+        escrow_duration,
+        receipts.len() as u64,
+        max_reward_amount,
+    )?;
+
+    for nft in accounts.nft_burn_accounts(&nfts, &nft_sources) {
+        nft.burn(NftStandard::NonFungible)?;
+    }
+
+    let ledger = accounts.ledger.to_account_info();
+    for receipt in receipts.iter() {
+        push_ledger_receipt(
+            &ledger,
+            &accounts.payer,
+            &accounts.system_program,
+            LedgerReceipt::from_nft_receipt(receipt)?,
+        )?;
+    }
+
+    mint_wl_token(
+        &accounts.locker,
+        &accounts.wl_token_mint,
+        &accounts.wl_destination,
+        &accounts.token_program,
+        wl_token_amount,
+    )?;
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for LockNftBatch<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_valid_ledger(&self.ledger, &self.locker, &self.escrow)?;
+        assert_keys_eq!(
+            self.wl_token_mint,
+            self.locker.wl_token_mint,
+            ProtocolError::InvalidLockerWLMint
+        );
+        assert_keys_eq!(
+            self.locked_tokens,
+            self.escrow.tokens,
+            ProtocolError::InvalidToken
+        );
+        invariant!(
+            self.locker.reward_mint.is_none(),
+            ProtocolError::RewardMintNotSupported
+        );
+        assert_keys_eq!(
+            self.escrow_owner,
+            self.escrow.owner,
+            ProtocolError::InvalidAccountOwner
+        );
+
+        Ok(())
+    }
+}
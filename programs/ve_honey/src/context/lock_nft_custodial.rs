@@ -0,0 +1,239 @@
+use crate::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct LockNftCustodial<'info> {
+    /// payer of the initialization of [NftReceipt] and the NFT vault.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// [Locker].
+    #[account(mut)]
+    pub locker: Box<Account<'info, Locker>>,
+    /// [Escrow].
+    #[account(mut)]
+    pub escrow: Box<Account<'info, Escrow>>,
+    /// [NftReceipt].
+    #[account(
+        init,
+        seeds = [
+            NFT_RECEIPT_SEED.as_bytes(),
+            locker.key().as_ref(),
+            escrow_owner.key().as_ref(),
+            escrow.receipt_count.to_le_bytes().as_ref(),
+        ],
+        bump,
+        space = 8 + NftReceipt::LEN,
+        payer = payer,
+    )]
+    pub receipt: Box<Account<'info, NftReceipt>>,
+    /// Authority of the [Escrow].
+    pub escrow_owner: Signer<'info>,
+    /// locked tokens, or the tokens of the [Escrow] in the reward mint of the [Locker].
+    #[account(mut)]
+    pub locked_tokens: Box<Account<'info, TokenAccount>>,
+    /// locker treasury of the reward mint.
+    #[account(mut)]
+    pub locker_treasury: Box<Account<'info, TokenAccount>>,
+    /// nft mint.
+    pub nft_mint: Box<Account<'info, Mint>>,
+    /// nft source token account.
+    #[account(mut)]
+    pub nft_source: Box<Account<'info, TokenAccount>>,
+    /// authority of the nft.
+    pub nft_source_authority: Signer<'info>,
+    /// CHECK: [RewardSchedule] of the [Locker], which may not exist.
+    #[account(
+        seeds = [REWARD_SCHEDULE_SEED.as_bytes(), locker.key().as_ref()],
+        bump,
+    )]
+    pub reward_schedule: UncheckedAccount<'info>,
+    /// CHECK: [VestTiers] of the [Locker], which may not exist.
+    #[account(
+        seeds = [VEST_TIERS_SEED.as_bytes(), locker.key().as_ref()],
+        bump,
+    )]
+    pub vest_tiers: UncheckedAccount<'info>,
+    /// CHECK: [Campaign] of the [Locker], which may not exist.
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_SEED.as_bytes(), locker.key().as_ref()],
+        bump,
+    )]
+    pub campaign: UncheckedAccount<'info>,
+    /// CHECK: [BurnCounter] of the collection of the NFT in the [Campaign]; checked against
+    /// the NFT when the [Campaign] exists.
+    #[account(mut)]
+    pub collection_burns: UncheckedAccount<'info>,
+    /// CHECK: [BurnCounter] of the [Escrow] in the [Campaign]; checked when the [Campaign]
+    /// exists.
+    #[account(mut)]
+    pub escrow_burns: UncheckedAccount<'info>,
+    /// vault of the [Locker] holding the nft until the vest ends.
+    #[account(
+        init,
+        token::mint = nft_mint,
+        token::authority = locker,
+        seeds = [
+            NFT_VAULT_SEED.as_bytes(),
+            locker.key().as_ref(),
+            nft_mint.key().as_ref(),
+        ],
+        bump,
+        payer = payer,
+    )]
+    pub nft_vault: Box<Account<'info, TokenAccount>>,
+
+    /// system program
+    pub system_program: Program<'info, System>,
+    /// token program
+    pub token_program: Program<'info, Token>,
+    /// rent sysvar
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> LockNftCustodial<'info> {
+    pub fn process(
+        &mut self,
+        duration: i64,
+        provenance: &NftProvenance,
+        reward_terms: (NftRewardParams, Option<NftRewardSchedule>),
+        vest_tier: Option<VestTier>,
+    ) -> Result<()> {
+        lock_nft_reward(
+            &mut self.locker,
+            &mut self.escrow,
+            &mut self.receipt,
+            &self.locker_treasury,
+            &self.locked_tokens,
+            &self.token_program,
+            provenance,
+            reward_terms,
+            vest_tier,
+            true,
+            duration,
+        )?;
+        CampaignAccounts {
+            campaign: &self.campaign,
+            collection_burns: &self.collection_burns,
+            escrow_burns: &self.escrow_burns,
+            payer: &self.payer,
+            system_program: &self.system_program,
+        }
+        .record_burn(
+            &mut self.locker,
+            &self.escrow.key(),
+            &provenance.campaign_collection(),
+            unwrap_int!(self.receipt.calculate_max_reward_amount()),
+        )?;
+
+        token::transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.nft_source.to_account_info(),
+                    to: self.nft_vault.to_account_info(),
+                    authority: self.nft_source_authority.to_account_info(),
+                },
+            ),
+            1,
+        )?;
+
+        emit!(LockNftCustodialEvent {
+            locker: self.locker.key(),
+            escrow_owner: self.escrow.owner,
+            receipt_id: self.receipt.receipt_id,
+            nft_mint: self.receipt.nft_mint,
+            vest_ends_at: self.receipt.vest_ends_at,
+        });
+
+        Ok(())
+    }
+}
+
+/// Remaining accounts: `[proof, token_metadata_program, metadata]`.
+///
+/// The NFT is held until the reward vests over the [VestTier] at index `vest_tier`, if
+/// any.
+pub fn custodial_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, LockNftCustodial<'info>>,
+    duration: i64,
+    vest_tier: Option<u8>,
+) -> Result<()> {
+    if ctx.remaining_accounts.len() < 3 {
+        return Err(error!(ProtocolError::InvalidRemainingAccounts));
+    }
+
+    let accounts_iter = &mut ctx.remaining_accounts.iter();
+    let proof_info = next_account_info(accounts_iter)?;
+    let metaplex_metadata_program = next_account_info(accounts_iter)?;
+    let nft_metadata = next_account_info(accounts_iter)?;
+
+    let provenance = assert_valid_nft(
+        &ctx.accounts.locker.key(),
+        ctx.program_id,
+        proof_info,
+        metaplex_metadata_program,
+        nft_metadata,
+        &ctx.accounts.nft_mint.key(),
+        None,
+    )?;
+
+    let reward_terms = nft_reward_terms(
+        proof_info,
+        &ctx.accounts.reward_schedule,
+        &ctx.accounts.locker.params,
+    )?;
+    let vest_tier = picked_vest_tier(&ctx.accounts.vest_tiers, vest_tier)?;
+    ctx.accounts
+        .process(duration, &provenance, reward_terms, vest_tier)?;
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for LockNftCustodial<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.locker,
+            self.escrow.locker,
+            ProtocolError::InvalidLocker
+        );
+        assert_reward_accounts(
+            &self.locker,
+            &self.escrow,
+            &self.locker_treasury,
+            &self.locked_tokens,
+        )?;
+        assert_keys_eq!(
+            self.escrow_owner,
+            self.escrow.owner,
+            ProtocolError::InvalidAccountOwner
+        );
+        invariant!(
+            self.nft_mint.supply == 1 && self.nft_mint.decimals == 0,
+            ProtocolError::InvalidNftMint
+        );
+        assert_keys_eq!(
+            self.nft_source.mint,
+            self.nft_mint,
+            ProtocolError::InvalidNftMint
+        );
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event called in [ve_honey::lock_nft_custodial].
+pub struct LockNftCustodialEvent {
+    /// [Locker] of the [Escrow].
+    #[index]
+    pub locker: Pubkey,
+    /// The owner of the [Escrow].
+    pub escrow_owner: Pubkey,
+    /// Id of the [NftReceipt].
+    pub receipt_id: u64,
+    /// Mint of the NFT held in the vault.
+    pub nft_mint: Pubkey,
+    /// When the NFT can be withdrawn.
+    pub vest_ends_at: i64,
+}
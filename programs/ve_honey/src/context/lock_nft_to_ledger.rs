@@ -0,0 +1,187 @@
+use crate::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+/// Locks an NFT like [LockNft], recording its receipt in the [ReceiptLedger] of the
+/// [Escrow] instead of a new [NftReceipt] account.
+#[derive(Accounts)]
+pub struct LockNftToLedger<'info> {
+    /// payer of the rent of the grown [ReceiptLedger].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// [Locker].
+    #[account(mut)]
+    pub locker: Box<Account<'info, Locker>>,
+    /// [Escrow].
+    #[account(mut)]
+    pub escrow: Box<Account<'info, Escrow>>,
+    /// [ReceiptLedger] of the [Escrow].
+    #[account(mut)]
+    pub ledger: AccountLoader<'info, ReceiptLedger>,
+    /// Authority of the [Escrow].
+    pub escrow_owner: Signer<'info>,
+    /// locked tokens.
+    #[account(mut)]
+    pub locked_tokens: Box<Account<'info, TokenAccount>>,
+    /// locker treasury.
+    #[account(mut)]
+    pub locker_treasury: Box<Account<'info, TokenAccount>>,
+    /// CHECK: nft source token account, or the asset for Metaplex Core; validated by the
+    /// burning program.
+    #[account(mut)]
+    pub nft_source: UncheckedAccount<'info>,
+    /// authority of the nft.
+    pub nft_source_authority: Signer<'info>,
+    /// CHECK: [RewardSchedule] of the [Locker], which may not exist.
+    #[account(
+        seeds = [REWARD_SCHEDULE_SEED.as_bytes(), locker.key().as_ref()],
+        bump,
+    )]
+    pub reward_schedule: UncheckedAccount<'info>,
+    /// CHECK: [Campaign] of the [Locker], which may not exist.
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_SEED.as_bytes(), locker.key().as_ref()],
+        bump,
+    )]
+    pub campaign: UncheckedAccount<'info>,
+    /// CHECK: [BurnCounter] of the collection of the NFT in the [Campaign]; checked against
+    /// the NFT when the [Campaign] exists.
+    #[account(mut)]
+    pub collection_burns: UncheckedAccount<'info>,
+    /// CHECK: [BurnCounter] of the [Escrow] in the [Campaign]; checked when the [Campaign]
+    /// exists.
+    #[account(mut)]
+    pub escrow_burns: UncheckedAccount<'info>,
+    /// WL token mint
+    #[account(mut)]
+    pub wl_token_mint: Box<Account<'info, Mint>>,
+    /// WL token destination.
+    #[account(mut)]
+    pub wl_destination: Box<Account<'info, TokenAccount>>,
+
+    /// system program
+    pub system_program: Program<'info, System>,
+    /// token program
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> LockNftToLedger<'info> {
+    pub fn process(
+        &mut self,
+        duration: i64,
+        provenance: &NftProvenance,
+        reward_terms: (NftRewardParams, Option<NftRewardSchedule>),
+    ) -> Result<()> {
+        let mut receipt = NftReceipt::default();
+
+        lock_nft_reward(
+            &mut self.locker,
+            &mut self.escrow,
+            &mut receipt,
+            &self.locker_treasury,
+            &self.locked_tokens,
+            &self.token_program,
+            provenance,
+            reward_terms,
+            None,
+            false,
+            duration,
+        )?;
+        CampaignAccounts {
+            campaign: &self.campaign,
+            collection_burns: &self.collection_burns,
+            escrow_burns: &self.escrow_burns,
+            payer: &self.payer,
+            system_program: &self.system_program,
+        }
+        .record_burn(
+            &mut self.locker,
+            &self.escrow.key(),
+            &provenance.campaign_collection(),
+            unwrap_int!(receipt.calculate_max_reward_amount()),
+        )?;
+
+        push_ledger_receipt(
+            &self.ledger.to_account_info(),
+            &self.payer,
+            &self.system_program,
+            LedgerReceipt::from_nft_receipt(&receipt)?,
+        )
+    }
+
+    pub(crate) fn nft_burn_accounts<'a>(
+        &'a self,
+        remaining_accounts: &'a [AccountInfo<'info>],
+    ) -> NftBurnAccounts<'a, 'info> {
+        NftBurnAccounts {
+            locker: self.locker.key(),
+            payer: &self.payer,
+            nft_source: &self.nft_source,
+            nft_source_authority: &self.nft_source_authority,
+            system_program: &self.system_program,
+            token_program: &self.token_program,
+            remaining_accounts,
+        }
+    }
+}
+
+/// Takes the remaining accounts of [LockNft].
+///
+/// Ledger records hold no [VestTier], so the reward vests over the max stake duration of
+/// its terms; NFTs are locked with a vest tier through [LockNft].
+pub fn ledger_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, LockNftToLedger<'info>>,
+    duration: i64,
+) -> Result<()> {
+    let nft = ctx.accounts.nft_burn_accounts(ctx.remaining_accounts);
+    let (standard, provenance) = nft.check_accounts(None)?;
+    let reward_terms = nft_reward_terms(
+        nft.proof(),
+        &ctx.accounts.reward_schedule,
+        &ctx.accounts.locker.params,
+    )?;
+    let wl_token_amount = reward_terms.0.wl_token_amount;
+
+    ctx.accounts.process(duration, &provenance, reward_terms)?;
+
+    ctx.accounts
+        .nft_burn_accounts(ctx.remaining_accounts)
+        .burn(standard)?;
+
+    mint_wl_token(
+        &ctx.accounts.locker,
+        &ctx.accounts.wl_token_mint,
+        &ctx.accounts.wl_destination,
+        &ctx.accounts.token_program,
+        wl_token_amount,
+    )?;
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for LockNftToLedger<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_valid_ledger(&self.ledger, &self.locker, &self.escrow)?;
+        assert_keys_eq!(
+            self.wl_token_mint,
+            self.locker.wl_token_mint,
+            ProtocolError::InvalidLockerWLMint
+        );
+        assert_keys_eq!(
+            self.locked_tokens,
+            self.escrow.tokens,
+            ProtocolError::InvalidToken
+        );
+        invariant!(
+            self.locker.reward_mint.is_none(),
+            ProtocolError::RewardMintNotSupported
+        );
+        assert_keys_eq!(
+            self.escrow_owner,
+            self.escrow.owner,
+            ProtocolError::InvalidAccountOwner
+        );
+
+        Ok(())
+    }
+}
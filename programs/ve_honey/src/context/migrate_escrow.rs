@@ -0,0 +1,183 @@
+use crate::*;
+use anchor_lang::solana_program::system_program;
+use anchor_lang::Discriminator;
+use std::io::Cursor;
+
+/// Migrate an [Escrow] created before the rewards held in the reward mint and the count of
+/// closed receipts were stored.
+#[derive(Accounts)]
+pub struct MigrateEscrow<'info> {
+    /// payer of the rent of the grown [Escrow].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: [Escrow] in the previous layout; checked in process.
+    #[account(mut, owner = crate::ID)]
+    pub escrow: UncheckedAccount<'info>,
+    /// authority of the [Escrow].
+    pub escrow_owner: Signer<'info>,
+
+    /// system program
+    pub system_program: Program<'info, System>,
+}
+
+/// [Escrow] before the rewards held in the reward mint and the count of closed receipts
+/// were stored.
+#[derive(AnchorDeserialize)]
+struct EscrowV0 {
+    locker: Pubkey,
+    owner: Pubkey,
+    bump: u8,
+    tokens: Pubkey,
+    amount: u64,
+    escrow_started_at: i64,
+    escrow_ends_at: i64,
+    receipt_count: u64,
+    amount_to_receipt: u64,
+    vote_delegate: Pubkey,
+}
+
+impl EscrowV0 {
+    const LEN: usize = 32 + 32 + 1 + 32 + 8 + 8 + 8 + 8 + 8 + 32;
+}
+
+impl<'info> MigrateEscrow<'info> {
+    /// Grows the escrow to the current layout with no rewards held in the reward mint, as
+    /// the receipts of a legacy escrow locked their rewards into it.
+    ///
+    /// The receipts closed before the migration are counted from `closed_receipt_ids`, in
+    /// increasing order, whose [NftReceipt] addresses are given as the remaining accounts
+    /// and must no longer exist. The owner signs the migration, so the count is not left
+    /// short by someone else and the escrow can still be transferred with
+    /// [ve_honey::accept_escrow_transfer].
+    pub fn process(
+        &mut self,
+        closed_receipt_ids: &[u64],
+        closed_receipts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let EscrowV0 {
+            locker,
+            owner,
+            bump,
+            tokens,
+            amount,
+            escrow_started_at,
+            escrow_ends_at,
+            receipt_count,
+            amount_to_receipt,
+            vote_delegate,
+        } = self.read_legacy_escrow()?;
+
+        assert_keys_eq!(owner, self.escrow_owner, ProtocolError::InvalidAccountOwner);
+        assert_closed_receipts(
+            &locker,
+            &owner,
+            receipt_count,
+            closed_receipt_ids,
+            closed_receipts,
+        )?;
+
+        let escrow = Escrow {
+            locker,
+            owner,
+            bump,
+            tokens,
+            amount,
+            escrow_started_at,
+            escrow_ends_at,
+            receipt_count,
+            amount_to_receipt,
+            vote_delegate,
+            reward_amount_to_receipt: 0,
+            closed_receipt_count: closed_receipt_ids.len() as u64,
+        };
+
+        let info = self.escrow.to_account_info();
+        realloc_account(&info, &self.payer, &self.system_program, 8 + Escrow::LEN)?;
+        escrow.try_serialize(&mut Cursor::new(&mut info.try_borrow_mut_data()?[..]))?;
+
+        emit!(MigrateEscrowEvent {
+            locker: escrow.locker,
+            escrow: info.key(),
+            escrow_owner: escrow.owner,
+            closed_receipt_count: escrow.closed_receipt_count,
+        });
+
+        Ok(())
+    }
+
+    fn read_legacy_escrow(&self) -> Result<EscrowV0> {
+        let data = self.escrow.try_borrow_data()?;
+
+        invariant!(
+            data.len() >= 8 && data[..8] == Escrow::discriminator(),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        invariant!(
+            data.len() == 8 + EscrowV0::LEN,
+            ProtocolError::AccountAlreadyMigrated
+        );
+
+        Ok(EscrowV0::deserialize(&mut &data[8..])?)
+    }
+}
+
+/// Checks that the [NftReceipt]s of `closed_receipt_ids` were created by the [Escrow] of
+/// `owner` and have been closed.
+fn assert_closed_receipts(
+    locker: &Pubkey,
+    owner: &Pubkey,
+    receipt_count: u64,
+    closed_receipt_ids: &[u64],
+    closed_receipts: &[AccountInfo],
+) -> Result<()> {
+    invariant!(
+        closed_receipt_ids.len() == closed_receipts.len(),
+        ProtocolError::InvalidRemainingAccounts
+    );
+
+    let mut next_receipt_id = 0;
+    for (receipt_id, info) in closed_receipt_ids.iter().zip(closed_receipts) {
+        invariant!(
+            *receipt_id >= next_receipt_id && *receipt_id < receipt_count,
+            ProtocolError::InvalidRemainingAccounts
+        );
+        next_receipt_id = unwrap_int!(receipt_id.checked_add(1));
+
+        let (receipt, _bump) = Pubkey::find_program_address(
+            &[
+                NFT_RECEIPT_SEED.as_bytes(),
+                locker.as_ref(),
+                owner.as_ref(),
+                receipt_id.to_le_bytes().as_ref(),
+            ],
+            &crate::ID,
+        );
+        assert_keys_eq!(receipt, info.key(), ProtocolError::InvalidRemainingAccounts);
+        invariant!(
+            info.owner == &system_program::ID && info.data_is_empty(),
+            ProtocolError::InvalidRemainingAccounts
+        );
+    }
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for MigrateEscrow<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[event]
+/// Event called in [ve_honey::migrate_escrow].
+pub struct MigrateEscrowEvent {
+    /// [Locker] of the [Escrow].
+    #[index]
+    pub locker: Pubkey,
+    /// The migrated [Escrow].
+    pub escrow: Pubkey,
+    /// The owner of the [Escrow].
+    pub escrow_owner: Pubkey,
+    /// Number of receipts closed before the migration.
+    pub closed_receipt_count: u64,
+}
@@ -0,0 +1,139 @@
+use crate::*;
+use anchor_lang::Discriminator;
+use std::io::Cursor;
+
+/// Migrate a [Locker] created before the custodial reward rate, the treasury and the
+/// reward mint were stored.
+#[derive(Accounts)]
+pub struct MigrateLocker<'info> {
+    /// payer of the rent of the grown [Locker].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: [Locker] in the previous layout; checked in process.
+    #[account(mut, owner = crate::ID)]
+    pub locker: UncheckedAccount<'info>,
+
+    /// system program
+    pub system_program: Program<'info, System>,
+}
+
+/// [Locker] before the custodial reward rate, the treasury and the reward mint were
+/// stored.
+#[derive(AnchorDeserialize)]
+struct LockerV0 {
+    base: Pubkey,
+    bump: u8,
+    token_mint: Pubkey,
+    locked_supply: u64,
+    wl_token_mint: Pubkey,
+    governor: Pubkey,
+    params: LockerParamsV0,
+}
+
+/// [LockerParams] before the custodial reward rate was stored.
+#[derive(AnchorDeserialize)]
+struct LockerParamsV0 {
+    min_stake_duration: u64,
+    max_stake_duration: u64,
+    whitelist_enabled: bool,
+    multiplier: u8,
+    proposal_activation_min_votes: u64,
+    nft_stake_duration_unit: i64,
+    nft_stake_base_reward: u64,
+    nft_stake_duration_count: u8,
+    nft_reward_halving_starts_at: u8,
+}
+
+impl LockerV0 {
+    const LEN: usize = 32 + 1 + 32 + 8 + 32 + 32 + LockerParamsV0::LEN;
+}
+
+impl LockerParamsV0 {
+    const LEN: usize = 8 + 8 + 1 + 1 + 8 + 8 + 8 + 1 + 1;
+}
+
+impl<'info> MigrateLocker<'info> {
+    /// Grows the locker to the current layout with no rewards reserved, no treasury
+    /// authority and no reward mint, so NFT rewards stay locked into the [Escrow]. Custodial
+    /// locks pay no reward until the governance sets their rate with
+    /// [ve_honey::set_locker_params].
+    pub fn process(&mut self) -> Result<()> {
+        let LockerV0 {
+            base,
+            bump,
+            token_mint,
+            locked_supply,
+            wl_token_mint,
+            governor,
+            params,
+        } = self.read_legacy_locker()?;
+        let locker = Locker {
+            base,
+            bump,
+            token_mint,
+            locked_supply,
+            wl_token_mint,
+            governor,
+            params: LockerParams {
+                min_stake_duration: params.min_stake_duration,
+                max_stake_duration: params.max_stake_duration,
+                whitelist_enabled: params.whitelist_enabled,
+                multiplier: params.multiplier,
+                proposal_activation_min_votes: params.proposal_activation_min_votes,
+                nft_stake_duration_unit: params.nft_stake_duration_unit,
+                nft_stake_base_reward: params.nft_stake_base_reward,
+                nft_stake_duration_count: params.nft_stake_duration_count,
+                nft_reward_halving_starts_at: params.nft_reward_halving_starts_at,
+                nft_custodial_reward_bps: 0,
+            },
+            reserved_rewards: 0,
+            treasury_authority: None,
+            reward_mint: None,
+        };
+
+        let info = self.locker.to_account_info();
+        realloc_account(&info, &self.payer, &self.system_program, 8 + Locker::LEN)?;
+        locker.try_serialize(&mut Cursor::new(&mut info.try_borrow_mut_data()?[..]))?;
+
+        emit!(MigrateLockerEvent {
+            locker: info.key(),
+            governor: locker.governor,
+            params: locker.params,
+        });
+
+        Ok(())
+    }
+
+    fn read_legacy_locker(&self) -> Result<LockerV0> {
+        let data = self.locker.try_borrow_data()?;
+
+        invariant!(
+            data.len() >= 8 && data[..8] == Locker::discriminator(),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        invariant!(
+            data.len() == 8 + LockerV0::LEN,
+            ProtocolError::AccountAlreadyMigrated
+        );
+
+        Ok(LockerV0::deserialize(&mut &data[8..])?)
+    }
+}
+
+impl<'info> Validate<'info> for MigrateLocker<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[event]
+/// Event called in [ve_honey::migrate_locker].
+pub struct MigrateLockerEvent {
+    /// The migrated [Locker].
+    #[index]
+    pub locker: Pubkey,
+    /// Governor of the [Locker].
+    pub governor: Pubkey,
+    /// Params of the [Locker].
+    pub params: LockerParams,
+}
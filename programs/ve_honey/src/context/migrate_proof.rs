@@ -0,0 +1,88 @@
+use crate::*;
+use anchor_lang::Discriminator;
+use std::io::Cursor;
+
+/// Migrate a [Proof] created before its reward terms were stored.
+#[derive(Accounts)]
+pub struct MigrateProof<'info> {
+    /// payer of the rent of the grown [Proof].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: [Proof] in the previous layout; checked in process.
+    #[account(mut, owner = crate::ID)]
+    pub proof: UncheckedAccount<'info>,
+
+    /// system program
+    pub system_program: Program<'info, System>,
+}
+
+/// [Proof] before the reward terms were stored.
+#[derive(AnchorDeserialize)]
+struct ProofV0 {
+    locker: Pubkey,
+    proof_type: u8,
+    proof_address: Pubkey,
+}
+
+impl ProofV0 {
+    const LEN: usize = 32 + 1 + 32;
+}
+
+impl<'info> MigrateProof<'info> {
+    /// Grows the proof to the current layout without reward terms, so the NFTs it accepts
+    /// keep the reward terms of the [Locker].
+    pub fn process(&mut self) -> Result<()> {
+        let legacy = self.read_legacy_proof()?;
+        let proof = Proof {
+            locker: legacy.locker,
+            proof_type: legacy.proof_type,
+            proof_address: legacy.proof_address,
+            reward_params: None,
+        };
+
+        let info = self.proof.to_account_info();
+        realloc_account(&info, &self.payer, &self.system_program, 8 + Proof::LEN)?;
+        proof.try_serialize(&mut Cursor::new(&mut info.try_borrow_mut_data()?[..]))?;
+
+        emit!(MigrateProofEvent {
+            locker: proof.locker,
+            proof_type: proof.proof_type,
+            proof_address: proof.proof_address,
+        });
+
+        Ok(())
+    }
+
+    fn read_legacy_proof(&self) -> Result<ProofV0> {
+        let data = self.proof.try_borrow_data()?;
+
+        invariant!(
+            data.len() >= 8 && data[..8] == Proof::discriminator(),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        invariant!(
+            data.len() == 8 + ProofV0::LEN,
+            ProtocolError::AccountAlreadyMigrated
+        );
+
+        Ok(ProofV0::deserialize(&mut &data[8..])?)
+    }
+}
+
+impl<'info> Validate<'info> for MigrateProof<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[event]
+/// Event called in [ve_honey::migrate_proof].
+pub struct MigrateProofEvent {
+    /// [Locker] of the [Proof].
+    #[index]
+    pub locker: Pubkey,
+    /// Type of the [Proof].
+    pub proof_type: u8,
+    /// Address of the [Proof].
+    pub proof_address: Pubkey,
+}
@@ -0,0 +1,178 @@
+use crate::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::Discriminator;
+use govern::Governor;
+use std::io::Cursor;
+
+/// Migrate an [NftReceipt] created before the NFT mint, reward terms, schedule, NFT
+/// provenance, reward mint and vest tier were stored in receipts.
+///
+/// Legacy receipts take the reward terms of the [Locker] at migration, so only the
+/// governance migrates them, before it changes the [LockerParams].
+#[derive(Accounts)]
+pub struct MigrateReceipt<'info> {
+    /// payer of the rent of the grown [NftReceipt].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// [Locker].
+    pub locker: Box<Account<'info, Locker>>,
+    /// CHECK: [NftReceipt] in a previous layout; checked in process.
+    #[account(mut, owner = crate::ID)]
+    pub nft_receipt: UncheckedAccount<'info>,
+    /// the [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// the smart wallet on the [Governor].
+    pub smart_wallet: Signer<'info>,
+
+    /// system program
+    pub system_program: Program<'info, System>,
+}
+
+/// [NftReceipt] as deployed, before the NFT mint, reward terms, schedule, NFT
+/// provenance, reward mint and vest tier were stored.
+#[derive(AnchorDeserialize)]
+struct NftReceiptV0 {
+    receipt_id: u64,
+    locker: Pubkey,
+    owner: Pubkey,
+    vest_started_at: i64,
+    vest_ends_at: i64,
+    claimed_amount: u64,
+}
+
+impl NftReceiptV0 {
+    const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8;
+}
+
+impl<'info> MigrateReceipt<'info> {
+    /// Stores the current reward terms of the [Locker] in the receipt and no reward
+    /// schedule. These are the terms a legacy receipt is already valued with, so the
+    /// migration does not change it. The provenance of the NFT is not known for legacy
+    /// receipts and is left empty, their rewards were locked into the [Escrow] and they
+    /// vest over the max stake duration of their reward terms.
+    pub fn process(&mut self) -> Result<()> {
+        let NftReceiptV0 {
+            receipt_id,
+            locker,
+            owner,
+            vest_started_at,
+            vest_ends_at,
+            claimed_amount,
+        } = self.read_legacy_receipt()?;
+
+        assert_keys_eq!(self.locker, locker, ProtocolError::InvalidLocker);
+
+        let receipt = NftReceipt {
+            receipt_id,
+            locker,
+            owner,
+            vest_started_at,
+            vest_ends_at,
+            claimed_amount,
+            nft_mint: Pubkey::default(),
+            custodial: false,
+            reward_bps: MAX_BPS,
+            withdrawn: false,
+            reward_params: self.locker.params.nft_reward_params(),
+            reward_schedule: None,
+            proof: Pubkey::default(),
+            proof_type: 0,
+            collection: None,
+            reward_mint: None,
+            vest_tier: None,
+        };
+
+        let info = self.nft_receipt.to_account_info();
+        realloc_account(
+            &info,
+            &self.payer,
+            &self.system_program,
+            8 + NftReceipt::LEN,
+        )?;
+        receipt.try_serialize(&mut Cursor::new(&mut info.try_borrow_mut_data()?[..]))?;
+
+        emit!(MigrateReceiptEvent {
+            locker: receipt.locker,
+            receipt_owner: receipt.owner,
+            receipt_id: receipt.receipt_id,
+            reward_params: receipt.reward_params,
+        });
+
+        Ok(())
+    }
+
+    fn read_legacy_receipt(&self) -> Result<NftReceiptV0> {
+        let data = self.nft_receipt.try_borrow_data()?;
+
+        invariant!(
+            data.len() >= 8 && data[..8] == NftReceipt::discriminator(),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        invariant!(
+            data.len() == 8 + NftReceiptV0::LEN,
+            ProtocolError::ReceiptAlreadyMigrated
+        );
+
+        Ok(NftReceiptV0::deserialize(&mut &data[8..])?)
+    }
+}
+
+/// Grows a program account to `space`, topping up its rent from `payer`.
+pub(crate) fn realloc_account<'info>(
+    info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+) -> Result<()> {
+    let lamports = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(info.lamports());
+
+    if lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: info.clone(),
+                },
+            ),
+            lamports,
+        )?;
+    }
+
+    info.realloc(space, false)?;
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for MigrateReceipt<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.locker.governor,
+            self.governor,
+            ProtocolError::GovernorMismatch
+        );
+        assert_keys_eq!(
+            self.governor.smart_wallet,
+            self.smart_wallet,
+            ProtocolError::SmartWalletMismatch
+        );
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event called in [ve_honey::migrate_receipt].
+pub struct MigrateReceiptEvent {
+    /// [Locker] of the [NftReceipt].
+    #[index]
+    pub locker: Pubkey,
+    /// The owner of the [NftReceipt].
+    pub receipt_owner: Pubkey,
+    /// Id of the [NftReceipt].
+    pub receipt_id: u64,
+    /// Reward terms stored in the [NftReceipt].
+    pub reward_params: NftRewardParams,
+}
@@ -0,0 +1,97 @@
+use crate::*;
+use anchor_lang::Discriminator;
+use std::io::Cursor;
+
+/// Migrate a [WhitelistEntry] created before its limits and usage were stored.
+#[derive(Accounts)]
+pub struct MigrateWhitelistEntry<'info> {
+    /// payer of the rent of the grown [WhitelistEntry].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: [WhitelistEntry] in the previous layout; checked in process.
+    #[account(mut, owner = crate::ID)]
+    pub whitelist_entry: UncheckedAccount<'info>,
+
+    /// system program
+    pub system_program: Program<'info, System>,
+}
+
+/// [WhitelistEntry] before the limits and usage were stored.
+#[derive(AnchorDeserialize)]
+struct WhitelistEntryV0 {
+    locker: Pubkey,
+    bump: u8,
+    program_id: Pubkey,
+    owner: Pubkey,
+}
+
+impl WhitelistEntryV0 {
+    const LEN: usize = 32 + 1 + 32 + 32;
+}
+
+impl<'info> MigrateWhitelistEntry<'info> {
+    /// Grows the entry to the current layout without limits, so the locks it allows are
+    /// unchanged, and with no usage recorded.
+    pub fn process(&mut self) -> Result<()> {
+        let entry = self.read_legacy_entry()?;
+        let whitelist_entry = WhitelistEntry {
+            locker: entry.locker,
+            bump: entry.bump,
+            program_id: entry.program_id,
+            owner: entry.owner,
+            params: WhitelistEntryParams::default(),
+            locked_amount: 0,
+            lock_count: 0,
+        };
+
+        let info = self.whitelist_entry.to_account_info();
+        realloc_account(
+            &info,
+            &self.payer,
+            &self.system_program,
+            8 + WhitelistEntry::LEN,
+        )?;
+        whitelist_entry.try_serialize(&mut Cursor::new(&mut info.try_borrow_mut_data()?[..]))?;
+
+        emit!(MigrateWhitelistEntryEvent {
+            locker: whitelist_entry.locker,
+            program_id: whitelist_entry.program_id,
+            owner: whitelist_entry.owner,
+        });
+
+        Ok(())
+    }
+
+    fn read_legacy_entry(&self) -> Result<WhitelistEntryV0> {
+        let data = self.whitelist_entry.try_borrow_data()?;
+
+        invariant!(
+            data.len() >= 8 && data[..8] == WhitelistEntry::discriminator(),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        invariant!(
+            data.len() == 8 + WhitelistEntryV0::LEN,
+            ProtocolError::AccountAlreadyMigrated
+        );
+
+        Ok(WhitelistEntryV0::deserialize(&mut &data[8..])?)
+    }
+}
+
+impl<'info> Validate<'info> for MigrateWhitelistEntry<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[event]
+/// Event called in [ve_honey::migrate_whitelist_entry].
+pub struct MigrateWhitelistEntryEvent {
+    /// [Locker] of the [WhitelistEntry].
+    #[index]
+    pub locker: Pubkey,
+    /// Program allowed to call the `lock` CPI.
+    pub program_id: Pubkey,
+    /// Owner allowed by the [WhitelistEntry].
+    pub owner: Pubkey,
+}
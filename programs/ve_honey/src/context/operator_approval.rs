@@ -0,0 +1,220 @@
+use crate::*;
+use anchor_spl::token::TokenAccount;
+
+#[derive(Accounts)]
+pub struct ApproveOperator<'info> {
+    /// payer of the initialization.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// [Escrow] managed by the operator.
+    pub escrow: Box<Account<'info, Escrow>>,
+    /// authority of the [Escrow].
+    pub escrow_owner: Signer<'info>,
+    /// CHECK: operator being approved.
+    pub operator: UncheckedAccount<'info>,
+    /// the [OperatorApproval].
+    #[account(
+        init_if_needed,
+        seeds = [
+            OPERATOR_APPROVAL_SEED.as_bytes(),
+            escrow.key().as_ref(),
+            operator.key().as_ref(),
+        ],
+        bump,
+        space = 8 + OperatorApproval::LEN,
+        payer = payer,
+    )]
+    pub operator_approval: Box<Account<'info, OperatorApproval>>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ApproveOperator<'info> {
+    /// Approves the operator for `actions` until `expires_at`, or without expiry if zero,
+    /// replacing any previous approval of the operator.
+    pub fn process(&mut self, actions: u8, expires_at: i64) -> Result<()> {
+        invariant!(
+            !OperatorApproval::read_actions(actions)?.is_empty(),
+            ProtocolError::InvalidOperatorActions
+        );
+        invariant!(
+            expires_at == 0 || expires_at > Clock::get()?.unix_timestamp,
+            ProtocolError::OperatorApprovalExpired
+        );
+
+        let operator_approval = &mut self.operator_approval;
+
+        operator_approval.escrow = self.escrow.key();
+        operator_approval.operator = self.operator.key();
+        operator_approval.actions = actions;
+        operator_approval.expires_at = expires_at;
+
+        emit!(ApproveOperatorEvent {
+            escrow: operator_approval.escrow,
+            escrow_owner: self.escrow.owner,
+            operator: operator_approval.operator,
+            actions,
+            expires_at,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for ApproveOperator<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.escrow.owner,
+            self.escrow_owner,
+            ProtocolError::InvalidAccountOwner
+        );
+        assert_keys_neq!(
+            self.escrow.owner,
+            self.operator,
+            ProtocolError::InvalidOperatorActions
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct RevokeOperator<'info> {
+    /// [Escrow] managed by the operator.
+    pub escrow: Box<Account<'info, Escrow>>,
+    /// authority of the [Escrow].
+    pub escrow_owner: Signer<'info>,
+    /// the [OperatorApproval].
+    #[account(mut, close = funds_receiver)]
+    pub operator_approval: Box<Account<'info, OperatorApproval>>,
+    /// CHECK: funds receiver
+    #[account(mut)]
+    pub funds_receiver: UncheckedAccount<'info>,
+}
+
+impl<'info> RevokeOperator<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        emit!(RevokeOperatorEvent {
+            escrow: self.operator_approval.escrow,
+            escrow_owner: self.escrow.owner,
+            operator: self.operator_approval.operator,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for RevokeOperator<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.escrow,
+            self.operator_approval.escrow,
+            ProtocolError::InvalidOperatorApproval
+        );
+        assert_keys_eq!(
+            self.escrow.owner,
+            self.escrow_owner,
+            ProtocolError::InvalidAccountOwner
+        );
+
+        Ok(())
+    }
+}
+
+/// Checks that `authority` is the owner of the [Escrow], or an operator whose
+/// [OperatorApproval], which may not exist, allows `action`.
+pub(crate) fn assert_escrow_authority(
+    escrow: &Account<Escrow>,
+    authority: &Pubkey,
+    operator_approval_info: &AccountInfo,
+    action: OperatorActions,
+) -> Result<()> {
+    if escrow.owner == *authority {
+        return Ok(());
+    }
+
+    assert_operator_approved(escrow, authority, operator_approval_info, action)
+}
+
+/// Checks that `authority` may claim from the [Escrow] into `destination_tokens`; the
+/// claims of an operator are always paid to the owner.
+pub(crate) fn assert_claim_authority(
+    escrow: &Account<Escrow>,
+    authority: &Pubkey,
+    operator_approval_info: &AccountInfo,
+    destination_tokens: &Account<TokenAccount>,
+) -> Result<()> {
+    if escrow.owner == *authority {
+        return Ok(());
+    }
+
+    assert_operator_approved(
+        escrow,
+        authority,
+        operator_approval_info,
+        OperatorActions::CLAIM,
+    )?;
+    assert_keys_eq!(
+        destination_tokens.owner,
+        escrow.owner,
+        ProtocolError::InvalidTokenOwner
+    );
+
+    Ok(())
+}
+
+/// Checks that the [OperatorApproval] of `operator` on the [Escrow] allows `action`.
+pub(crate) fn assert_operator_approved(
+    escrow: &Account<Escrow>,
+    operator: &Pubkey,
+    operator_approval_info: &AccountInfo,
+    action: OperatorActions,
+) -> Result<()> {
+    invariant!(
+        operator_approval_info.owner == &crate::ID,
+        ProtocolError::OperatorNotApproved
+    );
+    let operator_approval = Account::<OperatorApproval>::try_from(operator_approval_info)?;
+
+    assert_keys_eq!(
+        operator_approval.escrow,
+        escrow.key(),
+        ProtocolError::InvalidOperatorApproval
+    );
+    assert_keys_eq!(
+        operator_approval.operator,
+        *operator,
+        ProtocolError::InvalidOperatorApproval
+    );
+
+    operator_approval.allows(action, Clock::get()?.unix_timestamp)
+}
+
+#[event]
+/// Event called in [ve_honey::approve_operator].
+pub struct ApproveOperatorEvent {
+    /// The [Escrow] managed by the operator.
+    #[index]
+    pub escrow: Pubkey,
+    /// The owner of the [Escrow].
+    pub escrow_owner: Pubkey,
+    /// The approved operator.
+    pub operator: Pubkey,
+    /// [OperatorActions] the operator is approved for.
+    pub actions: u8,
+    /// Timestamp after which the approval no longer applies; never if zero.
+    pub expires_at: i64,
+}
+
+#[event]
+/// Event called in [ve_honey::revoke_operator].
+pub struct RevokeOperatorEvent {
+    /// The [Escrow] managed by the operator.
+    #[index]
+    pub escrow: Pubkey,
+    /// The owner of the [Escrow].
+    pub escrow_owner: Pubkey,
+    /// The revoked operator.
+    pub operator: Pubkey,
+}
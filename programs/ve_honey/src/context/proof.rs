@@ -21,7 +21,7 @@ pub struct AddProof<'info> {
         payer = payer,
     )]
     pub proof: Box<Account<'info, Proof>>,
-    /// proof address.
+    /// proof address, a collection mint for [ProofType::COLLECTION].
     pub address: UncheckedAccount<'info>,
     /// the [Governor].
     pub governor: Box<Account<'info, Governor>>,
@@ -33,14 +33,33 @@ pub struct AddProof<'info> {
 }
 
 impl<'info> AddProof<'info> {
-    pub fn process(&mut self, proof_type: u8) -> Result<()> {
+    pub fn process(
+        &mut self,
+        proof_type: u8,
+        reward_params: Option<NftRewardParams>,
+    ) -> Result<()> {
         let proof = &mut self.proof;
 
         let new_proof_type = Proof::read_type(proof_type)?;
+        invariant!(
+            !new_proof_type.contains(ProofType::MERKLE),
+            ProtocolError::InvalidProofType
+        );
+        if new_proof_type.contains(ProofType::COLLECTION) {
+            invariant!(
+                is_collection(&self.address),
+                ProtocolError::InvalidCollection
+            );
+        }
+
+        if let Some(reward_params) = reward_params {
+            reward_params.validate()?;
+        }
 
         proof.reset_type(new_proof_type);
         proof.proof_address = self.address.key();
         proof.locker = self.locker.key();
+        proof.reward_params = reward_params;
 
         Ok(())
     }
@@ -63,6 +82,75 @@ impl<'info> Validate<'info> for AddProof<'info> {
     }
 }
 
+#[derive(Accounts)]
+#[instruction(root: [u8; 32])]
+pub struct AddMerkleProof<'info> {
+    /// payer of the initialization.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// the [Locker].
+    pub locker: Box<Account<'info, Locker>>,
+    /// the [Proof], addressed by the merkle root.
+    #[account(
+        init_if_needed,
+        seeds = [
+            PROOF_SEED.as_bytes(),
+            locker.key().as_ref(),
+            root.as_ref(),
+        ],
+        bump,
+        space = 8 + Proof::LEN,
+        payer = payer,
+    )]
+    pub proof: Box<Account<'info, Proof>>,
+    /// the [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// the smart wallet on the [Governor].
+    pub smart_wallet: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddMerkleProof<'info> {
+    /// Allowlists the NFT mints of the merkle tree of `root`, built with [MintMerkleTree].
+    pub fn process(
+        &mut self,
+        root: [u8; 32],
+        reward_params: Option<NftRewardParams>,
+    ) -> Result<()> {
+        let proof = &mut self.proof;
+
+        if let Some(reward_params) = reward_params {
+            reward_params.validate()?;
+        }
+
+        proof.reset_type(ProofType::MERKLE);
+        proof.proof_address = Pubkey::new_from_array(root);
+        proof.locker = self.locker.key();
+        proof.reward_params = reward_params;
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for AddMerkleProof<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.locker.governor,
+            self.governor,
+            ProtocolError::GovernorMismatch
+        );
+        assert_keys_eq!(
+            self.governor.smart_wallet,
+            self.smart_wallet,
+            ProtocolError::SmartWalletMismatch
+        );
+
+        Ok(())
+    }
+}
+
 #[derive(Accounts)]
 pub struct RemoveProof<'info> {
     /// the [Locker].
@@ -86,6 +174,7 @@ impl<'info> RemoveProof<'info> {
         proof.proof_address = Pubkey::default();
         proof.locker = Pubkey::default();
         proof.proof_type = 0;
+        proof.reward_params = None;
 
         Ok(())
     }
@@ -0,0 +1,421 @@
+use crate::*;
+use anchor_lang::__private::bytemuck;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::token::{self, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct InitReceiptLedger<'info> {
+    /// Payer of the initialization.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// [Locker].
+    pub locker: Box<Account<'info, Locker>>,
+    /// [Escrow].
+    pub escrow: Box<Account<'info, Escrow>>,
+    /// [ReceiptLedger].
+    #[account(
+        init,
+        seeds = [
+            RECEIPT_LEDGER_SEED.as_bytes(),
+            escrow.key().as_ref(),
+        ],
+        bump,
+        space = 8 + ReceiptLedger::LEN,
+        payer = payer
+    )]
+    pub ledger: AccountLoader<'info, ReceiptLedger>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitReceiptLedger<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let mut ledger = self.ledger.load_init()?;
+
+        ledger.escrow = self.escrow.key();
+        ledger.locker = self.locker.key();
+        ledger.owner = self.escrow.owner;
+        ledger.receipt_count = 0;
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for InitReceiptLedger<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.locker,
+            self.escrow.locker,
+            ProtocolError::InvalidLocker
+        );
+
+        Ok(())
+    }
+}
+
+/// Claim with an [NftReceipt] of a [ReceiptLedger].
+#[derive(Accounts)]
+pub struct ClaimLedgerReceipt<'info> {
+    /// [Locker].
+    #[account(mut)]
+    pub locker: Box<Account<'info, Locker>>,
+    /// [Escrow] that is being claimed
+    #[account(mut)]
+    pub escrow: Box<Account<'info, Escrow>>,
+    /// authority of [Escrow], or an operator approved to claim to its owner.
+    pub escrow_owner: Signer<'info>,
+    /// CHECK: [OperatorApproval] of the signer on the [Escrow], which may not exist.
+    #[account(
+        seeds = [
+            OPERATOR_APPROVAL_SEED.as_bytes(),
+            escrow.key().as_ref(),
+            escrow_owner.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub operator_approval: UncheckedAccount<'info>,
+    /// tokens locked up in the [Locker].
+    #[account(mut)]
+    pub locked_tokens: Box<Account<'info, TokenAccount>>,
+    /// destination for the tokens claimed
+    #[account(mut)]
+    pub destination_tokens: Box<Account<'info, TokenAccount>>,
+    /// [ReceiptLedger] of the [Escrow].
+    #[account(mut)]
+    pub ledger: AccountLoader<'info, ReceiptLedger>,
+
+    /// token program
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ClaimLedgerReceipt<'info> {
+    pub fn process(&mut self, index: u64) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let info = self.ledger.to_account_info();
+
+        let claim_amount = {
+            let mut data = info.try_borrow_mut_data()?;
+            let (ledger, records) = ReceiptLedger::split_mut(&mut data)?;
+            let record = unwrap_opt!(
+                records.get_mut(index as usize),
+                ProtocolError::InvalidLedgerIndex
+            );
+
+            let mut receipt = record.to_nft_receipt(ledger);
+            let claim_amount = unwrap_int!(receipt.calculate_reward_amount_at_time(now));
+
+            invariant!(claim_amount > 0, ProtocolError::ClaimError);
+
+            receipt.update_receipt(&mut self.locker, &mut self.escrow, claim_amount)?;
+            record.claimed_amount = receipt.claimed_amount;
+
+            claim_amount
+        };
+
+        let seeds: &[&[&[u8]]] = escrow_seeds!(self.escrow);
+
+        token::transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::Transfer {
+                    from: self.locked_tokens.to_account_info(),
+                    to: self.destination_tokens.to_account_info(),
+                    authority: self.escrow.to_account_info(),
+                },
+            )
+            .with_signer(seeds),
+            claim_amount,
+        )?;
+
+        let escrow = &mut self.escrow;
+
+        if escrow.amount == 0 {
+            escrow.escrow_started_at = 0;
+            escrow.escrow_ends_at = 0;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for ClaimLedgerReceipt<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_valid_ledger(&self.ledger, &self.locker, &self.escrow)?;
+        assert_claim_authority(
+            &self.escrow,
+            &self.escrow_owner.key(),
+            &self.operator_approval,
+            &self.destination_tokens,
+        )?;
+        assert_keys_eq!(
+            self.escrow.tokens,
+            self.locked_tokens,
+            ProtocolError::InvalidToken
+        );
+        assert_keys_neq!(
+            self.locked_tokens,
+            self.destination_tokens,
+            ProtocolError::InvalidToken
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct CloseLedgerReceipt<'info> {
+    /// [Locker].
+    pub locker: Box<Account<'info, Locker>>,
+    /// [Escrow].
+    #[account(mut)]
+    pub escrow: Box<Account<'info, Escrow>>,
+    /// [ReceiptLedger] of the [Escrow].
+    #[account(mut)]
+    pub ledger: AccountLoader<'info, ReceiptLedger>,
+    /// escrow owner.
+    pub escrow_owner: Signer<'info>,
+
+    /// CHECK: funds receiver
+    #[account(mut)]
+    pub funds_receiver: UncheckedAccount<'info>,
+}
+
+impl<'info> CloseLedgerReceipt<'info> {
+    /// Removes the fully claimed record at `index`, moving the last record of the ledger
+    /// into its place, and returns the rent of the record. The moved record changes index,
+    /// so indices read before the close are stale afterwards.
+    pub fn process(&mut self, index: u64) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let info = self.ledger.to_account_info();
+
+        {
+            let data = &mut info.try_borrow_mut_data()?;
+            let (ledger, records) = ReceiptLedger::split_mut(data)?;
+            let record = unwrap_opt!(
+                records.get(index as usize),
+                ProtocolError::InvalidLedgerIndex
+            );
+            let receipt = record.to_nft_receipt(ledger);
+
+            invariant!(receipt.vest_ends_at < now, ProtocolError::ReceiptNotEnded);
+            invariant!(
+                receipt.calculate_max_reward_amount() == Some(receipt.claimed_amount),
+                ProtocolError::CloseNonZeroReceipt
+            );
+        }
+
+        let escrow = &mut self.escrow;
+        escrow.closed_receipt_count = unwrap_int!(escrow.closed_receipt_count.checked_add(1));
+
+        remove_ledger_receipt(&info, &self.funds_receiver, index)
+    }
+}
+
+impl<'info> Validate<'info> for CloseLedgerReceipt<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_valid_ledger(&self.ledger, &self.locker, &self.escrow)?;
+        assert_keys_eq!(
+            self.escrow_owner,
+            self.escrow.owner,
+            ProtocolError::InvalidAccountOwner
+        );
+
+        Ok(())
+    }
+}
+
+/// Moves an [NftReceipt] account into the [ReceiptLedger] of its [Escrow].
+#[derive(Accounts)]
+pub struct MigrateReceiptToLedger<'info> {
+    /// payer of the rent of the grown [ReceiptLedger].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// [Locker].
+    pub locker: Box<Account<'info, Locker>>,
+    /// [Escrow].
+    pub escrow: Box<Account<'info, Escrow>>,
+    /// [ReceiptLedger] of the [Escrow].
+    #[account(mut)]
+    pub ledger: AccountLoader<'info, ReceiptLedger>,
+    /// escrow owner.
+    pub escrow_owner: Signer<'info>,
+    /// [NftReceipt], closed once moved.
+    #[account(mut, close = funds_receiver)]
+    pub nft_receipt: Box<Account<'info, NftReceipt>>,
+
+    /// CHECK: funds receiver
+    #[account(mut)]
+    pub funds_receiver: UncheckedAccount<'info>,
+    /// system program
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateReceiptToLedger<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        push_ledger_receipt(
+            &self.ledger.to_account_info(),
+            &self.payer,
+            &self.system_program,
+            LedgerReceipt::from_nft_receipt(&self.nft_receipt)?,
+        )
+    }
+}
+
+impl<'info> Validate<'info> for MigrateReceiptToLedger<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_valid_ledger(&self.ledger, &self.locker, &self.escrow)?;
+        assert_keys_eq!(
+            self.locker,
+            self.nft_receipt.locker,
+            ProtocolError::InvalidLocker
+        );
+        assert_keys_eq!(
+            self.escrow_owner,
+            self.escrow.owner,
+            ProtocolError::InvalidAccountOwner
+        );
+        assert_keys_eq!(
+            self.escrow_owner,
+            self.nft_receipt.owner,
+            ProtocolError::InvalidAccountOwner
+        );
+        invariant!(
+            self.escrow.receipt_count > self.nft_receipt.receipt_id,
+            ProtocolError::InvariantViolated
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct CloseReceiptLedger<'info> {
+    /// [Locker].
+    pub locker: Box<Account<'info, Locker>>,
+    /// [Escrow].
+    pub escrow: Box<Account<'info, Escrow>>,
+    /// [ReceiptLedger] of the [Escrow].
+    #[account(mut, close = funds_receiver)]
+    pub ledger: AccountLoader<'info, ReceiptLedger>,
+    /// escrow owner.
+    pub escrow_owner: Signer<'info>,
+
+    /// CHECK: funds receiver
+    #[account(mut)]
+    pub funds_receiver: UncheckedAccount<'info>,
+}
+
+impl<'info> CloseReceiptLedger<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for CloseReceiptLedger<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_valid_ledger(&self.ledger, &self.locker, &self.escrow)?;
+        assert_keys_eq!(
+            self.escrow_owner,
+            self.escrow.owner,
+            ProtocolError::InvalidAccountOwner
+        );
+        invariant!(
+            self.ledger.load()?.receipt_count == 0,
+            ProtocolError::LedgerNotEmpty
+        );
+
+        Ok(())
+    }
+}
+
+pub(crate) fn assert_valid_ledger(
+    ledger: &AccountLoader<ReceiptLedger>,
+    locker: &Account<Locker>,
+    escrow: &Account<Escrow>,
+) -> Result<()> {
+    let ledger = ledger.load()?;
+
+    assert_keys_eq!(*locker, escrow.locker, ProtocolError::InvalidLocker);
+    assert_keys_eq!(*locker, ledger.locker, ProtocolError::InvalidLocker);
+    assert_keys_eq!(*escrow, ledger.escrow, ProtocolError::InvalidLedger);
+
+    Ok(())
+}
+
+/// Appends the record to the [ReceiptLedger], growing it with the rent paid by `payer`.
+pub(crate) fn push_ledger_receipt<'info>(
+    info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    record: LedgerReceipt,
+) -> Result<()> {
+    let receipt_count = {
+        let data = &mut info.try_borrow_mut_data()?;
+        ReceiptLedger::split_mut(data)?.0.receipt_count
+    };
+    let space = unwrap_int!(ReceiptLedger::space(unwrap_int!(
+        receipt_count.checked_add(1)
+    )));
+    let lamports = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(info.lamports());
+
+    if lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: info.clone(),
+                },
+            ),
+            lamports,
+        )?;
+    }
+
+    info.realloc(space, false)?;
+
+    let data = &mut info.try_borrow_mut_data()?;
+    data[space - LedgerReceipt::LEN..].copy_from_slice(bytemuck::bytes_of(&record));
+
+    let ledger: &mut ReceiptLedger = bytemuck::from_bytes_mut(&mut data[8..8 + ReceiptLedger::LEN]);
+    ledger.receipt_count = unwrap_int!(ledger.receipt_count.checked_add(1));
+
+    Ok(())
+}
+
+/// Removes the record at `index` from the [ReceiptLedger] by moving the last record into
+/// its place, and returns the rent freed by shrinking the ledger to `funds_receiver`.
+pub(crate) fn remove_ledger_receipt(
+    info: &AccountInfo,
+    funds_receiver: &AccountInfo,
+    index: u64,
+) -> Result<()> {
+    let receipt_count = {
+        let data = &mut info.try_borrow_mut_data()?;
+        let (ledger, records) = ReceiptLedger::split_mut(data)?;
+
+        invariant!(
+            (index as usize) < records.len(),
+            ProtocolError::InvalidLedgerIndex
+        );
+        records.swap(index as usize, records.len() - 1);
+        ledger.receipt_count -= 1;
+
+        ledger.receipt_count
+    };
+    let space = unwrap_int!(ReceiptLedger::space(receipt_count));
+
+    info.realloc(space, false)?;
+
+    let lamports = info
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(space));
+
+    let receiver_lamports = unwrap_int!(funds_receiver.lamports().checked_add(lamports));
+
+    **info.try_borrow_mut_lamports()? -= lamports;
+    **funds_receiver.try_borrow_mut_lamports()? = receiver_lamports;
+
+    Ok(())
+}
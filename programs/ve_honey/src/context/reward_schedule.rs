@@ -0,0 +1,136 @@
+use crate::*;
+use govern::Governor;
+
+#[derive(Accounts)]
+pub struct SetRewardSchedule<'info> {
+    /// payer of the initialization.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// the [Locker].
+    pub locker: Box<Account<'info, Locker>>,
+    /// the [RewardSchedule].
+    #[account(
+        init_if_needed,
+        seeds = [
+            REWARD_SCHEDULE_SEED.as_bytes(),
+            locker.key().as_ref(),
+        ],
+        bump,
+        space = 8 + RewardSchedule::LEN,
+        payer = payer,
+    )]
+    pub reward_schedule: Box<Account<'info, RewardSchedule>>,
+    /// the [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// the smart wallet on the [Governor].
+    pub smart_wallet: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetRewardSchedule<'info> {
+    pub fn process(&mut self, schedule: NftRewardSchedule) -> Result<()> {
+        schedule.validate()?;
+
+        let reward_schedule = &mut self.reward_schedule;
+
+        reward_schedule.locker = self.locker.key();
+        reward_schedule.schedule = schedule;
+
+        emit!(SetRewardScheduleEvent {
+            locker: reward_schedule.locker,
+            schedule: reward_schedule.schedule.clone(),
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for SetRewardSchedule<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.locker.governor,
+            self.governor,
+            ProtocolError::GovernorMismatch
+        );
+        assert_keys_eq!(
+            self.governor.smart_wallet,
+            self.smart_wallet,
+            ProtocolError::SmartWalletMismatch
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct RemoveRewardSchedule<'info> {
+    /// the [Locker].
+    pub locker: Box<Account<'info, Locker>>,
+    /// the [RewardSchedule].
+    #[account(mut, close = funds_receiver)]
+    pub reward_schedule: Box<Account<'info, RewardSchedule>>,
+    /// CHECK: funds receiver
+    #[account(mut)]
+    pub funds_receiver: UncheckedAccount<'info>,
+    /// the [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// the smart wallet on the [Governor].
+    pub smart_wallet: Signer<'info>,
+}
+
+impl<'info> RemoveRewardSchedule<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let reward_schedule = &mut self.reward_schedule;
+
+        emit!(RemoveRewardScheduleEvent {
+            locker: reward_schedule.locker,
+        });
+
+        reward_schedule.locker = Pubkey::default();
+        reward_schedule.schedule = NftRewardSchedule::default();
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for RemoveRewardSchedule<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.locker,
+            self.reward_schedule.locker,
+            ProtocolError::InvalidLocker
+        );
+        assert_keys_eq!(
+            self.locker.governor,
+            self.governor,
+            ProtocolError::GovernorMismatch
+        );
+        assert_keys_eq!(
+            self.governor.smart_wallet,
+            self.smart_wallet,
+            ProtocolError::SmartWalletMismatch
+        );
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event called in [ve_honey::set_reward_schedule].
+pub struct SetRewardScheduleEvent {
+    /// The [Locker].
+    #[index]
+    pub locker: Pubkey,
+    /// The new schedule of the NFT rewards.
+    pub schedule: NftRewardSchedule,
+}
+
+#[event]
+/// Event called in [ve_honey::remove_reward_schedule].
+pub struct RemoveRewardScheduleEvent {
+    /// The [Locker].
+    #[index]
+    pub locker: Pubkey,
+}
@@ -14,6 +14,7 @@ pub struct SetLockerParams<'info> {
 
 impl<'info> SetLockerParams<'info> {
     pub fn process(&mut self, params: LockerParams) -> Result<()> {
+        params.validate()?;
         self.locker.params = params;
 
         Ok(())
@@ -0,0 +1,448 @@
+use crate::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ProposeEscrowTransfer<'info> {
+    /// payer of the initialization.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// [Escrow] being transferred.
+    pub escrow: Box<Account<'info, Escrow>>,
+    /// authority of the [Escrow].
+    pub escrow_owner: Signer<'info>,
+    /// the [EscrowTransfer].
+    #[account(
+        init_if_needed,
+        seeds = [
+            ESCROW_TRANSFER_SEED.as_bytes(),
+            escrow.key().as_ref(),
+        ],
+        bump,
+        space = 8 + EscrowTransfer::LEN,
+        payer = payer,
+    )]
+    pub escrow_transfer: Box<Account<'info, EscrowTransfer>>,
+    /// CHECK: owner the [Escrow] is transferred to, who accepts the transfer.
+    pub new_owner: UncheckedAccount<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ProposeEscrowTransfer<'info> {
+    /// Proposes the transfer to the new owner, replacing any pending proposal.
+    pub fn process(&mut self) -> Result<()> {
+        let escrow_transfer = &mut self.escrow_transfer;
+
+        escrow_transfer.escrow = self.escrow.key();
+        escrow_transfer.new_owner = self.new_owner.key();
+
+        emit!(ProposeEscrowTransferEvent {
+            locker: self.escrow.locker,
+            escrow: escrow_transfer.escrow,
+            escrow_owner: self.escrow.owner,
+            new_owner: escrow_transfer.new_owner,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for ProposeEscrowTransfer<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.escrow.owner,
+            self.escrow_owner,
+            ProtocolError::InvalidAccountOwner
+        );
+        assert_keys_neq!(
+            self.escrow.owner,
+            self.new_owner,
+            ProtocolError::InvalidEscrowTransfer
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct CancelEscrowTransfer<'info> {
+    /// [Escrow] being transferred.
+    pub escrow: Box<Account<'info, Escrow>>,
+    /// authority of the [Escrow].
+    pub escrow_owner: Signer<'info>,
+    /// the [EscrowTransfer].
+    #[account(mut, close = funds_receiver)]
+    pub escrow_transfer: Box<Account<'info, EscrowTransfer>>,
+    /// CHECK: funds receiver
+    #[account(mut)]
+    pub funds_receiver: UncheckedAccount<'info>,
+}
+
+impl<'info> CancelEscrowTransfer<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for CancelEscrowTransfer<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.escrow,
+            self.escrow_transfer.escrow,
+            ProtocolError::InvalidEscrowTransfer
+        );
+        assert_keys_eq!(
+            self.escrow.owner,
+            self.escrow_owner,
+            ProtocolError::InvalidAccountOwner
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct AcceptEscrowTransfer<'info> {
+    /// payer of the initialization of the new [Escrow].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// [Locker].
+    pub locker: Box<Account<'info, Locker>>,
+    /// [Escrow] being transferred, closed to its previous owner.
+    #[account(mut, close = prev_owner)]
+    pub escrow: Box<Account<'info, Escrow>>,
+    /// CHECK: previous owner of the [Escrow], receiving the rent of its closed accounts.
+    #[account(mut)]
+    pub prev_owner: UncheckedAccount<'info>,
+    /// the [EscrowTransfer].
+    #[account(
+        mut,
+        close = prev_owner,
+        seeds = [
+            ESCROW_TRANSFER_SEED.as_bytes(),
+            escrow.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub escrow_transfer: Box<Account<'info, EscrowTransfer>>,
+    /// [Escrow] rebuilt under the new owner.
+    #[account(
+        init,
+        seeds = [
+            ESCROW_SEED.as_bytes(),
+            locker.key().as_ref(),
+            new_owner.key().as_ref(),
+        ],
+        bump,
+        space = 8 + Escrow::LEN,
+        payer = payer,
+    )]
+    pub new_escrow: Box<Account<'info, Escrow>>,
+    /// owner the [Escrow] is transferred to.
+    pub new_owner: Signer<'info>,
+    /// tokens locked up in the [Escrow].
+    #[account(mut)]
+    pub locked_tokens: Box<Account<'info, TokenAccount>>,
+    /// tokens of the new [Escrow].
+    #[account(mut)]
+    pub new_locked_tokens: Box<Account<'info, TokenAccount>>,
+    /// CHECK: tokens of the [Escrow] in the reward mint, held for its receipts; only
+    /// read when the [Escrow] holds rewards for its receipts.
+    #[account(mut)]
+    pub reward_tokens: UncheckedAccount<'info>,
+    /// CHECK: tokens of the new [Escrow] in the reward mint; only read with `reward_tokens`.
+    #[account(mut)]
+    pub new_reward_tokens: UncheckedAccount<'info>,
+    /// CHECK: [ReceiptLedger] of the [Escrow], which may not exist.
+    #[account(
+        mut,
+        seeds = [RECEIPT_LEDGER_SEED.as_bytes(), escrow.key().as_ref()],
+        bump,
+    )]
+    pub ledger: UncheckedAccount<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> AcceptEscrowTransfer<'info> {
+    /// Rebuilds the [Escrow] under the new owner with the same lock, balance and receipts.
+    ///
+    /// Every live [NftReceipt] account of the [Escrow] is given as the remaining accounts,
+    /// in increasing receipt id. Receipts keep the address derived from the owner that
+    /// locked them, so they are checked by their data, and together with the records of
+    /// the [ReceiptLedger] and the closed receipts they must account for every receipt of
+    /// the [Escrow]. The [ReceiptLedger] keeps its address and is moved to the new
+    /// [Escrow].
+    ///
+    /// Rewards held for the receipts in the reward mint are moved with them, so every
+    /// receipt with rewards left to claim in a reward mint must use the mint of
+    /// `reward_tokens`.
+    pub fn process(&mut self, bump: u8, receipts: &[AccountInfo<'info>]) -> Result<()> {
+        let new_escrow_key = self.new_escrow.key();
+        let escrow = &self.escrow;
+        let new_escrow = &mut self.new_escrow;
+
+        new_escrow.init(
+            &new_escrow_key,
+            &escrow.locker,
+            &self.locker.token_mint,
+            self.new_owner.key(),
+            bump,
+        );
+        new_escrow.amount = escrow.amount;
+        new_escrow.escrow_started_at = escrow.escrow_started_at;
+        new_escrow.escrow_ends_at = escrow.escrow_ends_at;
+        new_escrow.receipt_count = escrow.receipt_count;
+        new_escrow.amount_to_receipt = escrow.amount_to_receipt;
+        new_escrow.reward_amount_to_receipt = escrow.reward_amount_to_receipt;
+        new_escrow.closed_receipt_count = escrow.closed_receipt_count;
+        if escrow.vote_delegate != escrow.owner {
+            new_escrow.vote_delegate = escrow.vote_delegate;
+        }
+
+        let seeds: &[&[&[u8]]] = escrow_seeds!(escrow);
+
+        token::transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::Transfer {
+                    from: self.locked_tokens.to_account_info(),
+                    to: self.new_locked_tokens.to_account_info(),
+                    authority: escrow.to_account_info(),
+                },
+            )
+            .with_signer(seeds),
+            self.locked_tokens.amount,
+        )?;
+
+        token::close_account(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::CloseAccount {
+                    account: self.locked_tokens.to_account_info(),
+                    destination: self.prev_owner.to_account_info(),
+                    authority: escrow.to_account_info(),
+                },
+            )
+            .with_signer(seeds),
+        )?;
+
+        let reward_mint = if escrow.reward_amount_to_receipt > 0 {
+            Some(self.transfer_reward_tokens()?)
+        } else {
+            None
+        };
+
+        let mut ledger_receipt_count = 0;
+        if self.ledger.owner == &crate::ID {
+            let ledger = AccountLoader::<ReceiptLedger>::try_from(&self.ledger)?;
+            let mut ledger = ledger.load_mut()?;
+
+            assert_keys_eq!(ledger.escrow, escrow.key(), ProtocolError::InvalidLedger);
+            ledger.escrow = new_escrow_key;
+            ledger.owner = self.new_owner.key();
+            ledger_receipt_count = ledger.receipt_count;
+        }
+
+        self.transfer_receipts(receipts, ledger_receipt_count, reward_mint)?;
+
+        emit!(TransferEscrowEvent {
+            locker: escrow.locker,
+            prev_escrow: escrow.key(),
+            prev_owner: escrow.owner,
+            escrow: new_escrow_key,
+            escrow_owner: self.new_owner.key(),
+            amount: escrow.amount,
+            reward_amount: escrow.reward_amount_to_receipt,
+            receipt_count: escrow.receipt_count,
+        });
+
+        Ok(())
+    }
+
+    /// Moves the rewards held for the receipts to the new [Escrow], returning their mint.
+    fn transfer_reward_tokens(&self) -> Result<Pubkey> {
+        let escrow = &self.escrow;
+        let reward_tokens = Account::<TokenAccount>::try_from(&self.reward_tokens)?;
+        let new_reward_tokens = Account::<TokenAccount>::try_from(&self.new_reward_tokens)?;
+
+        assert_keys_neq!(
+            reward_tokens.mint,
+            self.locker.token_mint,
+            ProtocolError::InvalidToken
+        );
+        assert_keys_eq!(
+            reward_tokens.owner,
+            escrow.key(),
+            ProtocolError::InvalidToken
+        );
+        assert_keys_eq!(
+            new_reward_tokens,
+            anchor_spl::associated_token::get_associated_token_address(
+                &self.new_escrow.key(),
+                &reward_tokens.mint,
+            ),
+            ProtocolError::InvalidAssociatedTokenAccount
+        );
+
+        let seeds: &[&[&[u8]]] = escrow_seeds!(escrow);
+
+        token::transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::Transfer {
+                    from: reward_tokens.to_account_info(),
+                    to: new_reward_tokens.to_account_info(),
+                    authority: escrow.to_account_info(),
+                },
+            )
+            .with_signer(seeds),
+            reward_tokens.amount,
+        )?;
+
+        token::close_account(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::CloseAccount {
+                    account: reward_tokens.to_account_info(),
+                    destination: self.prev_owner.to_account_info(),
+                    authority: escrow.to_account_info(),
+                },
+            )
+            .with_signer(seeds),
+        )?;
+
+        Ok(reward_tokens.mint)
+    }
+
+    fn transfer_receipts(
+        &self,
+        receipts: &[AccountInfo<'info>],
+        ledger_receipt_count: u64,
+        reward_mint: Option<Pubkey>,
+    ) -> Result<()> {
+        let escrow = &self.escrow;
+        let accounted_receipt_count = unwrap_int!((receipts.len() as u64)
+            .checked_add(ledger_receipt_count)
+            .and_then(|count| count.checked_add(escrow.closed_receipt_count)));
+        invariant!(
+            accounted_receipt_count == escrow.receipt_count,
+            ProtocolError::InvalidRemainingAccounts
+        );
+
+        let mut next_receipt_id = 0;
+        for info in receipts.iter() {
+            invariant!(
+                info.owner == &crate::ID,
+                ProtocolError::InvalidRemainingAccounts
+            );
+
+            let mut nft_receipt: Account<NftReceipt> = Account::try_from(info)?;
+            assert_keys_eq!(
+                nft_receipt.locker,
+                escrow.locker,
+                ProtocolError::InvalidLocker
+            );
+            assert_keys_eq!(
+                nft_receipt.owner,
+                escrow.owner,
+                ProtocolError::InvalidAccountOwner
+            );
+            invariant!(
+                nft_receipt.receipt_id >= next_receipt_id
+                    && nft_receipt.receipt_id < escrow.receipt_count,
+                ProtocolError::InvalidRemainingAccounts
+            );
+            next_receipt_id = unwrap_int!(nft_receipt.receipt_id.checked_add(1));
+            if nft_receipt.reward_mint.is_some()
+                && unwrap_int!(nft_receipt.calculate_max_reward_amount())
+                    > nft_receipt.claimed_amount
+            {
+                invariant!(
+                    nft_receipt.reward_mint == reward_mint,
+                    ProtocolError::InvalidToken
+                );
+            }
+
+            nft_receipt.owner = self.new_owner.key();
+            nft_receipt.exit(&crate::ID)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for AcceptEscrowTransfer<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.locker,
+            self.escrow.locker,
+            ProtocolError::InvalidLocker
+        );
+        assert_keys_eq!(
+            self.escrow.owner,
+            self.prev_owner,
+            ProtocolError::InvalidAccountOwner
+        );
+        assert_keys_eq!(
+            self.escrow_transfer.new_owner,
+            self.new_owner,
+            ProtocolError::InvalidEscrowTransfer
+        );
+        assert_keys_eq!(
+            self.locked_tokens,
+            self.escrow.tokens,
+            ProtocolError::InvalidToken
+        );
+        assert_keys_eq!(
+            self.new_locked_tokens,
+            anchor_spl::associated_token::get_associated_token_address(
+                &self.new_escrow.key(),
+                &self.locker.token_mint,
+            ),
+            ProtocolError::InvalidAssociatedTokenAccount
+        );
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event called in [ve_honey::propose_escrow_transfer].
+pub struct ProposeEscrowTransferEvent {
+    /// [Locker] of the [Escrow].
+    #[index]
+    pub locker: Pubkey,
+    /// The [Escrow] being transferred.
+    pub escrow: Pubkey,
+    /// The owner of the [Escrow].
+    pub escrow_owner: Pubkey,
+    /// The owner the [Escrow] is transferred to.
+    pub new_owner: Pubkey,
+}
+
+#[event]
+/// Event called in [ve_honey::accept_escrow_transfer].
+pub struct TransferEscrowEvent {
+    /// [Locker] of the [Escrow].
+    #[index]
+    pub locker: Pubkey,
+    /// The closed [Escrow].
+    pub prev_escrow: Pubkey,
+    /// The previous owner of the [Escrow].
+    pub prev_owner: Pubkey,
+    /// The rebuilt [Escrow].
+    pub escrow: Pubkey,
+    /// The new owner of the [Escrow].
+    pub escrow_owner: Pubkey,
+    /// Amount of tokens moved to the rebuilt [Escrow].
+    pub amount: u64,
+    /// Amount of rewards held for the receipts moved to the rebuilt [Escrow].
+    pub reward_amount: u64,
+    /// Count of receipts of the [Escrow].
+    pub receipt_count: u64,
+}
@@ -0,0 +1,291 @@
+use crate::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+use govern::Governor;
+
+/// Fund a treasury of the [Locker] with reward tokens.
+#[derive(Accounts)]
+pub struct FundTreasury<'info> {
+    /// the [Locker].
+    pub locker: Box<Account<'info, Locker>>,
+    /// treasury of the [Locker].
+    #[account(
+        mut,
+        seeds = [
+            TREASURY_SEED.as_bytes(),
+            locker.key().as_ref(),
+            treasury.mint.as_ref(),
+        ],
+        bump,
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
+    /// The source of tokens.
+    #[account(mut)]
+    pub source_tokens: Box<Account<'info, TokenAccount>>,
+    /// The authority of source_tokens.
+    pub source_tokens_authority: Signer<'info>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> FundTreasury<'info> {
+    pub fn process(&mut self, amount: u64) -> Result<()> {
+        invariant!(amount > 0, ProtocolError::LockAmountZero);
+
+        token::transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::Transfer {
+                    from: self.source_tokens.to_account_info(),
+                    to: self.treasury.to_account_info(),
+                    authority: self.source_tokens_authority.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        emit!(FundTreasuryEvent {
+            locker: self.locker.key(),
+            funder: self.source_tokens_authority.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for FundTreasury<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Withdraw unreserved tokens from a treasury of the [Locker].
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    /// the [Locker].
+    pub locker: Box<Account<'info, Locker>>,
+    /// treasury of the [Locker].
+    #[account(
+        mut,
+        seeds = [
+            TREASURY_SEED.as_bytes(),
+            locker.key().as_ref(),
+            treasury.mint.as_ref(),
+        ],
+        bump,
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
+    /// destination of the withdrawn tokens.
+    #[account(mut)]
+    pub destination_tokens: Box<Account<'info, TokenAccount>>,
+    /// the [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// the smart wallet on the [Governor], or the treasury authority of the [Locker].
+    pub authority: Signer<'info>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> WithdrawTreasury<'info> {
+    /// Withdraws `amount` from the treasury, keeping the rewards reserved by the [Locker]
+    /// in the treasury of its reward mint.
+    pub fn process(&mut self, amount: u64) -> Result<()> {
+        let remaining = unwrap_int!(self.treasury.amount.checked_sub(amount));
+        let reserved_rewards = if self.treasury.mint == self.locker.reward_treasury_mint() {
+            self.locker.reserved_rewards
+        } else {
+            0
+        };
+        invariant!(
+            remaining >= reserved_rewards,
+            ProtocolError::InsufficientTreasuryFunds
+        );
+
+        let seeds: &[&[&[u8]]] = locker_seeds!(self.locker);
+
+        token::transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::Transfer {
+                    from: self.treasury.to_account_info(),
+                    to: self.destination_tokens.to_account_info(),
+                    authority: self.locker.to_account_info(),
+                },
+            )
+            .with_signer(seeds),
+            amount,
+        )?;
+
+        emit!(WithdrawTreasuryEvent {
+            locker: self.locker.key(),
+            authority: self.authority.key(),
+            destination: self.destination_tokens.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for WithdrawTreasury<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.locker.governor,
+            self.governor,
+            ProtocolError::GovernorMismatch
+        );
+
+        let authority = self.authority.key();
+        invariant!(
+            authority == self.governor.smart_wallet
+                || Some(authority) == self.locker.treasury_authority,
+            ProtocolError::TreasuryAuthorityMismatch
+        );
+
+        Ok(())
+    }
+}
+
+/// Set the account allowed to withdraw from the treasury besides the smart wallet.
+#[derive(Accounts)]
+pub struct SetTreasuryAuthority<'info> {
+    /// the [Locker].
+    #[account(mut)]
+    pub locker: Box<Account<'info, Locker>>,
+    /// the [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// the smart wallet on the [Governor].
+    pub smart_wallet: Signer<'info>,
+}
+
+impl<'info> SetTreasuryAuthority<'info> {
+    pub fn process(&mut self, treasury_authority: Option<Pubkey>) -> Result<()> {
+        self.locker.treasury_authority = treasury_authority;
+
+        emit!(SetTreasuryAuthorityEvent {
+            locker: self.locker.key(),
+            treasury_authority,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for SetTreasuryAuthority<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.locker.governor,
+            self.governor,
+            ProtocolError::GovernorMismatch
+        );
+        assert_keys_eq!(
+            self.governor.smart_wallet,
+            self.smart_wallet,
+            ProtocolError::SmartWalletMismatch
+        );
+
+        Ok(())
+    }
+}
+
+/// Set the mint the NFT rewards are paid in.
+#[derive(Accounts)]
+pub struct SetRewardMint<'info> {
+    /// the [Locker].
+    #[account(mut)]
+    pub locker: Box<Account<'info, Locker>>,
+    /// the [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// the smart wallet on the [Governor].
+    pub smart_wallet: Signer<'info>,
+}
+
+impl<'info> SetRewardMint<'info> {
+    /// Pays the rewards of the NFTs locked from now on in `reward_mint`, vested to the
+    /// owners of the receipts, or locks them into the [Escrow] in [Locker::token_mint] when
+    /// unset. Existing receipts keep the mint they were created with.
+    pub fn process(&mut self, reward_mint: Option<Pubkey>) -> Result<()> {
+        invariant!(
+            reward_mint != Some(self.locker.token_mint),
+            ProtocolError::InvalidRewardMint
+        );
+
+        self.locker.reward_mint = reward_mint;
+
+        emit!(SetRewardMintEvent {
+            locker: self.locker.key(),
+            reward_mint,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for SetRewardMint<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.locker.governor,
+            self.governor,
+            ProtocolError::GovernorMismatch
+        );
+        assert_keys_eq!(
+            self.governor.smart_wallet,
+            self.smart_wallet,
+            ProtocolError::SmartWalletMismatch
+        );
+        invariant!(
+            self.locker.reserved_rewards == 0,
+            ProtocolError::RewardsReserved
+        );
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event called in [ve_honey::fund_treasury].
+pub struct FundTreasuryEvent {
+    /// The [Locker].
+    #[index]
+    pub locker: Pubkey,
+    /// Authority of the funding tokens.
+    pub funder: Pubkey,
+    /// Amount funded.
+    pub amount: u64,
+}
+
+#[event]
+/// Event called in [ve_honey::withdraw_treasury].
+pub struct WithdrawTreasuryEvent {
+    /// The [Locker].
+    #[index]
+    pub locker: Pubkey,
+    /// The smart wallet or treasury authority that withdrew.
+    pub authority: Pubkey,
+    /// Destination token account.
+    pub destination: Pubkey,
+    /// Amount withdrawn.
+    pub amount: u64,
+}
+
+#[event]
+/// Event called in [ve_honey::set_treasury_authority].
+pub struct SetTreasuryAuthorityEvent {
+    /// The [Locker].
+    #[index]
+    pub locker: Pubkey,
+    /// The new treasury authority.
+    pub treasury_authority: Option<Pubkey>,
+}
+
+#[event]
+/// Event called in [ve_honey::set_reward_mint].
+pub struct SetRewardMintEvent {
+    /// The [Locker].
+    #[index]
+    pub locker: Pubkey,
+    /// The new reward mint.
+    pub reward_mint: Option<Pubkey>,
+}
@@ -0,0 +1,116 @@
+use crate::*;
+use govern::Governor;
+
+#[derive(Accounts)]
+pub struct SetVestTiers<'info> {
+    /// payer of the initialization.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// the [Locker].
+    pub locker: Box<Account<'info, Locker>>,
+    /// the [VestTiers].
+    #[account(
+        init_if_needed,
+        seeds = [
+            VEST_TIERS_SEED.as_bytes(),
+            locker.key().as_ref(),
+        ],
+        bump,
+        space = 8 + VestTiers::LEN,
+        payer = payer,
+    )]
+    pub vest_tiers: Box<Account<'info, VestTiers>>,
+    /// the [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// the smart wallet on the [Governor].
+    pub smart_wallet: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetVestTiers<'info> {
+    /// Replaces the tiers; receipts already locked keep the tier they picked.
+    pub fn process(&mut self, tiers: Vec<VestTier>) -> Result<()> {
+        invariant!(
+            !tiers.is_empty() && tiers.len() <= MAX_VEST_TIERS,
+            ProtocolError::InvalidVestTiers
+        );
+        for tier in tiers.iter() {
+            tier.validate(&self.locker.params)?;
+        }
+
+        let vest_tiers = &mut self.vest_tiers;
+
+        vest_tiers.locker = self.locker.key();
+        vest_tiers.tiers = tiers;
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for SetVestTiers<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.locker.governor,
+            self.governor,
+            ProtocolError::GovernorMismatch
+        );
+        assert_keys_eq!(
+            self.governor.smart_wallet,
+            self.smart_wallet,
+            ProtocolError::SmartWalletMismatch
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct RemoveVestTiers<'info> {
+    /// the [Locker].
+    pub locker: Box<Account<'info, Locker>>,
+    /// the [VestTiers].
+    #[account(mut, close = funds_receiver)]
+    pub vest_tiers: Box<Account<'info, VestTiers>>,
+    /// CHECK: funds receiver
+    #[account(mut)]
+    pub funds_receiver: UncheckedAccount<'info>,
+    /// the [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// the smart wallet on the [Governor].
+    pub smart_wallet: Signer<'info>,
+}
+
+impl<'info> RemoveVestTiers<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let vest_tiers = &mut self.vest_tiers;
+
+        vest_tiers.locker = Pubkey::default();
+        vest_tiers.tiers = vec![];
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for RemoveVestTiers<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.locker,
+            self.vest_tiers.locker,
+            ProtocolError::InvalidLocker
+        );
+        assert_keys_eq!(
+            self.locker.governor,
+            self.governor,
+            ProtocolError::GovernorMismatch
+        );
+        assert_keys_eq!(
+            self.governor.smart_wallet,
+            self.smart_wallet,
+            ProtocolError::SmartWalletMismatch
+        );
+
+        Ok(())
+    }
+}
@@ -38,18 +38,24 @@ pub struct ApproveProgramLockPrivilege<'info> {
 }
 
 impl<'info> ApproveProgramLockPrivilege<'info> {
-    pub fn process(&mut self, bump: u8) -> Result<()> {
+    pub fn process(&mut self, bump: u8, params: WhitelistEntryParams) -> Result<()> {
+        params.validate()?;
+
         let whitelist_entry = &mut self.whitelist_entry;
 
         whitelist_entry.bump = bump;
         whitelist_entry.locker = self.locker.key();
         whitelist_entry.program_id = self.executable_id.key();
         whitelist_entry.owner = self.whitelisted_owner.key();
+        whitelist_entry.params = params;
+        whitelist_entry.locked_amount = 0;
+        whitelist_entry.lock_count = 0;
 
         emit!(ApproveLockPrivilegeEvent {
             locker: whitelist_entry.locker,
             program_id: whitelist_entry.program_id,
             owner: whitelist_entry.owner,
+            params,
             timestamp: Clock::get()?.unix_timestamp
         });
 
@@ -73,6 +79,53 @@ impl<'info> Validate<'info> for ApproveProgramLockPrivilege<'info> {
     }
 }
 
+#[derive(Accounts)]
+pub struct SetProgramLockPrivilegeParams<'info> {
+    /// [Locker].
+    #[account(has_one = governor)]
+    pub locker: Box<Account<'info, Locker>>,
+    /// [WhitelistEntry].
+    #[account(mut, has_one = locker)]
+    pub whitelist_entry: Box<Account<'info, WhitelistEntry>>,
+    /// Governor of the [Locker].
+    pub governor: Box<Account<'info, Governor>>,
+    /// Smart wallet on the [Governor].
+    pub smart_wallet: Signer<'info>,
+}
+
+impl<'info> SetProgramLockPrivilegeParams<'info> {
+    pub fn process(&mut self, params: WhitelistEntryParams) -> Result<()> {
+        params.validate()?;
+
+        let whitelist_entry = &mut self.whitelist_entry;
+        let prev_params = whitelist_entry.params;
+        whitelist_entry.params = params;
+
+        emit!(SetLockPrivilegeParamsEvent {
+            locker: whitelist_entry.locker,
+            program_id: whitelist_entry.program_id,
+            owner: whitelist_entry.owner,
+            prev_params,
+            params,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for SetProgramLockPrivilegeParams<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.governor.smart_wallet,
+            self.smart_wallet,
+            ProtocolError::SmartWalletMismatch
+        );
+
+        Ok(())
+    }
+}
+
 #[derive(Accounts)]
 pub struct RevokeProgramLockPrivilege<'info> {
     /// Payer.
@@ -124,6 +177,26 @@ pub struct ApproveLockPrivilegeEvent {
     pub program_id: Pubkey,
     /// Owner of the [Escrow].
     pub owner: Pubkey,
+    /// Limits of the [WhitelistEntry].
+    pub params: WhitelistEntryParams,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+#[event]
+/// Event called in [ve_honey::set_program_lock_privilege_params].
+pub struct SetLockPrivilegeParamsEvent {
+    /// [Locker].
+    #[index]
+    pub locker: Pubkey,
+    /// ProgramId approved to make CPI calls to [ve_honey::lock].
+    pub program_id: Pubkey,
+    /// Owner of the [Escrow].
+    pub owner: Pubkey,
+    /// Previous limits of the [WhitelistEntry].
+    pub prev_params: WhitelistEntryParams,
+    /// New limits of the [WhitelistEntry].
+    pub params: WhitelistEntryParams,
     /// Timestamp
     pub timestamp: i64,
 }
@@ -0,0 +1,127 @@
+use crate::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+/// Withdraw the NFT of a custodial [NftReceipt] once its vest has ended.
+#[derive(Accounts)]
+pub struct WithdrawNft<'info> {
+    /// [Locker].
+    pub locker: Box<Account<'info, Locker>>,
+    /// [NftReceipt] of the custodial lock.
+    #[account(mut)]
+    pub nft_receipt: Box<Account<'info, NftReceipt>>,
+    /// owner of the [NftReceipt].
+    pub receipt_owner: Signer<'info>,
+    /// vault of the [Locker] holding the nft.
+    #[account(
+        mut,
+        seeds = [
+            NFT_VAULT_SEED.as_bytes(),
+            locker.key().as_ref(),
+            nft_receipt.nft_mint.as_ref(),
+        ],
+        bump,
+    )]
+    pub nft_vault: Box<Account<'info, TokenAccount>>,
+    /// destination of the nft.
+    #[account(mut)]
+    pub nft_destination: Box<Account<'info, TokenAccount>>,
+    /// CHECK: receiver of the rent of the vault.
+    #[account(mut)]
+    pub funds_receiver: UncheckedAccount<'info>,
+
+    /// token program
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> WithdrawNft<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let seeds: &[&[&[u8]]] = locker_seeds!(self.locker);
+
+        token::transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::Transfer {
+                    from: self.nft_vault.to_account_info(),
+                    to: self.nft_destination.to_account_info(),
+                    authority: self.locker.to_account_info(),
+                },
+            )
+            .with_signer(seeds),
+            self.nft_vault.amount,
+        )?;
+
+        token::close_account(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::CloseAccount {
+                    account: self.nft_vault.to_account_info(),
+                    destination: self.funds_receiver.to_account_info(),
+                    authority: self.locker.to_account_info(),
+                },
+            )
+            .with_signer(seeds),
+        )?;
+
+        self.nft_receipt.withdrawn = true;
+
+        emit!(WithdrawNftEvent {
+            locker: self.locker.key(),
+            receipt_owner: self.nft_receipt.owner,
+            receipt_id: self.nft_receipt.receipt_id,
+            nft_mint: self.nft_receipt.nft_mint,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for WithdrawNft<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.locker,
+            self.nft_receipt.locker,
+            ProtocolError::InvalidLocker
+        );
+        assert_keys_eq!(
+            self.receipt_owner,
+            self.nft_receipt.owner,
+            ProtocolError::InvalidAccountOwner
+        );
+        invariant!(
+            self.nft_receipt.custodial,
+            ProtocolError::ReceiptNotCustodial
+        );
+        invariant!(
+            !self.nft_receipt.withdrawn,
+            ProtocolError::NftAlreadyWithdrawn
+        );
+        invariant!(
+            self.nft_receipt.vest_ends_at < Clock::get()?.unix_timestamp,
+            ProtocolError::ReceiptNotEnded
+        );
+        assert_keys_eq!(
+            self.nft_destination.mint,
+            self.nft_receipt.nft_mint,
+            ProtocolError::InvalidNftMint
+        );
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event called in [ve_honey::withdraw_nft].
+pub struct WithdrawNftEvent {
+    /// [Locker] of the [NftReceipt].
+    #[index]
+    pub locker: Pubkey,
+    /// The owner of the [NftReceipt].
+    pub receipt_owner: Pubkey,
+    /// Id of the [NftReceipt].
+    pub receipt_id: u64,
+    /// Mint of the withdrawn NFT.
+    pub nft_mint: Pubkey,
+    /// Timestamp for the event.
+    pub timestamp: i64,
+}
@@ -0,0 +1,240 @@
+use crate::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use govern::Governor;
+
+#[derive(Accounts)]
+#[instruction(offer_id: u64)]
+pub struct RegisterWlOffer<'info> {
+    /// payer of the initialization.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// the [Locker].
+    pub locker: Box<Account<'info, Locker>>,
+    /// the [WlOffer].
+    #[account(
+        init,
+        seeds = [
+            WL_OFFER_SEED.as_bytes(),
+            locker.key().as_ref(),
+            offer_id.to_le_bytes().as_ref(),
+        ],
+        bump,
+        space = 8 + WlOffer::LEN,
+        payer = payer,
+    )]
+    pub offer: Box<Account<'info, WlOffer>>,
+    /// the [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// the smart wallet on the [Governor].
+    pub smart_wallet: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RegisterWlOffer<'info> {
+    pub fn process(&mut self, offer_id: u64, params: WlOfferParams) -> Result<()> {
+        params.validate()?;
+
+        let offer = &mut self.offer;
+
+        offer.locker = self.locker.key();
+        offer.offer_id = offer_id;
+        offer.params = params;
+        offer.redeemed_units = 0;
+        offer.wl_burned = 0;
+
+        emit!(WlOfferEvent {
+            locker: offer.locker,
+            offer_id,
+            params,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for RegisterWlOffer<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.locker.governor,
+            self.governor,
+            ProtocolError::GovernorMismatch
+        );
+        assert_keys_eq!(
+            self.governor.smart_wallet,
+            self.smart_wallet,
+            ProtocolError::SmartWalletMismatch
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct SetWlOfferParams<'info> {
+    /// the [Locker].
+    pub locker: Box<Account<'info, Locker>>,
+    /// the [WlOffer].
+    #[account(mut)]
+    pub offer: Box<Account<'info, WlOffer>>,
+    /// the [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// the smart wallet on the [Governor].
+    pub smart_wallet: Signer<'info>,
+}
+
+impl<'info> SetWlOfferParams<'info> {
+    /// Replaces the terms of the offer; units already redeemed are kept.
+    pub fn process(&mut self, params: WlOfferParams) -> Result<()> {
+        params.validate()?;
+
+        let offer = &mut self.offer;
+        offer.params = params;
+
+        emit!(WlOfferEvent {
+            locker: offer.locker,
+            offer_id: offer.offer_id,
+            params,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for SetWlOfferParams<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.locker, self.offer.locker, ProtocolError::InvalidLocker);
+        assert_keys_eq!(
+            self.locker.governor,
+            self.governor,
+            ProtocolError::GovernorMismatch
+        );
+        assert_keys_eq!(
+            self.governor.smart_wallet,
+            self.smart_wallet,
+            ProtocolError::SmartWalletMismatch
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct RedeemWl<'info> {
+    /// payer of the initialization of [WlRedemption].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// the [Locker].
+    pub locker: Box<Account<'info, Locker>>,
+    /// the [WlOffer].
+    #[account(mut)]
+    pub offer: Box<Account<'info, WlOffer>>,
+    /// the [WlRedemption] of the user.
+    #[account(
+        init_if_needed,
+        seeds = [
+            WL_REDEMPTION_SEED.as_bytes(),
+            offer.key().as_ref(),
+            owner.key().as_ref(),
+        ],
+        bump,
+        space = 8 + WlRedemption::LEN,
+        payer = payer,
+    )]
+    pub redemption: Box<Account<'info, WlRedemption>>,
+    /// the user redeeming.
+    pub owner: Signer<'info>,
+    /// WL token mint
+    #[account(mut)]
+    pub wl_token_mint: Box<Account<'info, Mint>>,
+    /// WL tokens burnt.
+    #[account(mut)]
+    pub wl_source: Box<Account<'info, TokenAccount>>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> RedeemWl<'info> {
+    /// Burns the WL tokens of `units` of the offer and records them on the offer and the
+    /// redemption of the user.
+    pub fn process(&mut self, units: u64) -> Result<()> {
+        invariant!(units > 0, ProtocolError::LockAmountZero);
+
+        let redemption = &mut self.redemption;
+        redemption.offer = self.offer.key();
+        redemption.owner = self.owner.key();
+
+        let wl_amount =
+            self.offer
+                .record_redemption(redemption, units, Clock::get()?.unix_timestamp)?;
+        let amount =
+            unwrap_int!(wl_amount.checked_mul(10u64.pow(self.wl_token_mint.decimals as u32)));
+
+        token::burn(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::Burn {
+                    mint: self.wl_token_mint.to_account_info(),
+                    from: self.wl_source.to_account_info(),
+                    authority: self.owner.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        emit!(RedeemWlEvent {
+            locker: self.locker.key(),
+            offer_id: self.offer.offer_id,
+            owner: redemption.owner,
+            units,
+            wl_amount,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for RedeemWl<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.locker, self.offer.locker, ProtocolError::InvalidLocker);
+        assert_keys_eq!(
+            self.wl_token_mint,
+            self.locker.wl_token_mint,
+            ProtocolError::InvalidLockerWLMint
+        );
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event called in [ve_honey::register_wl_offer] and [ve_honey::set_wl_offer_params].
+pub struct WlOfferEvent {
+    /// The [Locker].
+    #[index]
+    pub locker: Pubkey,
+    /// Id of the [WlOffer].
+    pub offer_id: u64,
+    /// Terms of the [WlOffer].
+    pub params: WlOfferParams,
+}
+
+#[event]
+/// Event called in [ve_honey::redeem_wl].
+pub struct RedeemWlEvent {
+    /// The [Locker].
+    #[index]
+    pub locker: Pubkey,
+    /// Id of the [WlOffer].
+    pub offer_id: u64,
+    /// The user that redeemed.
+    pub owner: Pubkey,
+    /// Number of units redeemed.
+    pub units: u64,
+    /// Number of whole WL tokens burnt.
+    pub wl_amount: u64,
+}
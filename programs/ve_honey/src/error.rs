@@ -16,6 +16,24 @@ pub enum ProtocolError {
     InvalidGovernorParams,
     InvalidVoteDelegate,
     InvalidProgramId,
+    InvalidWhitelistEntryParams,
+    InvalidLockerParams,
+    InvalidNftMint,
+    InvalidCollection,
+    InvalidNftRewardParams,
+    InvalidRewardSchedule,
+    InvalidLedger,
+    InvalidLedgerIndex,
+    InvalidCampaignParams,
+    InvalidBurnCounter,
+    InvalidMerkleProof,
+    InvalidRewardMint,
+    InvalidWlOfferParams,
+    InvalidVestTiers,
+    InvalidVestTier,
+    InvalidEscrowTransfer,
+    InvalidOperatorActions,
+    InvalidOperatorApproval,
 
     EscrowNotEnded = 1100,
     EscrowExpired,
@@ -28,12 +46,42 @@ pub enum ProtocolError {
     CloseNonZeroReceipt,
     ReceiptCountError,
     ReceiptNotEnded,
+    LockAmountZero,
+    ReceiptNotCustodial,
+    NftAlreadyWithdrawn,
+    NftNotWithdrawn,
+    ReceiptAlreadyMigrated,
+    DuplicateNftReceipt,
+    LedgerNotEmpty,
+    CampaignNotOpen,
+    CampaignNotActive,
+    CampaignBudgetExceeded,
+    CollectionBurnCapReached,
+    EscrowBurnCapReached,
+    InvalidCampaignStatus,
+    CampaignRequiresLockNft,
+    InsufficientTreasuryFunds,
+    RewardsReserved,
+    RewardMintNotSupported,
+    WlOfferExpired,
+    WlOfferSoldOut,
+    WlRedemptionCapReached,
+    VestTierNotSupported,
+    OperatorNotApproved,
+    OperatorApprovalExpired,
+    AccountAlreadyMigrated,
 
     MustProvideWhitelist = 1200,
     ProgramNotWhitelisted,
     EscrowOwnerNotWhitelisted,
     ProgramIdMustBeExecutable,
     NoProofProvided,
+    WhitelistEntryExpired,
+    WhitelistLockAmountExceeded,
+    WhitelistLockDurationTooShort,
+    WhitelistLockDurationTooLong,
+    InvalidLockAuthority,
+    WhitelistEntryNotWritable,
 
     GovernorMismatch = 1300,
     SmartWalletMismatch,
@@ -43,6 +91,7 @@ pub enum ProtocolError {
     ProposalMustBeActive,
     InsufficientVotingPower,
     LockedSupplyMismatch,
+    TreasuryAuthorityMismatch,
 
     InvariantViolated = 1400,
 }
@@ -5,11 +5,15 @@ pub mod constants;
 pub mod context;
 pub mod error;
 pub mod macros;
+pub mod merkle;
+pub mod nft;
 pub mod state;
 
 use constants::*;
 use context::*;
 use error::*;
+use merkle::*;
+use nft::*;
 use state::*;
 
 declare_id!("CKQapf8pWoMddT15grV8UCPjiLCTHa12NRgkKV63Lc7q");
@@ -37,6 +41,33 @@ pub mod ve_honey {
         Ok(())
     }
 
+    #[access_control(ctx.accounts.validate())]
+    pub fn fund_treasury(ctx: Context<FundTreasury>, amount: u64) -> Result<()> {
+        ctx.accounts.process(amount)?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        ctx.accounts.process(amount)?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_treasury_authority(
+        ctx: Context<SetTreasuryAuthority>,
+        treasury_authority: Option<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts.process(treasury_authority)?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_reward_mint(ctx: Context<SetRewardMint>, reward_mint: Option<Pubkey>) -> Result<()> {
+        ctx.accounts.process(reward_mint)?;
+        Ok(())
+    }
+
     #[access_control(ctx.accounts.validate())]
     pub fn set_locker_params(ctx: Context<SetLockerParams>, params: LockerParams) -> Result<()> {
         ctx.accounts.process(params)?;
@@ -44,8 +75,27 @@ pub mod ve_honey {
     }
 
     #[access_control(ctx.accounts.validate())]
-    pub fn approve_program_lock_privilege(ctx: Context<ApproveProgramLockPrivilege>) -> Result<()> {
-        ctx.accounts.process(unwrap_bump!(ctx, "whitelist_entry"))?;
+    pub fn migrate_locker(ctx: Context<MigrateLocker>) -> Result<()> {
+        ctx.accounts.process()?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn approve_program_lock_privilege(
+        ctx: Context<ApproveProgramLockPrivilege>,
+        params: WhitelistEntryParams,
+    ) -> Result<()> {
+        ctx.accounts
+            .process(unwrap_bump!(ctx, "whitelist_entry"), params)?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_program_lock_privilege_params(
+        ctx: Context<SetProgramLockPrivilegeParams>,
+        params: WhitelistEntryParams,
+    ) -> Result<()> {
+        ctx.accounts.process(params)?;
         Ok(())
     }
 
@@ -56,8 +106,28 @@ pub mod ve_honey {
     }
 
     #[access_control(ctx.accounts.validate())]
-    pub fn add_proof(ctx: Context<AddProof>, proof_type: u8) -> Result<()> {
-        ctx.accounts.process(proof_type)?;
+    pub fn migrate_whitelist_entry(ctx: Context<MigrateWhitelistEntry>) -> Result<()> {
+        ctx.accounts.process()?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn add_proof(
+        ctx: Context<AddProof>,
+        proof_type: u8,
+        reward_params: Option<NftRewardParams>,
+    ) -> Result<()> {
+        ctx.accounts.process(proof_type, reward_params)?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn add_merkle_proof(
+        ctx: Context<AddMerkleProof>,
+        root: [u8; 32],
+        reward_params: Option<NftRewardParams>,
+    ) -> Result<()> {
+        ctx.accounts.process(root, reward_params)?;
         Ok(())
     }
 
@@ -67,6 +137,63 @@ pub mod ve_honey {
         Ok(())
     }
 
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_proof(ctx: Context<MigrateProof>) -> Result<()> {
+        ctx.accounts.process()?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_reward_schedule(
+        ctx: Context<SetRewardSchedule>,
+        schedule: NftRewardSchedule,
+    ) -> Result<()> {
+        ctx.accounts.process(schedule)?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn remove_reward_schedule(ctx: Context<RemoveRewardSchedule>) -> Result<()> {
+        ctx.accounts.process()?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_vest_tiers(ctx: Context<SetVestTiers>, tiers: Vec<VestTier>) -> Result<()> {
+        ctx.accounts.process(tiers)?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn remove_vest_tiers(ctx: Context<RemoveVestTiers>) -> Result<()> {
+        ctx.accounts.process()?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn open_campaign(ctx: Context<OpenCampaign>, params: CampaignParams) -> Result<()> {
+        ctx.accounts.process(params)?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn pause_campaign(ctx: Context<SetCampaignStatus>) -> Result<()> {
+        ctx.accounts.process(CampaignStatus::Paused)?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn resume_campaign(ctx: Context<SetCampaignStatus>) -> Result<()> {
+        ctx.accounts.process(CampaignStatus::Open)?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_campaign(ctx: Context<SetCampaignStatus>) -> Result<()> {
+        ctx.accounts.process(CampaignStatus::Closed)?;
+        Ok(())
+    }
+
     #[access_control(ctx.accounts.validate())]
     pub fn set_wl_mint_authority(ctx: Context<SetWLMintAuthority>) -> Result<()> {
         ctx.accounts.process()?;
@@ -82,6 +209,31 @@ pub mod ve_honey {
         Ok(())
     }
 
+    #[access_control(ctx.accounts.validate())]
+    pub fn register_wl_offer(
+        ctx: Context<RegisterWlOffer>,
+        offer_id: u64,
+        params: WlOfferParams,
+    ) -> Result<()> {
+        ctx.accounts.process(offer_id, params)?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_wl_offer_params(
+        ctx: Context<SetWlOfferParams>,
+        params: WlOfferParams,
+    ) -> Result<()> {
+        ctx.accounts.process(params)?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn redeem_wl(ctx: Context<RedeemWl>, units: u64) -> Result<()> {
+        ctx.accounts.process(units)?;
+        Ok(())
+    }
+
     #[access_control(ctx.accounts.validate())]
     pub fn lock<'info>(
         ctx: Context<'_, '_, '_, 'info, Lock<'info>>,
@@ -89,18 +241,105 @@ pub mod ve_honey {
         duration: i64,
     ) -> Result<()> {
         if ctx.accounts.locker.params.whitelist_enabled {
-            ctx.accounts.check_whitelisted(ctx.remaining_accounts)?;
+            ctx.accounts
+                .check_whitelisted(ctx.remaining_accounts, amount, duration)?;
         }
         ctx.accounts.process(amount, duration)?;
         Ok(())
     }
 
+    #[access_control(ctx.accounts.validate())]
+    pub fn lock_for<'info>(
+        ctx: Context<'_, '_, '_, 'info, LockFor<'info>>,
+        amount: u64,
+        duration: i64,
+    ) -> Result<()> {
+        if ctx.accounts.locker.params.whitelist_enabled {
+            ctx.accounts
+                .check_whitelisted(ctx.remaining_accounts, amount, duration)?;
+        }
+        ctx.accounts
+            .process(unwrap_bump!(ctx, "escrow"), amount, duration)?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn extend_lock<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExtendLock<'info>>,
+        duration: i64,
+    ) -> Result<()> {
+        if ctx.accounts.locker.params.whitelist_enabled {
+            ctx.accounts
+                .check_whitelisted(ctx.remaining_accounts, duration)?;
+        }
+        ctx.accounts.process(duration)?;
+        Ok(())
+    }
+
     #[access_control(ctx.accounts.validate())]
     pub fn lock_nft<'info>(
         ctx: Context<'_, '_, '_, 'info, LockNft<'info>>,
         duration: i64,
+        merkle_proof: Option<MintMerkleProof>,
+        vest_tier: Option<u8>,
+    ) -> Result<()> {
+        lock_nft::handler(ctx, duration, merkle_proof, vest_tier)?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn lock_nft_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, LockNftBatch<'info>>,
+        duration: i64,
+    ) -> Result<()> {
+        lock_nft_batch::batch_handler(ctx, duration)?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn lock_nft_custodial<'info>(
+        ctx: Context<'_, '_, '_, 'info, LockNftCustodial<'info>>,
+        duration: i64,
+        vest_tier: Option<u8>,
+    ) -> Result<()> {
+        lock_nft_custodial::custodial_handler(ctx, duration, vest_tier)?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn lock_nft_to_ledger<'info>(
+        ctx: Context<'_, '_, '_, 'info, LockNftToLedger<'info>>,
+        duration: i64,
     ) -> Result<()> {
-        lock_nft::handler(ctx, duration)?;
+        lock_nft_to_ledger::ledger_handler(ctx, duration)?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn lock_cnft<'info>(
+        ctx: Context<'_, '_, '_, 'info, LockCnft<'info>>,
+        duration: i64,
+        root: [u8; 32],
+        nonce: u64,
+        index: u32,
+        metadata: CnftMetadata,
+        vest_tier: Option<u8>,
+    ) -> Result<()> {
+        ctx.accounts.process(
+            duration,
+            root,
+            nonce,
+            index,
+            metadata,
+            vest_tier,
+            ctx.remaining_accounts,
+        )?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn withdraw_nft(ctx: Context<WithdrawNft>) -> Result<()> {
+        ctx.accounts.process()?;
         Ok(())
     }
 
@@ -110,6 +349,33 @@ pub mod ve_honey {
         Ok(())
     }
 
+    #[access_control(ctx.accounts.validate())]
+    pub fn operator_claim(ctx: Context<OperatorClaim>) -> Result<()> {
+        ctx.accounts.process()?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn claim_all<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimAll<'info>>,
+        close_ended: bool,
+    ) -> Result<()> {
+        ctx.accounts.process(ctx.remaining_accounts, close_ended)?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn claim_and_relock(ctx: Context<ClaimAndRelock>, duration: Option<i64>) -> Result<()> {
+        ctx.accounts.process(duration)?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_receipt(ctx: Context<MigrateReceipt>) -> Result<()> {
+        ctx.accounts.process()?;
+        Ok(())
+    }
+
     #[access_control(ctx.accounts.validate())]
     pub fn unlock(ctx: Context<Unlock>) -> Result<()> {
         ctx.accounts.process()?;
@@ -122,12 +388,73 @@ pub mod ve_honey {
         Ok(())
     }
 
+    #[access_control(ctx.accounts.validate())]
+    pub fn init_receipt_ledger(ctx: Context<InitReceiptLedger>) -> Result<()> {
+        ctx.accounts.process()?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn claim_ledger_receipt(ctx: Context<ClaimLedgerReceipt>, index: u64) -> Result<()> {
+        ctx.accounts.process(index)?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_ledger_receipt(ctx: Context<CloseLedgerReceipt>, index: u64) -> Result<()> {
+        ctx.accounts.process(index)?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_receipt_to_ledger(ctx: Context<MigrateReceiptToLedger>) -> Result<()> {
+        ctx.accounts.process()?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_receipt_ledger(ctx: Context<CloseReceiptLedger>) -> Result<()> {
+        ctx.accounts.process()?;
+        Ok(())
+    }
+
     #[access_control(ctx.accounts.validate())]
     pub fn close_escrow(ctx: Context<CloseEscrow>) -> Result<()> {
         ctx.accounts.process()?;
         Ok(())
     }
 
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_escrow<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateEscrow<'info>>,
+        closed_receipt_ids: Vec<u64>,
+    ) -> Result<()> {
+        ctx.accounts
+            .process(&closed_receipt_ids, ctx.remaining_accounts)?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn propose_escrow_transfer(ctx: Context<ProposeEscrowTransfer>) -> Result<()> {
+        ctx.accounts.process()?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn cancel_escrow_transfer(ctx: Context<CancelEscrowTransfer>) -> Result<()> {
+        ctx.accounts.process()?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn accept_escrow_transfer<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptEscrowTransfer<'info>>,
+    ) -> Result<()> {
+        ctx.accounts
+            .process(unwrap_bump!(ctx, "new_escrow"), ctx.remaining_accounts)?;
+        Ok(())
+    }
+
     #[access_control(ctx.accounts.validate())]
     pub fn activate_proposal(ctx: Context<ActivateProposal>) -> Result<()> {
         ctx.accounts.process()?;
@@ -140,9 +467,31 @@ pub mod ve_honey {
         Ok(())
     }
 
+    #[access_control(ctx.accounts.validate())]
+    pub fn operator_cast_vote(ctx: Context<OperatorCastVote>, side: u8) -> Result<()> {
+        ctx.accounts.process(side)?;
+        Ok(())
+    }
+
     #[access_control(ctx.accounts.validate())]
     pub fn set_vote_delegate(ctx: Context<SetVoteDelegate>, new_delegate: Pubkey) -> Result<()> {
         ctx.accounts.process(new_delegate)?;
         Ok(())
     }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn approve_operator(
+        ctx: Context<ApproveOperator>,
+        actions: u8,
+        expires_at: i64,
+    ) -> Result<()> {
+        ctx.accounts.process(actions, expires_at)?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn revoke_operator(ctx: Context<RevokeOperator>) -> Result<()> {
+        ctx.accounts.process()?;
+        Ok(())
+    }
 }
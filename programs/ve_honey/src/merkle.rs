@@ -0,0 +1,174 @@
+//! Merkle allowlists of NFT mints, accepted by [Proof]s of type [ProofType::MERKLE].
+//!
+//! A leaf commits to a mint and its reward weight. Siblings are hashed in sorted order, so
+//! a proof is the list of siblings from the leaf up, and a node without a sibling moves up
+//! a level unchanged. The `merkle_distributor` trees are hashed and verified the same way.
+
+use crate::*;
+use anchor_lang::solana_program::hash::hashv;
+
+/// Proof that an NFT mint is in the allowlist of a [ProofType::MERKLE] [Proof], passed as
+/// instruction data.
+#[derive(AnchorDeserialize, AnchorSerialize, Default, Debug, Clone, PartialEq, Eq)]
+pub struct MintMerkleProof {
+    /// Reward weight of the mint, in basis points.
+    pub weight_bps: u16,
+    /// Siblings of the leaf, from the leaf up to the root.
+    pub path: Vec<[u8; 32]>,
+}
+
+/// Hash of a leaf of an allowlist.
+pub fn leaf_hash(mint: &Pubkey, weight_bps: u16) -> [u8; 32] {
+    hashv(&[mint.as_ref(), &weight_bps.to_le_bytes()]).to_bytes()
+}
+
+/// Hash of two sibling nodes.
+pub fn node_hash(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    if a <= b {
+        hashv(&[a, b]).to_bytes()
+    } else {
+        hashv(&[b, a]).to_bytes()
+    }
+}
+
+/// Returns true if `leaf` is part of the tree of `root` according to `path`.
+pub fn verify(path: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed_hash = path.iter().fold(leaf, |computed_hash, sibling| {
+        node_hash(&computed_hash, sibling)
+    });
+
+    computed_hash == *root
+}
+
+/// Tree of leaf hashes built off-chain, hashed the same way [verify] checks proofs.
+#[derive(Debug, Clone)]
+pub struct MerkleTree {
+    /// Hashes of each level of the tree, from the leaves up to the root.
+    layers: Vec<Vec<[u8; 32]>>,
+}
+
+impl MerkleTree {
+    pub fn new(leaves: Vec<[u8; 32]>) -> Self {
+        let mut layers = vec![leaves];
+
+        while layers[layers.len() - 1].len() > 1 {
+            let next = layers[layers.len() - 1]
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => node_hash(a, b),
+                    [a] => *a,
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers.push(next);
+        }
+
+        Self { layers }
+    }
+
+    /// Root of the tree; all zero for an empty tree.
+    pub fn root(&self) -> [u8; 32] {
+        self.layers[self.layers.len() - 1]
+            .first()
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Siblings of the leaf at `index`, from the leaf up to the root.
+    pub fn proof(&self, mut index: usize) -> Vec<[u8; 32]> {
+        let mut path = vec![];
+
+        for layer in &self.layers[..self.layers.len() - 1] {
+            if let Some(sibling) = layer.get(index ^ 1) {
+                path.push(*sibling);
+            }
+            index /= 2;
+        }
+
+        path
+    }
+}
+
+/// Allowlist tree built off-chain from a list of mints, giving the root to store in a
+/// [Proof] and the proof of each mint to lock it.
+#[derive(Debug, Clone)]
+pub struct MintMerkleTree {
+    leaves: Vec<(Pubkey, u16)>,
+    tree: MerkleTree,
+}
+
+impl MintMerkleTree {
+    /// Tree of `mints`, each with the full reward.
+    pub fn new(mints: &[Pubkey]) -> Self {
+        let leaves: Vec<_> = mints.iter().map(|mint| (*mint, MAX_BPS)).collect();
+        Self::with_weights(&leaves)
+    }
+
+    /// Tree of mints with their reward weight, in basis points.
+    pub fn with_weights(leaves: &[(Pubkey, u16)]) -> Self {
+        let tree = MerkleTree::new(
+            leaves
+                .iter()
+                .map(|(mint, weight_bps)| leaf_hash(mint, *weight_bps))
+                .collect(),
+        );
+
+        Self {
+            leaves: leaves.to_vec(),
+            tree,
+        }
+    }
+
+    /// Root of the tree; all zero for an empty tree.
+    pub fn root(&self) -> [u8; 32] {
+        self.tree.root()
+    }
+
+    /// Proof of `mint`, if it is in the tree.
+    pub fn proof(&self, mint: &Pubkey) -> Option<MintMerkleProof> {
+        let index = self.leaves.iter().position(|(leaf, _)| leaf == mint)?;
+
+        Some(MintMerkleProof {
+            weight_bps: self.leaves[index].1,
+            path: self.tree.proof(index),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_mint_verifies_against_the_root() {
+        for len in 1..=9 {
+            let leaves: Vec<_> = (0..len)
+                .map(|i| (Pubkey::new_unique(), 1_000 * i as u16))
+                .collect();
+            let tree = MintMerkleTree::with_weights(&leaves);
+
+            for (mint, weight_bps) in &leaves {
+                let proof = tree.proof(mint).unwrap();
+
+                assert_eq!(proof.weight_bps, *weight_bps);
+                assert!(verify(
+                    &proof.path,
+                    &tree.root(),
+                    leaf_hash(mint, proof.weight_bps)
+                ));
+                assert!(!verify(
+                    &proof.path,
+                    &tree.root(),
+                    leaf_hash(mint, proof.weight_bps + 1)
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn unknown_mint_has_no_proof() {
+        let tree = MintMerkleTree::new(&[Pubkey::new_unique(), Pubkey::new_unique()]);
+
+        assert_eq!(tree.proof(&Pubkey::new_unique()), None);
+    }
+}
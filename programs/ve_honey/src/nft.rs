@@ -0,0 +1,387 @@
+//! NFT standards accepted by [crate::ve_honey::lock_nft] and [crate::ve_honey::lock_cnft].
+//!
+//! The metadata crate in use predates programmable NFTs, Metaplex Core and Bubblegum, so
+//! the parts of their accounts and instructions needed here are handled manually.
+
+use crate::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::keccak;
+use anchor_lang::solana_program::pubkey::PUBKEY_BYTES;
+use anchor_lang::solana_program::{system_program, sysvar};
+use mpl_token_metadata::state::Data;
+
+/// Metaplex Core program.
+pub mod mpl_core {
+    use anchor_lang::declare_id;
+
+    declare_id!("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d");
+}
+
+/// Metaplex Bubblegum program.
+pub mod mpl_bubblegum {
+    use anchor_lang::declare_id;
+
+    declare_id!("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
+}
+
+/// SPL account compression program.
+pub mod spl_account_compression {
+    use anchor_lang::declare_id;
+
+    declare_id!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+}
+
+/// SPL noop program, the log wrapper of account compression.
+pub mod spl_noop {
+    use anchor_lang::declare_id;
+
+    declare_id!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+}
+
+/// `TokenStandard::ProgrammableNonFungible` of token metadata.
+const TOKEN_STANDARD_PROGRAMMABLE_NON_FUNGIBLE: u8 = 4;
+/// `TokenStandard::ProgrammableNonFungibleEdition` of token metadata.
+const TOKEN_STANDARD_PROGRAMMABLE_NON_FUNGIBLE_EDITION: u8 = 5;
+/// `MetadataInstruction::Burn` of token metadata.
+const TOKEN_METADATA_BURN_INSTRUCTION: u8 = 41;
+/// `Key::AssetV1` of Metaplex Core.
+const CORE_ASSET_V1_KEY: u8 = 1;
+/// `Key::CollectionV1` of Metaplex Core.
+const CORE_COLLECTION_V1_KEY: u8 = 5;
+/// `MplAssetInstruction::BurnV1` of Metaplex Core.
+const CORE_BURN_V1_INSTRUCTION: u8 = 12;
+/// Anchor discriminator of the Bubblegum `burn` instruction.
+const BUBBLEGUM_BURN_DISCRIMINATOR: [u8; 8] = [116, 110, 29, 56, 107, 219, 42, 93];
+/// Seed of the asset id of a compressed NFT.
+const BUBBLEGUM_ASSET_SEED: &[u8] = b"asset";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NftStandard {
+    /// Legacy token metadata NFT, burnt with `burn_nft`.
+    NonFungible,
+    /// Programmable NFT, burnt with `BurnV1` along with its token record.
+    ProgrammableNonFungible,
+    /// Metaplex Core asset.
+    Core,
+}
+
+impl NftStandard {
+    /// Reads the standard of a token metadata NFT from its metadata account.
+    pub fn from_metadata(metadata: &AccountInfo) -> Result<Self> {
+        let (token_standard, _collection) = read_metadata_extension(metadata)?;
+
+        match token_standard {
+            Some(TOKEN_STANDARD_PROGRAMMABLE_NON_FUNGIBLE)
+            | Some(TOKEN_STANDARD_PROGRAMMABLE_NON_FUNGIBLE_EDITION) => {
+                Ok(Self::ProgrammableNonFungible)
+            }
+            _ => Ok(Self::NonFungible),
+        }
+    }
+}
+
+/// Collection of a token metadata NFT.
+#[derive(AnchorDeserialize)]
+struct MetadataCollection {
+    verified: bool,
+    key: Pubkey,
+}
+
+/// Reads the verified collection of a token metadata NFT from its metadata account.
+///
+/// The metadata crate in use fails on the token standard of programmable NFTs and drops
+/// their collection along with it, so the collection is read here for every standard.
+pub fn verified_collection(metadata: &AccountInfo) -> Result<Option<Pubkey>> {
+    let (_token_standard, collection) = read_metadata_extension(metadata)?;
+
+    Ok(collection
+        .filter(|collection| collection.verified)
+        .map(|collection| collection.key))
+}
+
+/// Reads the token standard and the collection that follow the data of a token metadata
+/// account.
+fn read_metadata_extension(
+    metadata: &AccountInfo,
+) -> Result<(Option<u8>, Option<MetadataCollection>)> {
+    let data = metadata.try_borrow_data()?;
+    let buf = &mut unwrap_opt!(
+        data.get(1 + PUBKEY_BYTES * 2..),
+        ProtocolError::MetadataMismatch
+    );
+
+    <Data as AnchorDeserialize>::deserialize(buf)
+        .and_then(|_| <(bool, bool, Option<u8>) as AnchorDeserialize>::deserialize(buf))
+        .map_err(|_| error!(ProtocolError::MetadataMismatch))?;
+    // metadata created before token standards ends here
+    let token_standard = match <Option<u8> as AnchorDeserialize>::deserialize(buf) {
+        Ok(token_standard) => token_standard,
+        Err(_) => return Ok((None, None)),
+    };
+    let collection = <Option<MetadataCollection> as AnchorDeserialize>::deserialize(buf)
+        .ok()
+        .flatten();
+
+    Ok((token_standard, collection))
+}
+
+/// Whether the account is a collection NFT mint or a Metaplex Core collection.
+pub fn is_collection(info: &AccountInfo) -> bool {
+    if *info.owner == anchor_spl::token::ID {
+        let data = match info.try_borrow_data() {
+            Ok(data) => data,
+            Err(_) => return false,
+        };
+        return matches!(
+            anchor_spl::token::Mint::try_deserialize(&mut &data[..]),
+            Ok(mint) if mint.supply == 1 && mint.decimals == 0
+        );
+    }
+
+    *info.owner == mpl_core::ID
+        && matches!(info.try_borrow_data(), Ok(data) if data.first() == Some(&CORE_COLLECTION_V1_KEY))
+}
+
+/// Update authority of a Metaplex Core asset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoreUpdateAuthority {
+    None,
+    Address(Pubkey),
+    Collection(Pubkey),
+}
+
+/// The fields of a Metaplex Core asset needed to lock it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CoreAsset {
+    pub owner: Pubkey,
+    pub update_authority: CoreUpdateAuthority,
+}
+
+impl CoreAsset {
+    pub fn from_account_info(asset: &AccountInfo) -> Result<Self> {
+        assert_keys_eq!(*asset.owner, mpl_core::ID, ProtocolError::InvalidProgramId);
+
+        let data = asset.try_borrow_data()?;
+        invariant!(
+            data.len() >= 2 + PUBKEY_BYTES && data[0] == CORE_ASSET_V1_KEY,
+            ProtocolError::MetadataMismatch
+        );
+
+        let owner = Pubkey::new(&data[1..1 + PUBKEY_BYTES]);
+        let authority = &data[2 + PUBKEY_BYTES..];
+        let update_authority = match data[1 + PUBKEY_BYTES] {
+            0 => CoreUpdateAuthority::None,
+            1 if authority.len() >= PUBKEY_BYTES => {
+                CoreUpdateAuthority::Address(Pubkey::new(&authority[..PUBKEY_BYTES]))
+            }
+            2 if authority.len() >= PUBKEY_BYTES => {
+                CoreUpdateAuthority::Collection(Pubkey::new(&authority[..PUBKEY_BYTES]))
+            }
+            _ => return Err(error!(ProtocolError::MetadataMismatch)),
+        };
+
+        Ok(Self {
+            owner,
+            update_authority,
+        })
+    }
+
+    pub fn collection(&self) -> Option<Pubkey> {
+        match self.update_authority {
+            CoreUpdateAuthority::Collection(collection) => Some(collection),
+            _ => None,
+        }
+    }
+}
+
+/// Token metadata `BurnV1` of a programmable NFT.
+///
+/// Burning skips the rule set of the NFT, so no authorization rules accounts are needed.
+#[allow(clippy::too_many_arguments)]
+pub fn burn_programmable_nft(
+    authority: Pubkey,
+    collection_metadata: Option<Pubkey>,
+    metadata: Pubkey,
+    edition: Pubkey,
+    mint: Pubkey,
+    token: Pubkey,
+    token_record: Pubkey,
+    spl_token_program: Pubkey,
+) -> Instruction {
+    let program_id = mpl_token_metadata::ID;
+    let optional = |key: Option<Pubkey>| match key {
+        Some(key) => AccountMeta::new(key, false),
+        None => AccountMeta::new_readonly(program_id, false),
+    };
+
+    let mut data = vec![TOKEN_METADATA_BURN_INSTRUCTION, 0];
+    data.extend_from_slice(&1u64.to_le_bytes());
+
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(authority, true),
+            optional(collection_metadata),
+            AccountMeta::new(metadata, false),
+            AccountMeta::new(edition, false),
+            AccountMeta::new(mint, false),
+            AccountMeta::new(token, false),
+            optional(None),
+            optional(None),
+            optional(None),
+            optional(None),
+            AccountMeta::new(token_record, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(sysvar::instructions::ID, false),
+            AccountMeta::new_readonly(spl_token_program, false),
+        ],
+        data,
+    }
+}
+
+/// Metaplex Core `BurnV1` of an asset.
+pub fn burn_core_asset(
+    asset: Pubkey,
+    collection: Option<Pubkey>,
+    payer: Pubkey,
+    authority: Pubkey,
+) -> Instruction {
+    let program_id = mpl_core::ID;
+
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(asset, false),
+            match collection {
+                Some(collection) => AccountMeta::new(collection, false),
+                None => AccountMeta::new_readonly(program_id, false),
+            },
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(program_id, false),
+        ],
+        data: vec![CORE_BURN_V1_INSTRUCTION, 0],
+    }
+}
+
+/// Creator of a compressed NFT.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CnftCreator {
+    pub address: Pubkey,
+    pub verified: bool,
+    pub share: u8,
+}
+
+/// Collection of a compressed NFT.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CnftCollection {
+    pub verified: bool,
+    pub key: Pubkey,
+}
+
+/// Uses of a compressed NFT.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CnftUses {
+    pub use_method: u8,
+    pub remaining: u64,
+    pub total: u64,
+}
+
+/// Leaf data of a compressed NFT, serialized the same as Bubblegum `MetadataArgs`.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CnftMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub seller_fee_basis_points: u16,
+    pub primary_sale_happened: bool,
+    pub is_mutable: bool,
+    pub edition_nonce: Option<u8>,
+    pub token_standard: Option<u8>,
+    pub collection: Option<CnftCollection>,
+    pub uses: Option<CnftUses>,
+    pub token_program_version: u8,
+    pub creators: Vec<CnftCreator>,
+}
+
+impl CnftMetadata {
+    /// Data hash of the leaf.
+    pub fn data_hash(&self) -> Result<[u8; 32]> {
+        let metadata_hash = keccak::hash(&self.try_to_vec()?);
+
+        Ok(keccak::hashv(&[
+            metadata_hash.as_ref(),
+            &self.seller_fee_basis_points.to_le_bytes(),
+        ])
+        .to_bytes())
+    }
+
+    /// Creator hash of the leaf.
+    pub fn creator_hash(&self) -> [u8; 32] {
+        let creators: Vec<Vec<u8>> = self
+            .creators
+            .iter()
+            .map(|c| [c.address.as_ref(), &[c.verified as u8], &[c.share]].concat())
+            .collect();
+        let creators: Vec<&[u8]> = creators.iter().map(Vec::as_slice).collect();
+
+        keccak::hashv(&creators).to_bytes()
+    }
+}
+
+/// Asset id of the compressed NFT minted into `merkle_tree` with `nonce`.
+pub fn get_asset_id(merkle_tree: &Pubkey, nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            BUBBLEGUM_ASSET_SEED,
+            merkle_tree.as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        &mpl_bubblegum::ID,
+    )
+    .0
+}
+
+/// Bubblegum `burn` of a compressed NFT, signed by its owner.
+#[allow(clippy::too_many_arguments)]
+pub fn burn_compressed_nft(
+    tree_authority: Pubkey,
+    leaf_owner: Pubkey,
+    leaf_delegate: Pubkey,
+    merkle_tree: Pubkey,
+    proof: &[Pubkey],
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(tree_authority, false),
+        AccountMeta::new_readonly(leaf_owner, true),
+        AccountMeta::new_readonly(leaf_delegate, false),
+        AccountMeta::new(merkle_tree, false),
+        AccountMeta::new_readonly(spl_noop::ID, false),
+        AccountMeta::new_readonly(spl_account_compression::ID, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+    accounts.extend(
+        proof
+            .iter()
+            .map(|node| AccountMeta::new_readonly(*node, false)),
+    );
+
+    let mut data = BUBBLEGUM_BURN_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&root);
+    data.extend_from_slice(&data_hash);
+    data.extend_from_slice(&creator_hash);
+    data.extend_from_slice(&nonce.to_le_bytes());
+    data.extend_from_slice(&index.to_le_bytes());
+
+    Instruction {
+        program_id: mpl_bubblegum::ID,
+        accounts,
+        data,
+    }
+}
@@ -1,11 +1,25 @@
+pub mod campaign;
 pub mod escrow;
+pub mod escrow_transfer;
 pub mod locker;
 pub mod nft_receipt;
+pub mod operator_approval;
 pub mod proof;
+pub mod receipt_ledger;
+pub mod reward_schedule;
+pub mod vest_tiers;
 pub mod whitelist_entry;
+pub mod wl_offer;
 
+pub use campaign::*;
 pub use escrow::*;
+pub use escrow_transfer::*;
 pub use locker::*;
 pub use nft_receipt::*;
+pub use operator_approval::*;
 pub use proof::*;
+pub use receipt_ledger::*;
+pub use reward_schedule::*;
+pub use vest_tiers::*;
 pub use whitelist_entry::*;
+pub use wl_offer::*;
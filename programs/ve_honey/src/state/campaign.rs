@@ -0,0 +1,115 @@
+use crate::*;
+use anchor_lang::solana_program::pubkey::PUBKEY_BYTES;
+
+/// Campaign bounding the NFTs locked into a [Locker], set by governance. While the campaign
+/// of a [Locker] is open, every NFT lock, through [ve_honey::lock_nft],
+/// [ve_honey::lock_nft_batch], [ve_honey::lock_nft_custodial], [ve_honey::lock_nft_to_ledger]
+/// or [ve_honey::lock_cnft], must fall within its window, budget and caps.
+///
+/// Without a campaign, or once it is closed, NFT locks are bounded only by the [Proof]s and
+/// the treasury balance, as before campaigns existed. This is intended: governance keeps a
+/// campaign open for as long as it wants the locks bounded.
+#[account]
+#[derive(Debug)]
+pub struct Campaign {
+    /// [Locker] that this campaign belongs to.
+    pub locker: Pubkey,
+    /// Number of times a campaign was opened on the [Locker]; scopes its [BurnCounter]s.
+    pub campaign_id: u64,
+    /// Status of the campaign.
+    pub status: CampaignStatus,
+    /// Terms of the campaign.
+    pub params: CampaignParams,
+    /// Sum of the max rewards of the NFTs locked in the campaign.
+    pub reward_spent: u64,
+    /// Number of NFTs locked in the campaign.
+    pub burn_count: u64,
+}
+
+impl Campaign {
+    pub const LEN: usize = PUBKEY_BYTES + 8 + 1 + CampaignParams::LEN + 8 + 8;
+
+    /// Checks that an NFT paying `reward_amount` can be locked in the campaign at
+    /// `timestamp`, and counts it.
+    pub fn record_burn(&mut self, reward_amount: u64, timestamp: i64) -> Result<()> {
+        invariant!(
+            self.status == CampaignStatus::Open,
+            ProtocolError::CampaignNotOpen
+        );
+        invariant!(
+            self.params.starts_at <= timestamp && timestamp < self.params.ends_at,
+            ProtocolError::CampaignNotActive
+        );
+
+        let reward_spent = unwrap_int!(self.reward_spent.checked_add(reward_amount));
+        invariant!(
+            reward_spent <= self.params.reward_budget,
+            ProtocolError::CampaignBudgetExceeded
+        );
+
+        self.reward_spent = reward_spent;
+        self.burn_count = unwrap_int!(self.burn_count.checked_add(1));
+
+        Ok(())
+    }
+}
+
+/// Status of a [Campaign].
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CampaignStatus {
+    /// NFTs can be locked within the window of the campaign.
+    Open,
+    /// NFTs cannot be locked until the campaign is resumed.
+    Paused,
+    /// NFTs are locked without campaign bounds until a new campaign is opened.
+    Closed,
+}
+
+/// Terms of a [Campaign].
+#[derive(AnchorDeserialize, AnchorSerialize, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CampaignParams {
+    /// When NFTs start being accepted.
+    pub starts_at: i64,
+    /// When NFTs stop being accepted.
+    pub ends_at: i64,
+    /// Maximum sum of the max rewards of the NFTs locked in the campaign.
+    pub reward_budget: u64,
+    /// Maximum number of NFTs of a collection locked in the campaign.
+    pub collection_burn_cap: u64,
+    /// Maximum number of NFTs locked into an [Escrow] in the campaign.
+    pub escrow_burn_cap: Option<u64>,
+}
+
+impl CampaignParams {
+    pub const LEN: usize = 8 + 8 + 8 + 8 + 1 + 8;
+
+    pub fn validate(&self) -> Result<()> {
+        invariant!(
+            self.starts_at < self.ends_at
+                && self.reward_budget > 0
+                && self.collection_burn_cap > 0
+                && self.escrow_burn_cap != Some(0),
+            ProtocolError::InvalidCampaignParams
+        );
+
+        Ok(())
+    }
+}
+
+/// Number of NFTs of a collection, or locked into an [Escrow], in a [Campaign].
+#[account]
+#[derive(Debug, Default)]
+pub struct BurnCounter {
+    /// The [Campaign].
+    pub campaign: Pubkey,
+    /// Id of the campaign when the counter was created.
+    pub campaign_id: u64,
+    /// Collection or [Escrow] counted.
+    pub key: Pubkey,
+    /// Number of NFTs locked.
+    pub burn_count: u64,
+}
+
+impl BurnCounter {
+    pub const LEN: usize = PUBKEY_BYTES + 8 + PUBKEY_BYTES + 8;
+}
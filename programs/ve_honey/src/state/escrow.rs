@@ -28,11 +28,41 @@ pub struct Escrow {
     /// Account that is authorized to vote on behalf of this [Escrow].
     /// Defaults to the [Escrow::owner].
     pub vote_delegate: Pubkey,
+
+    /// Amount of the reward mint of the [Locker] held for receipts burning NFTs.
+    pub reward_amount_to_receipt: u64,
+    /// Count of receipts closed, as [NftReceipt] accounts or [ReceiptLedger] records.
+    pub closed_receipt_count: u64,
 }
 
 impl Escrow {
     pub const LEN: usize =
-        PUBKEY_BYTES + PUBKEY_BYTES + 1 + PUBKEY_BYTES + 8 + 8 + 8 + 8 + 8 + PUBKEY_BYTES;
+        PUBKEY_BYTES + PUBKEY_BYTES + 1 + PUBKEY_BYTES + 8 + 8 + 8 + 8 + 8 + PUBKEY_BYTES + 8 + 8;
+
+    /// Initializes a new [Escrow] of `owner` inside of the [Locker].
+    pub fn init(
+        &mut self,
+        key: &Pubkey,
+        locker: &Pubkey,
+        token_mint: &Pubkey,
+        owner: Pubkey,
+        bump: u8,
+    ) {
+        self.locker = *locker;
+        self.owner = owner;
+        self.bump = bump;
+
+        self.tokens = anchor_spl::associated_token::get_associated_token_address(key, token_mint);
+        self.amount = 0;
+        self.escrow_started_at = 0;
+        self.escrow_ends_at = 0;
+        self.receipt_count = 0;
+        self.amount_to_receipt = 0;
+
+        self.vote_delegate = owner;
+        self.reward_amount_to_receipt = 0;
+        self.closed_receipt_count = 0;
+    }
 
     pub fn update_lock_event(
         &mut self,
@@ -40,14 +70,14 @@ impl Escrow {
         lock_amount: u64,
         next_escrow_started_at: i64,
         next_escrow_ends_at: i64,
-        receipt: bool,
+        receipt_count: u64,
     ) -> Result<()> {
         self.amount = unwrap_int!(self.amount.checked_add(lock_amount));
         self.escrow_started_at = next_escrow_started_at;
         self.escrow_ends_at = next_escrow_ends_at;
 
-        if receipt {
-            self.receipt_count += 1;
+        if receipt_count > 0 {
+            self.receipt_count = unwrap_int!(self.receipt_count.checked_add(receipt_count));
             self.amount_to_receipt = unwrap_int!(self.amount_to_receipt.checked_add(lock_amount));
         }
 
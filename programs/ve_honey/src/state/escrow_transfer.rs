@@ -0,0 +1,16 @@
+use crate::*;
+use anchor_lang::solana_program::pubkey::PUBKEY_BYTES;
+
+/// Transfer of an [Escrow] proposed by its owner, pending the acceptance of the new owner.
+#[account]
+#[derive(Debug, Default)]
+pub struct EscrowTransfer {
+    /// The [Escrow] being transferred.
+    pub escrow: Pubkey,
+    /// The owner the [Escrow] is transferred to.
+    pub new_owner: Pubkey,
+}
+
+impl EscrowTransfer {
+    pub const LEN: usize = PUBKEY_BYTES + PUBKEY_BYTES;
+}
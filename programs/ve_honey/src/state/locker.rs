@@ -21,11 +21,35 @@ pub struct Locker {
 
     /// Locker params.
     pub params: LockerParams,
+
+    /// Treasury tokens reserved for the rewards still to be paid by the [Campaign] of the
+    /// [Locker]; withdrawals from the treasury cannot dip below it.
+    pub reserved_rewards: u64,
+    /// Account allowed to withdraw from the treasury besides the smart wallet.
+    pub treasury_authority: Option<Pubkey>,
+    /// Mint of the NFT rewards when they are vested to the owners of the receipts; unset,
+    /// the rewards are locked into the [Escrow] in [Locker::token_mint].
+    pub reward_mint: Option<Pubkey>,
 }
 
 impl Locker {
-    pub const LEN: usize =
-        PUBKEY_BYTES + 1 + PUBKEY_BYTES + 8 + PUBKEY_BYTES + PUBKEY_BYTES + LockerParams::LEN;
+    pub const LEN: usize = PUBKEY_BYTES
+        + 1
+        + PUBKEY_BYTES
+        + 8
+        + PUBKEY_BYTES
+        + PUBKEY_BYTES
+        + LockerParams::LEN
+        + 8
+        + 1
+        + PUBKEY_BYTES
+        + 1
+        + PUBKEY_BYTES;
+
+    /// Mint of the treasury paying the NFT rewards.
+    pub fn reward_treasury_mint(&self) -> Pubkey {
+        self.reward_mint.unwrap_or(self.token_mint)
+    }
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize, Default, Debug, Clone, Copy, PartialEq, Eq)]
@@ -48,10 +72,21 @@ pub struct LockerParams {
     pub nft_stake_duration_count: u8,
     /// First halving count.
     pub nft_reward_halving_starts_at: u8,
+    /// Rate of the NFT reward paid to custodial locks, in basis points.
+    pub nft_custodial_reward_bps: u16,
 }
 
 impl LockerParams {
-    pub const LEN: usize = 8 + 8 + 1 + 1 + 8 + 8 + 8 + 1 + 1;
+    pub const LEN: usize = 8 + 8 + 1 + 1 + 8 + 8 + 8 + 1 + 1 + 2;
+
+    pub fn validate(&self) -> Result<()> {
+        invariant!(
+            self.nft_custodial_reward_bps <= MAX_BPS,
+            ProtocolError::InvalidLockerParams
+        );
+
+        Ok(())
+    }
 
     pub fn calculate_voter_power(&self, escrow: &Escrow, now: i64) -> Option<u64> {
         if now == 0 {
@@ -82,7 +117,85 @@ impl LockerParams {
         Some(power)
     }
 
+    /// Default [NftRewardParams] of the NFTs locked in the [Locker].
+    pub fn nft_reward_params(&self) -> NftRewardParams {
+        NftRewardParams {
+            nft_stake_duration_unit: self.nft_stake_duration_unit,
+            nft_stake_base_reward: self.nft_stake_base_reward,
+            nft_stake_duration_count: self.nft_stake_duration_count,
+            nft_reward_halving_starts_at: self.nft_reward_halving_starts_at,
+            wl_token_amount: DEFAULT_WL_TOKEN_AMOUNT,
+        }
+    }
+}
+
+/// Reward terms of a locked NFT.
+#[derive(AnchorDeserialize, AnchorSerialize, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NftRewardParams {
+    /// NFT stake duration unit.
+    pub nft_stake_duration_unit: i64,
+    /// NFT stake base reward
+    pub nft_stake_base_reward: u64,
+    /// NFT stake duration count.
+    pub nft_stake_duration_count: u8,
+    /// First halving count.
+    pub nft_reward_halving_starts_at: u8,
+    /// Number of WL tokens minted against burning the NFT.
+    pub wl_token_amount: u64,
+}
+
+impl NftRewardParams {
+    pub const LEN: usize = 8 + 8 + 1 + 1 + 8;
+
+    pub fn validate(&self) -> Result<()> {
+        invariant!(
+            self.nft_stake_duration_unit > 0 && self.nft_stake_duration_count > 0,
+            ProtocolError::InvalidNftRewardParams
+        );
+
+        Ok(())
+    }
+
+    /// Reward of `duration`, paying the base reward for each elapsed unit until the
+    /// halving starts and halving it on every unit after.
+    ///
+    /// The halved units pay `base >> 1, base >> 2, ..., base >> m`, which sum up to
+    /// `q * (2^m - 1) + r - popcount(r)` for `base = q * 2^m + r`, so the reward is
+    /// computed without walking the units.
     pub fn calculate_reward_amount(&self, duration: i64) -> Option<u64> {
+        if duration <= 0 || self.nft_stake_duration_unit <= 0 {
+            return None;
+        }
+
+        let units = (duration / self.nft_stake_duration_unit) as u64;
+        let base = self.nft_stake_base_reward as u128;
+
+        let full_units = units.min(self.nft_reward_halving_starts_at as u64);
+        let halved_units = (units - full_units).min(u64::BITS as u64) as u32;
+
+        let q = base >> halved_units;
+        let r = base - (q << halved_units);
+        let halved_amount = q * ((1u128 << halved_units) - 1) + r - r.count_ones() as u128;
+
+        (base * full_units as u128 + halved_amount).to_u64()
+    }
+
+    pub fn calculate_nft_max_stake_duration(&self) -> Option<i64> {
+        self.nft_stake_duration_unit
+            .checked_mul(self.nft_stake_duration_count as i64)
+    }
+
+    pub fn calculate_max_reward_amount(&self) -> Option<u64> {
+        self.calculate_reward_amount(self.calculate_nft_max_stake_duration()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reward walking the elapsed units one by one, as it was computed before.
+    fn calculate_reward_amount_by_units(params: &NftRewardParams, duration: i64) -> Option<u64> {
         if duration <= 0 {
             return None;
         }
@@ -90,10 +203,11 @@ impl LockerParams {
         let mut duration: u64 = duration as u64;
         let mut reward_amount: u64 = 0;
         let mut count: u8 = 0;
-        let mut amount_per_unit = self.nft_stake_base_reward;
+        let mut amount_per_unit = params.nft_stake_base_reward;
 
-        while let Some(next_duration) = duration.checked_sub(self.nft_stake_duration_unit as u64) {
-            if count >= self.nft_reward_halving_starts_at {
+        while let Some(next_duration) = duration.checked_sub(params.nft_stake_duration_unit as u64)
+        {
+            if count >= params.nft_reward_halving_starts_at {
                 amount_per_unit /= 2;
             }
             reward_amount = reward_amount.checked_add(amount_per_unit)?;
@@ -104,12 +218,61 @@ impl LockerParams {
         Some(reward_amount)
     }
 
-    pub fn calculate_nft_max_stake_duration(&self) -> Option<i64> {
-        self.nft_stake_duration_unit
-            .checked_mul(self.nft_stake_duration_count as i64)
+    #[test]
+    fn reward_amount_matches_unit_walk() {
+        let base_rewards = [
+            0,
+            1,
+            2,
+            3,
+            7,
+            1_000,
+            3_750_000_000,
+            u64::MAX / 255,
+            u64::MAX / 3,
+            u64::MAX,
+        ];
+        let halving_starts = [0, 1, 2, 5, 63, 64, 65, 200, 255];
+
+        for unit in [1, 2, 7] {
+            for base_reward in base_rewards {
+                for halving_starts_at in halving_starts {
+                    let params = NftRewardParams {
+                        nft_stake_duration_unit: unit,
+                        nft_stake_base_reward: base_reward,
+                        nft_stake_duration_count: u8::MAX,
+                        nft_reward_halving_starts_at: halving_starts_at,
+                        wl_token_amount: DEFAULT_WL_TOKEN_AMOUNT,
+                    };
+                    let max_duration = params.calculate_nft_max_stake_duration().unwrap();
+
+                    for duration in -1..=max_duration + unit - 1 {
+                        assert_eq!(
+                            params.calculate_reward_amount(duration),
+                            calculate_reward_amount_by_units(&params, duration),
+                            "unit {} base {} halving {} duration {}",
+                            unit,
+                            base_reward,
+                            halving_starts_at,
+                            duration
+                        );
+                    }
+                }
+            }
+        }
     }
 
-    pub fn calculate_max_reward_amount(&self) -> Option<u64> {
-        self.calculate_reward_amount(self.calculate_nft_max_stake_duration()?)
+    #[test]
+    fn reward_amount_of_zero_unit_is_none() {
+        let params = NftRewardParams {
+            nft_stake_duration_unit: 0,
+            nft_stake_base_reward: 1_000,
+            nft_stake_duration_count: 10,
+            nft_reward_halving_starts_at: 2,
+            wl_token_amount: DEFAULT_WL_TOKEN_AMOUNT,
+        };
+
+        assert_eq!(params.calculate_reward_amount(100), None);
+        assert_eq!(params.calculate_max_reward_amount(), None);
     }
 }
@@ -1,7 +1,9 @@
 use crate::*;
 use anchor_lang::solana_program::pubkey::PUBKEY_BYTES;
+use num_traits::ToPrimitive;
 
 #[account]
+#[derive(Default)]
 pub struct NftReceipt {
     // receipt id
     pub receipt_id: u64,
@@ -15,10 +17,52 @@ pub struct NftReceipt {
     pub vest_ends_at: i64,
     // claimed amount
     pub claimed_amount: u64,
+    // mint of the locked NFT.
+    pub nft_mint: Pubkey,
+    // whether the NFT is held in the vault of the [Locker] instead of being burnt.
+    pub custodial: bool,
+    // rate of the reward paid to this receipt, in basis points.
+    pub reward_bps: u16,
+    // whether the custodial NFT has been withdrawn.
+    pub withdrawn: bool,
+    // reward terms the receipt was created with.
+    pub reward_params: NftRewardParams,
+    // reward schedule the receipt was created with, replacing the halving of its reward terms.
+    pub reward_schedule: Option<NftRewardSchedule>,
+    // [Proof] that accepted the NFT.
+    pub proof: Pubkey,
+    // type of the [Proof] that matched the NFT.
+    pub proof_type: u8,
+    // verified collection of the NFT.
+    pub collection: Option<Pubkey>,
+    // mint of the vested reward; unset when the reward is locked into the [Escrow].
+    pub reward_mint: Option<Pubkey>,
+    // vest tier the receipt was created with, vesting its reward over the tier duration.
+    pub vest_tier: Option<VestTier>,
 }
 
 impl NftReceipt {
-    pub const LEN: usize = 8 + PUBKEY_BYTES + PUBKEY_BYTES + 8 + 8 + 8;
+    pub const LEN: usize = 8
+        + PUBKEY_BYTES
+        + PUBKEY_BYTES
+        + 8
+        + 8
+        + 8
+        + PUBKEY_BYTES
+        + 1
+        + 2
+        + 1
+        + NftRewardParams::LEN
+        + 1
+        + NftRewardSchedule::LEN
+        + PUBKEY_BYTES
+        + 1
+        + 1
+        + PUBKEY_BYTES
+        + 1
+        + PUBKEY_BYTES
+        + 1
+        + VestTier::LEN;
 
     pub fn update_receipt(
         &mut self,
@@ -26,29 +70,79 @@ impl NftReceipt {
         escrow: &mut Escrow,
         claim_amount: u64,
     ) -> Result<()> {
-        locker.locked_supply = unwrap_int!(locker.locked_supply.checked_sub(claim_amount));
-        escrow.amount = unwrap_int!(escrow.amount.checked_sub(claim_amount));
-        escrow.amount_to_receipt = unwrap_int!(escrow.amount_to_receipt.checked_sub(claim_amount));
+        if self.reward_mint.is_some() {
+            escrow.reward_amount_to_receipt =
+                unwrap_int!(escrow.reward_amount_to_receipt.checked_sub(claim_amount));
+        } else {
+            locker.locked_supply = unwrap_int!(locker.locked_supply.checked_sub(claim_amount));
+            escrow.amount = unwrap_int!(escrow.amount.checked_sub(claim_amount));
+            escrow.amount_to_receipt =
+                unwrap_int!(escrow.amount_to_receipt.checked_sub(claim_amount));
+        }
 
         self.claimed_amount = unwrap_int!(self.claimed_amount.checked_add(claim_amount));
 
         Ok(())
     }
 
-    pub fn calculate_reward_amount_at_time(
-        &self,
-        locker: &LockerParams,
-        timestamp: i64,
-    ) -> Option<u64> {
+    /// Whether the receipt is fully claimed after its vest end, with its NFT withdrawn if
+    /// it was held in custody.
+    pub fn is_closable(&self, timestamp: i64) -> bool {
+        self.vest_ends_at < timestamp
+            && self.calculate_max_reward_amount() == Some(self.claimed_amount)
+            && (!self.custodial || self.withdrawn)
+    }
+
+    pub fn calculate_reward_amount_at_time(&self, timestamp: i64) -> Option<u64> {
         let due = timestamp.min(self.vest_ends_at);
-        let duration = due.checked_sub(self.vest_started_at)?;
+        let duration = self.scale_vest_duration(due.checked_sub(self.vest_started_at)?)?;
 
-        locker
-            .calculate_reward_amount(duration)?
+        self.apply_reward_rate(self.calculate_reward_amount(duration)?)?
             .checked_sub(self.claimed_amount)
     }
 
-    pub fn calculate_remaining_reward_amount(&self, locker: &LockerParams) -> Option<u64> {
-        self.calculate_reward_amount_at_time(locker, self.vest_ends_at)
+    pub fn calculate_max_reward_amount(&self) -> Option<u64> {
+        self.apply_reward_rate(
+            self.calculate_reward_amount(self.calculate_nft_max_stake_duration()?)?,
+        )
+    }
+
+    pub fn calculate_nft_max_stake_duration(&self) -> Option<i64> {
+        match &self.reward_schedule {
+            Some(schedule) => schedule
+                .calculate_nft_max_stake_duration(self.reward_params.nft_stake_duration_unit),
+            None => self.reward_params.calculate_nft_max_stake_duration(),
+        }
+    }
+
+    fn calculate_reward_amount(&self, duration: i64) -> Option<u64> {
+        match &self.reward_schedule {
+            Some(schedule) => schedule
+                .calculate_reward_amount(self.reward_params.nft_stake_duration_unit, duration),
+            None => self.reward_params.calculate_reward_amount(duration),
+        }
+    }
+
+    /// Maps `elapsed` vest time onto the reward terms, which the vest tier of the receipt
+    /// spreads over its own duration.
+    fn scale_vest_duration(&self, elapsed: i64) -> Option<i64> {
+        match &self.vest_tier {
+            Some(vest_tier) => (elapsed as i128)
+                .checked_mul(self.calculate_nft_max_stake_duration()?.into())?
+                .checked_div(vest_tier.vest_duration.into())?
+                .to_i64(),
+            None => Some(elapsed),
+        }
+    }
+
+    fn apply_reward_rate(&self, amount: u64) -> Option<u64> {
+        (amount as u128)
+            .checked_mul(self.reward_bps.into())?
+            .checked_div(MAX_BPS.into())?
+            .to_u64()
+    }
+
+    pub fn calculate_remaining_reward_amount(&self) -> Option<u64> {
+        self.calculate_reward_amount_at_time(self.vest_ends_at)
     }
 }
@@ -0,0 +1,50 @@
+use crate::*;
+use anchor_lang::solana_program::pubkey::PUBKEY_BYTES;
+
+/// Approval of an operator to manage an [Escrow] on behalf of its owner.
+#[account]
+#[derive(Debug, Default)]
+pub struct OperatorApproval {
+    /// The [Escrow] that the operator manages.
+    pub escrow: Pubkey,
+    /// The approved operator.
+    pub operator: Pubkey,
+    /// [OperatorActions] the operator is approved for.
+    pub actions: u8,
+    /// Timestamp after which the approval no longer applies; never if zero.
+    pub expires_at: i64,
+}
+
+impl OperatorApproval {
+    pub const LEN: usize = PUBKEY_BYTES + PUBKEY_BYTES + 1 + 8;
+
+    pub fn read_actions(actions: u8) -> Result<OperatorActions> {
+        OperatorActions::from_bits(actions)
+            .ok_or_else(|| error!(ProtocolError::InvalidOperatorActions))
+    }
+
+    /// Checks that the approval allows `action` at `now`.
+    pub fn allows(&self, action: OperatorActions, now: i64) -> Result<()> {
+        if self.expires_at != 0 {
+            invariant!(
+                now < self.expires_at,
+                ProtocolError::OperatorApprovalExpired
+            );
+        }
+        invariant!(
+            OperatorApproval::read_actions(self.actions)?.contains(action),
+            ProtocolError::OperatorNotApproved
+        );
+
+        Ok(())
+    }
+}
+
+bitflags::bitflags! {
+   pub struct OperatorActions: u8 {
+       const EXTEND = 1 << 0;
+       const RELOCK = 1 << 1;
+       const CLAIM = 1 << 2;
+       const VOTE = 1 << 3;
+   }
+}
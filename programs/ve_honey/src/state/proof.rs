@@ -9,10 +9,12 @@ pub struct Proof {
     pub proof_type: u8,
     /// Whitelisted address.
     pub proof_address: Pubkey,
+    /// Reward terms of the NFTs locked by this proof, overriding the [LockerParams].
+    pub reward_params: Option<NftRewardParams>,
 }
 
 impl Proof {
-    pub const LEN: usize = PUBKEY_BYTES + 1 + PUBKEY_BYTES;
+    pub const LEN: usize = PUBKEY_BYTES + 1 + PUBKEY_BYTES + 1 + NftRewardParams::LEN;
 
     pub fn read_type(proof_type: u8) -> Result<ProofType> {
         ProofType::from_bits(proof_type).ok_or_else(|| error!(ProtocolError::InvariantViolated))
@@ -35,5 +37,7 @@ bitflags::bitflags! {
    pub struct ProofType: u8 {
        const CREATOR = 1 << 0;
        const MINT = 1 << 1;
+       const COLLECTION = 1 << 2;
+       const MERKLE = 1 << 3;
    }
 }
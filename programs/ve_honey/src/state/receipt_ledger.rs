@@ -0,0 +1,207 @@
+use crate::*;
+use anchor_lang::__private::bytemuck::{self, Pod, Zeroable};
+use anchor_lang::solana_program::pubkey::PUBKEY_BYTES;
+use std::mem::size_of;
+
+/// Ledger of the [NftReceipt]s of an [Escrow], stored as fixed-size records after this
+/// header instead of one account per receipt. The account grows by one record per lock.
+///
+/// Instructions address records by index, which is not stable: closing a record moves the
+/// last record into its place. Clients find the index of a record by its `receipt_id`
+/// right before each call.
+#[account(zero_copy)]
+#[derive(Debug, Default)]
+pub struct ReceiptLedger {
+    /// The [Escrow] that this ledger belongs to.
+    pub escrow: Pubkey,
+    /// The [Locker] of the [Escrow].
+    pub locker: Pubkey,
+    /// The owner of the [Escrow].
+    pub owner: Pubkey,
+    /// Number of records in the ledger.
+    pub receipt_count: u64,
+}
+
+impl ReceiptLedger {
+    pub const LEN: usize = size_of::<ReceiptLedger>();
+
+    /// Account space of a ledger holding `receipt_count` records.
+    pub fn space(receipt_count: u64) -> Option<usize> {
+        (receipt_count as usize)
+            .checked_mul(LedgerReceipt::LEN)?
+            .checked_add(8 + Self::LEN)
+    }
+
+    /// Splits the account data of a ledger into its header and records.
+    pub fn split_mut(data: &mut [u8]) -> Result<(&mut ReceiptLedger, &mut [LedgerReceipt])> {
+        invariant!(data.len() >= 8 + Self::LEN, ProtocolError::InvalidLedger);
+
+        let (header, records) = data[8..].split_at_mut(Self::LEN);
+        let ledger: &mut ReceiptLedger = bytemuck::from_bytes_mut(header);
+
+        let records_len =
+            unwrap_int!((ledger.receipt_count as usize).checked_mul(LedgerReceipt::LEN));
+        invariant!(records.len() == records_len, ProtocolError::InvalidLedger);
+
+        Ok((ledger, bytemuck::cast_slice_mut(records)))
+    }
+}
+
+/// An [NftReceipt] stored in a [ReceiptLedger].
+#[zero_copy]
+#[derive(Debug, Default)]
+pub struct LedgerReceipt {
+    /// receipt id, unique among the receipts of the [Escrow].
+    pub receipt_id: u64,
+    /// when the NFT was locked.
+    pub vest_started_at: i64,
+    /// when the reward is fully vested.
+    pub vest_ends_at: i64,
+    /// claimed amount
+    pub claimed_amount: u64,
+    /// NFT stake duration unit of the reward terms.
+    pub nft_stake_duration_unit: i64,
+    /// NFT stake base reward of the reward terms.
+    pub nft_stake_base_reward: u64,
+    /// Number of WL tokens minted for the NFT.
+    pub wl_token_amount: u64,
+    /// reward per period of each segment of the reward schedule.
+    pub segment_rewards: [u64; MAX_REWARD_SEGMENTS],
+    /// mint of the locked NFT.
+    pub nft_mint: Pubkey,
+    /// [Proof] that accepted the NFT.
+    pub proof: Pubkey,
+    /// verified collection of the NFT; only set with `has_collection`.
+    pub collection: Pubkey,
+    /// rate of the reward paid to this receipt, in basis points.
+    pub reward_bps: u16,
+    /// NFT stake duration count of the reward terms.
+    pub nft_stake_duration_count: u8,
+    /// First halving count of the reward terms.
+    pub nft_reward_halving_starts_at: u8,
+    /// number of segments of the reward schedule; zero without a schedule.
+    pub segment_count: u8,
+    /// whether the reward of a period of the schedule vests linearly.
+    pub linear: u8,
+    /// number of periods of each segment of the reward schedule.
+    pub segment_period_counts: [u8; MAX_REWARD_SEGMENTS],
+    /// type of the [Proof] that matched the NFT.
+    pub proof_type: u8,
+    /// whether the NFT has a verified collection.
+    pub has_collection: u8,
+}
+
+// SAFETY: `LedgerReceipt` is `repr(C)` with no implicit padding and only plain integer
+// fields, as `#[account(zero_copy)]` assumes of its accounts.
+unsafe impl Pod for LedgerReceipt {}
+unsafe impl Zeroable for LedgerReceipt {}
+
+// The size of the fields alone; any padding would make the `Pod` impl unsound.
+const _: () = assert!(
+    size_of::<LedgerReceipt>()
+        == 7 * 8 + 8 * MAX_REWARD_SEGMENTS + 3 * PUBKEY_BYTES + 2 + 4 + MAX_REWARD_SEGMENTS + 2
+);
+
+impl LedgerReceipt {
+    pub const LEN: usize = size_of::<LedgerReceipt>();
+
+    /// Record of a non-custodial or withdrawn [NftReceipt].
+    pub fn from_nft_receipt(receipt: &NftReceipt) -> Result<Self> {
+        invariant!(
+            !receipt.custodial || receipt.withdrawn,
+            ProtocolError::NftNotWithdrawn
+        );
+        invariant!(
+            receipt.reward_mint.is_none(),
+            ProtocolError::RewardMintNotSupported
+        );
+        invariant!(
+            receipt.vest_tier.is_none(),
+            ProtocolError::VestTierNotSupported
+        );
+
+        let mut record = LedgerReceipt {
+            receipt_id: receipt.receipt_id,
+            vest_started_at: receipt.vest_started_at,
+            vest_ends_at: receipt.vest_ends_at,
+            claimed_amount: receipt.claimed_amount,
+            nft_stake_duration_unit: receipt.reward_params.nft_stake_duration_unit,
+            nft_stake_base_reward: receipt.reward_params.nft_stake_base_reward,
+            wl_token_amount: receipt.reward_params.wl_token_amount,
+            nft_mint: receipt.nft_mint,
+            proof: receipt.proof,
+            collection: receipt.collection.unwrap_or_default(),
+            reward_bps: receipt.reward_bps,
+            nft_stake_duration_count: receipt.reward_params.nft_stake_duration_count,
+            nft_reward_halving_starts_at: receipt.reward_params.nft_reward_halving_starts_at,
+            proof_type: receipt.proof_type,
+            has_collection: receipt.collection.is_some() as u8,
+            ..Default::default()
+        };
+
+        if let Some(schedule) = &receipt.reward_schedule {
+            invariant!(
+                schedule.segments.len() <= MAX_REWARD_SEGMENTS,
+                ProtocolError::InvalidRewardSchedule
+            );
+
+            record.segment_count = schedule.segments.len() as u8;
+            record.linear = schedule.linear as u8;
+            for (i, segment) in schedule.segments.iter().enumerate() {
+                record.segment_period_counts[i] = segment.period_count;
+                record.segment_rewards[i] = segment.reward_per_period;
+            }
+        }
+
+        Ok(record)
+    }
+
+    /// The [NftReceipt] of the record, to value it.
+    pub fn to_nft_receipt(&self, ledger: &ReceiptLedger) -> NftReceipt {
+        let reward_schedule = if self.segment_count > 0 {
+            let segments = (0..self.segment_count as usize)
+                .map(|i| RewardSegment {
+                    period_count: self.segment_period_counts[i],
+                    reward_per_period: self.segment_rewards[i],
+                })
+                .collect();
+
+            Some(NftRewardSchedule {
+                linear: self.linear != 0,
+                segments,
+            })
+        } else {
+            None
+        };
+
+        NftReceipt {
+            receipt_id: self.receipt_id,
+            locker: ledger.locker,
+            owner: ledger.owner,
+            vest_started_at: self.vest_started_at,
+            vest_ends_at: self.vest_ends_at,
+            claimed_amount: self.claimed_amount,
+            nft_mint: self.nft_mint,
+            custodial: false,
+            reward_bps: self.reward_bps,
+            withdrawn: false,
+            reward_params: NftRewardParams {
+                nft_stake_duration_unit: self.nft_stake_duration_unit,
+                nft_stake_base_reward: self.nft_stake_base_reward,
+                nft_stake_duration_count: self.nft_stake_duration_count,
+                nft_reward_halving_starts_at: self.nft_reward_halving_starts_at,
+                wl_token_amount: self.wl_token_amount,
+            },
+            reward_schedule,
+            proof: self.proof,
+            proof_type: self.proof_type,
+            collection: if self.has_collection != 0 {
+                Some(self.collection)
+            } else {
+                None
+            },
+            reward_mint: None,
+            vest_tier: None,
+        }
+    }
+}
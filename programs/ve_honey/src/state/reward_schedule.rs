@@ -0,0 +1,93 @@
+use crate::*;
+use anchor_lang::solana_program::pubkey::PUBKEY_BYTES;
+use num_traits::ToPrimitive;
+
+/// Schedule of the NFT rewards of a [Locker], set by governance.
+#[account]
+#[derive(Debug, Default)]
+pub struct RewardSchedule {
+    /// [Locker] that this schedule belongs to.
+    pub locker: Pubkey,
+    /// The schedule.
+    pub schedule: NftRewardSchedule,
+}
+
+impl RewardSchedule {
+    pub const LEN: usize = PUBKEY_BYTES + NftRewardSchedule::LEN;
+}
+
+/// Periods paying the same reward each.
+#[derive(AnchorDeserialize, AnchorSerialize, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RewardSegment {
+    /// Number of periods of the segment.
+    pub period_count: u8,
+    /// Reward paid for each period.
+    pub reward_per_period: u64,
+}
+
+impl RewardSegment {
+    pub const LEN: usize = 1 + 8;
+}
+
+/// Piecewise reward of a locked NFT, over periods of the NFT stake duration unit.
+#[derive(AnchorDeserialize, AnchorSerialize, Default, Debug, Clone, PartialEq, Eq)]
+pub struct NftRewardSchedule {
+    /// Whether the reward of a period vests linearly instead of at its end.
+    pub linear: bool,
+    /// Segments of the schedule, in order.
+    pub segments: Vec<RewardSegment>,
+}
+
+impl NftRewardSchedule {
+    pub const LEN: usize = 1 + 4 + RewardSegment::LEN * MAX_REWARD_SEGMENTS;
+
+    pub fn validate(&self) -> Result<()> {
+        invariant!(
+            !self.segments.is_empty()
+                && self.segments.len() <= MAX_REWARD_SEGMENTS
+                && self.segments.iter().all(|s| s.period_count > 0),
+            ProtocolError::InvalidRewardSchedule
+        );
+
+        Ok(())
+    }
+
+    pub fn period_count(&self) -> u64 {
+        self.segments.iter().map(|s| s.period_count as u64).sum()
+    }
+
+    pub fn calculate_nft_max_stake_duration(&self, period: i64) -> Option<i64> {
+        period.checked_mul(self.period_count().to_i64()?)
+    }
+
+    pub fn calculate_reward_amount(&self, period: i64, duration: i64) -> Option<u64> {
+        if duration <= 0 || period <= 0 {
+            return None;
+        }
+
+        let period = period as u64;
+        let mut duration = duration as u64;
+        let mut reward_amount: u64 = 0;
+
+        for segment in self.segments.iter() {
+            let elapsed_periods = (duration / period).min(segment.period_count as u64);
+            reward_amount = reward_amount
+                .checked_add(segment.reward_per_period.checked_mul(elapsed_periods)?)?;
+
+            if elapsed_periods < segment.period_count as u64 {
+                if self.linear {
+                    let partial = (segment.reward_per_period as u128)
+                        .checked_mul((duration % period).into())?
+                        .checked_div(period.into())?
+                        .to_u64()?;
+                    reward_amount = reward_amount.checked_add(partial)?;
+                }
+                break;
+            }
+
+            duration -= elapsed_periods * period;
+        }
+
+        Some(reward_amount)
+    }
+}
@@ -0,0 +1,46 @@
+use crate::*;
+use anchor_lang::solana_program::pubkey::PUBKEY_BYTES;
+use num_traits::ToPrimitive;
+
+/// Vest tiers that users pick from when locking an NFT into a [Locker], set by governance.
+#[account]
+#[derive(Debug, Default)]
+pub struct VestTiers {
+    /// [Locker] that these tiers belong to.
+    pub locker: Pubkey,
+    /// The tiers.
+    pub tiers: Vec<VestTier>,
+}
+
+impl VestTiers {
+    pub const LEN: usize = PUBKEY_BYTES + 4 + VestTier::LEN * MAX_VEST_TIERS;
+}
+
+/// Vest of a locked NFT: its reward vests over `vest_duration` instead of the max stake
+/// duration of its reward terms, scaled by `reward_bps`.
+#[derive(AnchorDeserialize, AnchorSerialize, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VestTier {
+    /// Duration the reward of the receipt vests over.
+    pub vest_duration: i64,
+    /// Duration the lock of the [Escrow] is extended to.
+    pub escrow_duration: i64,
+    /// Rate of the reward paid for the tier, in basis points.
+    pub reward_bps: u16,
+}
+
+impl VestTier {
+    pub const LEN: usize = 8 + 8 + 2;
+
+    pub fn validate(&self, params: &LockerParams) -> Result<()> {
+        let escrow_duration_valid = self.escrow_duration.to_u64().is_some_and(|duration| {
+            params.min_stake_duration <= duration && duration <= params.max_stake_duration
+        });
+
+        invariant!(
+            self.vest_duration > 0 && self.reward_bps > 0 && escrow_duration_valid,
+            ProtocolError::InvalidVestTiers
+        );
+
+        Ok(())
+    }
+}
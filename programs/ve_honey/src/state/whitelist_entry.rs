@@ -1,5 +1,6 @@
 use crate::*;
 use anchor_lang::solana_program::pubkey::PUBKEY_BYTES;
+use num_traits::ToPrimitive;
 
 #[account]
 #[derive(Debug, Default)]
@@ -14,8 +15,82 @@ pub struct WhitelistEntry {
     /// If set to [anchor_lang::solana_program::system_program::ID],
     /// all accounts are allowed to be the [Escrow::owner]
     pub owner: Pubkey,
+
+    /// Limits applied to the locks made through this entry.
+    pub params: WhitelistEntryParams,
+    /// Total amount of tokens locked through this entry.
+    pub locked_amount: u64,
+    /// Number of locks made through this entry.
+    pub lock_count: u64,
 }
 
 impl WhitelistEntry {
-    pub const LEN: usize = 1 + PUBKEY_BYTES * 3;
+    pub const LEN: usize = 1 + PUBKEY_BYTES * 3 + WhitelistEntryParams::LEN + 8 + 8;
+
+    /// Checks the lock against the entry limits and records its usage.
+    pub fn record_lock(&mut self, amount: u64, duration: i64, now: i64) -> Result<()> {
+        let params = &self.params;
+
+        if params.expires_at != 0 {
+            invariant!(
+                now < params.expires_at,
+                ProtocolError::WhitelistEntryExpired
+            );
+        }
+
+        let duration = unwrap_int!(duration.to_u64());
+        if params.min_duration != 0 {
+            invariant!(
+                duration >= params.min_duration,
+                ProtocolError::WhitelistLockDurationTooShort
+            );
+        }
+        if params.max_duration != 0 {
+            invariant!(
+                duration <= params.max_duration,
+                ProtocolError::WhitelistLockDurationTooLong
+            );
+        }
+
+        let locked_amount = unwrap_int!(self.locked_amount.checked_add(amount));
+        if params.max_locked_amount != 0 {
+            invariant!(
+                locked_amount <= params.max_locked_amount,
+                ProtocolError::WhitelistLockAmountExceeded
+            );
+        }
+
+        self.locked_amount = locked_amount;
+        self.lock_count = unwrap_int!(self.lock_count.checked_add(1));
+
+        Ok(())
+    }
+}
+
+/// Optional limits of a [WhitelistEntry]. A value of `0` disables the limit.
+#[derive(AnchorDeserialize, AnchorSerialize, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WhitelistEntryParams {
+    /// Maximum cumulative amount of tokens that can be locked through the entry.
+    pub max_locked_amount: u64,
+    /// Minimum lock duration allowed through the entry.
+    pub min_duration: u64,
+    /// Maximum lock duration allowed through the entry.
+    pub max_duration: u64,
+    /// Timestamp after which the entry can no longer be used.
+    pub expires_at: i64,
+}
+
+impl WhitelistEntryParams {
+    pub const LEN: usize = 8 + 8 + 8 + 8;
+
+    pub fn validate(&self) -> Result<()> {
+        if self.min_duration != 0 && self.max_duration != 0 {
+            invariant!(
+                self.min_duration <= self.max_duration,
+                ProtocolError::InvalidWhitelistEntryParams
+            );
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,133 @@
+use crate::*;
+use anchor_lang::solana_program::pubkey::PUBKEY_BYTES;
+
+/// Offer registered by governance that WL tokens of a [Locker] can be redeemed against.
+/// What an offer grants is delivered off-chain from the [WlRedemption]s recorded on it.
+#[account]
+#[derive(Debug, Default)]
+pub struct WlOffer {
+    /// [Locker] that this offer belongs to.
+    pub locker: Pubkey,
+    /// Id of the offer in the [Locker], chosen by governance.
+    pub offer_id: u64,
+    /// Terms of the offer.
+    pub params: WlOfferParams,
+    /// Number of units redeemed against the offer.
+    pub redeemed_units: u64,
+    /// Amount of WL tokens burnt against the offer.
+    pub wl_burned: u64,
+}
+
+impl WlOffer {
+    pub const LEN: usize = PUBKEY_BYTES + 8 + WlOfferParams::LEN + 8 + 8;
+
+    /// Checks that `units` of the offer can be redeemed by the owner of `redemption` at
+    /// `now` and records them, returning the number of whole WL tokens to burn.
+    pub fn record_redemption(
+        &mut self,
+        redemption: &mut WlRedemption,
+        units: u64,
+        now: i64,
+    ) -> Result<u64> {
+        let params = &self.params;
+
+        if params.expires_at != 0 {
+            invariant!(now < params.expires_at, ProtocolError::WlOfferExpired);
+        }
+
+        let redeemed_units = unwrap_int!(self.redeemed_units.checked_add(units));
+        if params.max_units != 0 {
+            invariant!(
+                redeemed_units <= params.max_units,
+                ProtocolError::WlOfferSoldOut
+            );
+        }
+
+        let user_redeemed_units = unwrap_int!(redemption.redeemed_units.checked_add(units));
+        if params.max_units_per_user != 0 {
+            invariant!(
+                user_redeemed_units <= params.max_units_per_user,
+                ProtocolError::WlRedemptionCapReached
+            );
+        }
+
+        let wl_amount = unwrap_int!(units.checked_mul(params.wl_cost));
+
+        self.redeemed_units = redeemed_units;
+        self.wl_burned = unwrap_int!(self.wl_burned.checked_add(wl_amount));
+        redemption.redeemed_units = user_redeemed_units;
+        redemption.wl_burned = unwrap_int!(redemption.wl_burned.checked_add(wl_amount));
+
+        Ok(wl_amount)
+    }
+}
+
+/// What a unit of a [WlOffer] grants.
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WlOfferKind {
+    /// A slot in the mint allowlist of `project`.
+    AllowlistSlot { project: Pubkey },
+    /// An allocation of `amount` tokens of `mint`, bought for `price` tokens of
+    /// `price_mint`.
+    TokenAllocation {
+        mint: Pubkey,
+        amount: u64,
+        price_mint: Pubkey,
+        price: u64,
+    },
+}
+
+impl Default for WlOfferKind {
+    fn default() -> Self {
+        WlOfferKind::AllowlistSlot {
+            project: Pubkey::default(),
+        }
+    }
+}
+
+impl WlOfferKind {
+    pub const LEN: usize = 1 + PUBKEY_BYTES + 8 + PUBKEY_BYTES + 8;
+}
+
+/// Terms of a [WlOffer].
+#[derive(AnchorDeserialize, AnchorSerialize, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WlOfferParams {
+    /// What a unit of the offer grants.
+    pub kind: WlOfferKind,
+    /// Number of whole WL tokens burnt per unit.
+    pub wl_cost: u64,
+    /// Maximum number of units redeemed against the offer; unlimited if zero.
+    pub max_units: u64,
+    /// Maximum number of units redeemed by a user; unlimited if zero.
+    pub max_units_per_user: u64,
+    /// Timestamp after which the offer can no longer be redeemed; never if zero.
+    pub expires_at: i64,
+}
+
+impl WlOfferParams {
+    pub const LEN: usize = WlOfferKind::LEN + 8 + 8 + 8 + 8;
+
+    pub fn validate(&self) -> Result<()> {
+        invariant!(self.wl_cost > 0, ProtocolError::InvalidWlOfferParams);
+
+        Ok(())
+    }
+}
+
+/// WL tokens redeemed by a user against a [WlOffer].
+#[account]
+#[derive(Debug, Default)]
+pub struct WlRedemption {
+    /// The [WlOffer].
+    pub offer: Pubkey,
+    /// The user that redeemed.
+    pub owner: Pubkey,
+    /// Number of units redeemed.
+    pub redeemed_units: u64,
+    /// Amount of WL tokens burnt.
+    pub wl_burned: u64,
+}
+
+impl WlRedemption {
+    pub const LEN: usize = PUBKEY_BYTES + PUBKEY_BYTES + 8 + 8;
+}
@@ -12,3 +12,15 @@ solana program dump Govz1VyoyLD5BL6CSCxUJLVLsQHRwjfFj1prNsdNg5Jw \
 
 solana program dump metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s \
     artifacts/deploy/metaplex_token_metadata.so --url devnet
+
+solana program dump BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY \
+    artifacts/deploy/mpl_bubblegum.so --url devnet
+
+solana program dump cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK \
+    artifacts/deploy/spl_account_compression.so --url devnet
+
+solana program dump noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV \
+    artifacts/deploy/spl_noop.so --url devnet
+
+solana program dump CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d \
+    artifacts/deploy/mpl_core.so --url devnet
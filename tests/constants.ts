@@ -1,6 +1,10 @@
 import * as anchor from "@project-serum/anchor";
 
-import { GovernorParams, LockerParams } from "./mock/governor";
+import {
+  GovernorParams,
+  LockerParams,
+  WhitelistEntryParams,
+} from "./mock/governor";
 
 export const POOL_INFO_SEED = "PoolInfo";
 export const POOL_USER_SEED = "PoolUser";
@@ -16,6 +20,20 @@ export const WHITELIST_ENTRY_SEED = "LockerWhitelistEntry";
 export const TREASURY_SEED = "Treasury";
 export const PROOF_SEED = "Proof";
 export const NFT_RECEIPT_SEED = "Receipt";
+export const LOCK_AUTHORITY_SEED = "LockAuthority";
+export const NFT_VAULT_SEED = "NftVault";
+export const REWARD_SCHEDULE_SEED = "RewardSchedule";
+export const VEST_TIERS_SEED = "VestTiers";
+export const ESCROW_TRANSFER_SEED = "EscrowTransfer";
+export const OPERATOR_APPROVAL_SEED = "OperatorApproval";
+export const RECEIPT_LEDGER_SEED = "ReceiptLedger";
+export const CAMPAIGN_SEED = "Campaign";
+export const COLLECTION_BURNS_SEED = "CollectionBurns";
+export const ESCROW_BURNS_SEED = "EscrowBurns";
+export const WL_OFFER_SEED = "WlOffer";
+export const WL_REDEMPTION_SEED = "WlRedemption";
+
+export const DISTRIBUTOR_SEED = "MerkleDistributor";
 
 // external seeds
 export const SMART_WALLET_SEED = "GokiSmartWallet";
@@ -26,7 +44,32 @@ export const PROPOSAL_SEED = "TribecaProposal";
 export const PROPOSAL_META_SEED = "TribecaProposalMeta";
 export const VOTE_SEED = "TribecaVote";
 
+// external programs
+export const BUBBLEGUM_PROGRAM_ID = new anchor.web3.PublicKey(
+  "BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY"
+);
+export const ACCOUNT_COMPRESSION_PROGRAM_ID = new anchor.web3.PublicKey(
+  "cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK"
+);
+export const NOOP_PROGRAM_ID = new anchor.web3.PublicKey(
+  "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV"
+);
+export const MPL_CORE_PROGRAM_ID = new anchor.web3.PublicKey(
+  "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+);
+
+export const PROOF_TYPE_CREATOR = 1 << 0;
+export const PROOF_TYPE_MINT = 1 << 1;
+export const PROOF_TYPE_COLLECTION = 1 << 2;
+export const PROOF_TYPE_MERKLE = 1 << 3;
+
+export const OPERATOR_ACTION_EXTEND = 1 << 0;
+export const OPERATOR_ACTION_RELOCK = 1 << 1;
+export const OPERATOR_ACTION_CLAIM = 1 << 2;
+export const OPERATOR_ACTION_VOTE = 1 << 3;
+
 export const DEFAULT_DECIMALS = 6;
+export const MAX_BPS = 10_000;
 
 export const DEFAULT_GOVERNOR_PARAMS: GovernorParams = {
   votingDelay: new anchor.BN(1),
@@ -45,4 +88,12 @@ export const DEFAULT_LOCKER_PARAMS: LockerParams = {
   nftStakeBaseReward: new anchor.BN(3_750_000_000),
   nftStakeDurationCount: 10,
   nftRewardHalvingStartsAt: 2,
+  nftCustodialRewardBps: 5_000,
+};
+
+export const DEFAULT_WHITELIST_ENTRY_PARAMS: WhitelistEntryParams = {
+  maxLockedAmount: new anchor.BN(0),
+  minDuration: new anchor.BN(0),
+  maxDuration: new anchor.BN(0),
+  expiresAt: new anchor.BN(0),
 };
@@ -0,0 +1,142 @@
+require("./utils/setup");
+import * as anchor from "@project-serum/anchor";
+import { AnchorProvider } from "@project-serum/anchor";
+import { assert, expect } from "chai";
+
+import { MockMint } from "./mock/mint";
+import { MockWallet } from "./mock/wallet";
+import { MockGovernor } from "./mock/governor";
+import { MockDistributor } from "./mock/distributor";
+import * as constants from "./constants";
+import { checkBN, checkTokenAccount } from "./utils/check";
+import { DistributionTree } from "./utils/merkle";
+
+describe("merkle distributor", () => {
+  const provider = AnchorProvider.env();
+
+  let tokenMint: MockMint;
+  let wlTokenMint: MockMint;
+  let governor: MockGovernor;
+  let claimants: MockWallet[];
+  let tree: DistributionTree;
+  let distributor: MockDistributor;
+
+  before(async () => {
+    [tokenMint, wlTokenMint] = await Promise.all([
+      MockMint.create(provider, 6),
+      MockMint.create(provider, 6),
+    ]);
+
+    governor = await MockGovernor.create({
+      provider,
+      tokenMint,
+      wlTokenMint,
+      governorParams: {
+        ...constants.DEFAULT_GOVERNOR_PARAMS,
+      },
+      lockerParams: {
+        ...constants.DEFAULT_LOCKER_PARAMS,
+        minStakeDuration: new anchor.BN(2),
+      },
+    });
+
+    const program = anchor.workspace.MerkleDistributor;
+    await governor.approveProgramLockPrivilege(
+      constants.DEFAULT_WHITELIST_ENTRY_PARAMS,
+      program.programId
+    );
+
+    claimants = await Promise.all(
+      [0, 1, 2].map(() => MockWallet.createWithBalance(provider, 1))
+    );
+    tree = new DistributionTree([
+      {
+        claimant: claimants[0].publicKey,
+        amount: new anchor.BN(1_000_000),
+        duration: new anchor.BN(3),
+      },
+      {
+        claimant: claimants[1].publicKey,
+        amount: new anchor.BN(2_000_000),
+        duration: new anchor.BN(1),
+      },
+      {
+        claimant: claimants[2].publicKey,
+        amount: new anchor.BN(500_000),
+        duration: new anchor.BN(4),
+      },
+    ]);
+    distributor = await MockDistributor.create({ provider, governor, tree });
+  });
+
+  it("claim locks the airdrop into the claimant's escrow", async () => {
+    await distributor.claim({ claimant: claimants[0], index: 0 });
+
+    const escrow = await distributor.getEscrowAddress(claimants[0].publicKey);
+    const escrowAccount =
+      await governor.veHoneyProgram.account.escrow.fetch(escrow);
+    checkBN(escrowAccount.amount, tree.claims[0].amount, "amount");
+    checkBN(
+      escrowAccount.escrowEndsAt.sub(escrowAccount.escrowStartedAt),
+      tree.claims[0].duration,
+      "duration"
+    );
+    checkTokenAccount({
+      account: await tokenMint.tryGetAssociatedTokenAccount(escrow),
+      mint: tokenMint.address,
+      amount: tree.claims[0].amount,
+    });
+
+    const distributorAccount = await distributor.fetch();
+    checkBN(
+      distributorAccount.totalAmountClaimed,
+      tree.claims[0].amount,
+      "totalAmountClaimed"
+    );
+    checkBN(
+      distributorAccount.numNodesClaimed,
+      new anchor.BN(1),
+      "numNodesClaimed"
+    );
+  });
+
+  it("leaf cannot be claimed twice", async () => {
+    const claimWithFail = distributor.claim({
+      claimant: claimants[0],
+      index: 0,
+    });
+
+    await expect(claimWithFail).to.eventually.be.rejectedWith(
+      'failed ({"err":{"InstructionError":[0,{"Custom":6201}]}})'
+    );
+  });
+
+  it("claim with another claimant's leaf fails", async () => {
+    const claimWithFail = distributor.claim({
+      claimant: claimants[2],
+      index: 1,
+    });
+
+    await expect(claimWithFail).to.eventually.be.rejectedWith(
+      'failed ({"err":{"InstructionError":[0,{"Custom":6200}]}})'
+    );
+  });
+
+  it("lock duration is raised to the min stake duration", async () => {
+    await distributor.claim({ claimant: claimants[1], index: 1 });
+
+    const escrow = await distributor.getEscrowAddress(claimants[1].publicKey);
+    const escrowAccount =
+      await governor.veHoneyProgram.account.escrow.fetch(escrow);
+    checkBN(escrowAccount.amount, tree.claims[1].amount, "amount");
+    checkBN(
+      escrowAccount.escrowEndsAt.sub(escrowAccount.escrowStartedAt),
+      new anchor.BN(2),
+      "duration"
+    );
+    assert.isTrue(
+      tree.claims[1].duration.lt(new anchor.BN(2)),
+      "leaf duration below the min stake duration"
+    );
+  });
+});
@@ -1,9 +1,11 @@
+require("./utils/setup");
 import * as anchor from "@project-serum/anchor";
 import { AnchorProvider } from "@project-serum/anchor";
-import { assert } from "chai";
+import { assert, expect } from "chai";
 
 import { MockGovernor, LockerParams } from "./mock/governor";
 import { MintHelpers, MockMint } from "./mock/mint";
+import { MockWallet } from "./mock/wallet";
 import * as constants from "./constants";
 import {
   checkLocker,
@@ -65,6 +67,7 @@ describe("governor in locker", () => {
       nftStakeBaseReward: new anchor.BN(3_750_000_000),
       nftStakeDurationCount: 10,
       nftRewardHalvingStartsAt: 2,
+      nftCustodialRewardBps: 2_500,
     };
 
     await governor.setLockerParams({ ...newParams });
@@ -116,6 +119,34 @@ describe("governor in locker", () => {
     assert.strictEqual(whitelistEntryAccount, null);
   });
 
+  it("governor can update lock privilege limits", async () => {
+    await governor.approveProgramLockPrivilege();
+
+    const whitelistEntry = await governor.getWhitelistEntryAddress(
+      governor.stakeProgram.programId,
+      anchor.web3.SystemProgram.programId
+    );
+    const params = {
+      maxLockedAmount: new anchor.BN(100_000_000),
+      minDuration: new anchor.BN(10),
+      maxDuration: new anchor.BN(100),
+      expiresAt: new anchor.BN(Math.floor(Date.now() / 1000) + 3600),
+    };
+    await governor.setProgramLockPrivilegeParams(whitelistEntry, params);
+
+    const whitelistEntryAccount = await governor.fetchWhitelistEntry();
+
+    checkWhitelistEntry({
+      account: whitelistEntryAccount,
+      locker: governor.locker,
+      programId: governor.stakeProgram.programId,
+      owner: anchor.web3.SystemProgram.programId,
+      params,
+      lockedAmount: new anchor.BN(0),
+      lockCount: new anchor.BN(0),
+    });
+  });
+
   it("governor can add/remove proof", async () => {
     const proofAddress = anchor.web3.Keypair.generate();
     await governor.addProof(proofAddress.publicKey);
@@ -136,6 +167,54 @@ describe("governor in locker", () => {
     assert.strictEqual(proofAccount, null);
   });
 
+  it("governor can add collection proof only for collection mints", async () => {
+    const collectionMint = await MockMint.create(provider, 0);
+    await collectionMint.mintTo(
+      await MockWallet.createWithBalance(provider, 1),
+      new anchor.BN(1)
+    );
+    await governor.addProof(
+      collectionMint.address,
+      constants.PROOF_TYPE_COLLECTION
+    );
+
+    checkProof({
+      account: await governor.fetchProof(collectionMint.address),
+      proofType: constants.PROOF_TYPE_COLLECTION,
+      proofAddress: collectionMint.address,
+      locker: governor.locker,
+    });
+
+    const notCollection = anchor.web3.Keypair.generate();
+    const addWithFail = governor.addProof(
+      notCollection.publicKey,
+      constants.PROOF_TYPE_COLLECTION
+    );
+    await expect(addWithFail).to.eventually.be.rejected;
+  });
+
+  it("governor can set/remove reward schedule", async () => {
+    const setWithFail = governor.setRewardSchedule({
+      linear: true,
+      segments: [],
+    });
+    await expect(setWithFail).to.eventually.be.rejected;
+
+    await governor.setRewardSchedule({
+      linear: true,
+      segments: [{ periodCount: 4, rewardPerPeriod: new anchor.BN(1_000) }],
+    });
+
+    const rewardSchedule = await governor.fetchRewardSchedule();
+    assert.ok(rewardSchedule.locker.equals(governor.locker));
+    assert.strictEqual(rewardSchedule.schedule.linear, true);
+    assert.strictEqual(rewardSchedule.schedule.segments[0].periodCount, 4);
+
+    await governor.removeRewardSchedule();
+
+    assert.strictEqual(await governor.fetchRewardSchedule(), null);
+  });
+
   it("can be set/reclaim mint authority of WL tokens", async () => {
     await governor.setWlMintAuthority();
 
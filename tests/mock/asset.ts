@@ -0,0 +1,303 @@
+import { AnchorProvider } from "@project-serum/anchor";
+import { programs } from "@metaplex/js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  Token,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  Transaction,
+  TransactionInstruction,
+} from "@solana/web3.js";
+
+import { MockWallet } from "./wallet";
+import { MockNFT, TEST_METADATA } from "./nft";
+import * as constants from "../constants";
+
+const METADATA_PROGRAM_ID = programs.metadata.MetadataProgram.PUBKEY;
+
+// `MetadataInstruction::Create` and `Mint` of token metadata
+const TOKEN_METADATA_CREATE_INSTRUCTION = 42;
+const TOKEN_METADATA_MINT_INSTRUCTION = 43;
+// `MetadataInstruction::Verify` of token metadata, with `CollectionV1` args
+const TOKEN_METADATA_VERIFY_INSTRUCTION = 52;
+const VERIFICATION_ARGS_COLLECTION_V1 = 1;
+const TOKEN_STANDARD_PROGRAMMABLE_NON_FUNGIBLE = 4;
+// `MplAssetInstruction::CreateV1` and `CreateCollectionV1` of Metaplex Core
+const CORE_CREATE_V1_INSTRUCTION = 0;
+const CORE_CREATE_COLLECTION_V1_INSTRUCTION = 1;
+
+function accountMeta(
+  pubkey: PublicKey,
+  isSigner: boolean,
+  isWritable: boolean
+) {
+  return { pubkey, isSigner, isWritable };
+}
+
+function serializeString(value: string): Buffer {
+  const data = Buffer.from(value, "utf-8");
+  const len = Buffer.alloc(4);
+  len.writeUInt32LE(data.length);
+  return Buffer.concat([len, data]);
+}
+
+// A programmable NFT created and minted with token metadata `CreateV1` and
+// `MintV1`, with no rule set, optionally verified in a collection NFT.
+export class MockProgrammableNft {
+  provider: AnchorProvider;
+  owner: MockWallet;
+  mint: Keypair;
+  metadata: PublicKey;
+  edition: PublicKey;
+  token: PublicKey;
+  tokenRecord: PublicKey;
+  collection: MockNFT | null;
+
+  constructor(
+    provider: AnchorProvider,
+    owner: MockWallet,
+    collection: MockNFT | null = null
+  ) {
+    this.provider = provider;
+    this.owner = owner;
+    this.mint = Keypair.generate();
+    this.collection = collection;
+  }
+
+  public async init() {
+    const mint = this.mint.publicKey;
+    this.metadata = await programs.metadata.Metadata.getPDA(mint);
+    this.edition = await programs.metadata.Edition.getPDA(mint);
+    this.token = await Token.getAssociatedTokenAddress(
+      ASSOCIATED_TOKEN_PROGRAM_ID,
+      TOKEN_PROGRAM_ID,
+      mint,
+      this.owner.publicKey
+    );
+    [this.tokenRecord] = await PublicKey.findProgramAddress(
+      [
+        Buffer.from("metadata"),
+        METADATA_PROGRAM_ID.toBuffer(),
+        mint.toBuffer(),
+        Buffer.from("token_record"),
+        this.token.toBuffer(),
+      ],
+      METADATA_PROGRAM_ID
+    );
+
+    const authority = this.provider.wallet.publicKey;
+    const fee = Buffer.alloc(2);
+    fee.writeUInt16LE(TEST_METADATA.sellerFeeBasisPoints);
+    const amount = Buffer.alloc(8);
+    amount.writeUInt8(1);
+
+    const tx = new Transaction().add(
+      new TransactionInstruction({
+        programId: METADATA_PROGRAM_ID,
+        keys: [
+          accountMeta(this.metadata, false, true),
+          accountMeta(this.edition, false, true),
+          accountMeta(mint, true, true),
+          accountMeta(authority, true, false),
+          accountMeta(authority, true, true),
+          accountMeta(authority, true, false),
+          accountMeta(SystemProgram.programId, false, false),
+          accountMeta(SYSVAR_INSTRUCTIONS_PUBKEY, false, false),
+          accountMeta(TOKEN_PROGRAM_ID, false, false),
+        ],
+        data: Buffer.concat([
+          Buffer.from([TOKEN_METADATA_CREATE_INSTRUCTION, 0]),
+          serializeString(TEST_METADATA.name),
+          serializeString(TEST_METADATA.symbol),
+          serializeString(TEST_METADATA.uri),
+          fee,
+          // no creators, primary sale, mutable
+          Buffer.from([0, 0, 1]),
+          Buffer.from([TOKEN_STANDARD_PROGRAMMABLE_NON_FUNGIBLE]),
+          this.collection
+            ? // unverified collection, verified below
+              Buffer.concat([
+                Buffer.from([1, 0]),
+                this.collection.mint.address.toBuffer(),
+              ])
+            : Buffer.from([0]),
+          // no uses, collection details nor rule set
+          Buffer.from([0, 0, 0]),
+          // 0 decimals, print supply of zero
+          Buffer.from([1, 0, 1, 0]),
+        ]),
+      }),
+      new TransactionInstruction({
+        programId: METADATA_PROGRAM_ID,
+        keys: [
+          accountMeta(this.token, false, true),
+          accountMeta(this.owner.publicKey, false, false),
+          accountMeta(this.metadata, false, false),
+          accountMeta(this.edition, false, false),
+          accountMeta(this.tokenRecord, false, true),
+          accountMeta(mint, false, true),
+          accountMeta(authority, true, false),
+          accountMeta(METADATA_PROGRAM_ID, false, false),
+          accountMeta(authority, true, true),
+          accountMeta(SystemProgram.programId, false, false),
+          accountMeta(SYSVAR_INSTRUCTIONS_PUBKEY, false, false),
+          accountMeta(TOKEN_PROGRAM_ID, false, false),
+          accountMeta(ASSOCIATED_TOKEN_PROGRAM_ID, false, false),
+          accountMeta(METADATA_PROGRAM_ID, false, false),
+          accountMeta(METADATA_PROGRAM_ID, false, false),
+        ],
+        data: Buffer.concat([
+          Buffer.from([TOKEN_METADATA_MINT_INSTRUCTION, 0]),
+          amount,
+          // no authorization data
+          Buffer.from([0]),
+        ]),
+      })
+    );
+
+    await this.provider.sendAndConfirm(tx, [this.mint]);
+
+    if (this.collection) {
+      await this.verifyCollection();
+    }
+  }
+
+  private async verifyCollection() {
+    const collectionMint = this.collection.mint.address;
+    const tx = new Transaction().add(
+      new TransactionInstruction({
+        programId: METADATA_PROGRAM_ID,
+        keys: [
+          accountMeta(this.provider.wallet.publicKey, true, false),
+          accountMeta(METADATA_PROGRAM_ID, false, false),
+          accountMeta(this.metadata, false, true),
+          accountMeta(collectionMint, false, false),
+          accountMeta(
+            await programs.metadata.Metadata.getPDA(collectionMint),
+            false,
+            true
+          ),
+          accountMeta(
+            await programs.metadata.Edition.getPDA(collectionMint),
+            false,
+            false
+          ),
+          accountMeta(SystemProgram.programId, false, false),
+          accountMeta(SYSVAR_INSTRUCTIONS_PUBKEY, false, false),
+        ],
+        data: Buffer.from([
+          TOKEN_METADATA_VERIFY_INSTRUCTION,
+          VERIFICATION_ARGS_COLLECTION_V1,
+        ]),
+      })
+    );
+
+    await this.provider.sendAndConfirm(tx);
+  }
+
+  public static async create(
+    provider: AnchorProvider,
+    owner: MockWallet,
+    collection: MockNFT | null = null
+  ) {
+    const nft = new MockProgrammableNft(provider, owner, collection);
+    await nft.init();
+    return nft;
+  }
+}
+
+// A Metaplex Core asset, either in a new collection or with an address as
+// its update authority.
+export class MockCoreAsset {
+  provider: AnchorProvider;
+  owner: MockWallet;
+  asset: Keypair;
+  collection: Keypair | null;
+  updateAuthority: PublicKey;
+
+  constructor(
+    provider: AnchorProvider,
+    owner: MockWallet,
+    inCollection: boolean
+  ) {
+    this.provider = provider;
+    this.owner = owner;
+    this.asset = Keypair.generate();
+    this.collection = inCollection ? Keypair.generate() : null;
+    this.updateAuthority = Keypair.generate().publicKey;
+  }
+
+  public async init() {
+    const payer = this.provider.wallet.publicKey;
+    const programId = constants.MPL_CORE_PROGRAM_ID;
+    const tx = new Transaction();
+    const signers = [this.asset];
+
+    if (this.collection) {
+      tx.add(
+        new TransactionInstruction({
+          programId,
+          keys: [
+            accountMeta(this.collection.publicKey, true, true),
+            accountMeta(programId, false, false),
+            accountMeta(payer, true, true),
+            accountMeta(SystemProgram.programId, false, false),
+          ],
+          data: Buffer.concat([
+            Buffer.from([CORE_CREATE_COLLECTION_V1_INSTRUCTION]),
+            serializeString(TEST_METADATA.name),
+            serializeString(TEST_METADATA.uri),
+            // no plugins
+            Buffer.from([0]),
+          ]),
+        })
+      );
+      signers.push(this.collection);
+    }
+
+    tx.add(
+      new TransactionInstruction({
+        programId,
+        keys: [
+          accountMeta(this.asset.publicKey, true, true),
+          this.collection
+            ? accountMeta(this.collection.publicKey, false, true)
+            : accountMeta(programId, false, false),
+          accountMeta(payer, true, false),
+          accountMeta(payer, true, true),
+          accountMeta(this.owner.publicKey, false, false),
+          this.collection
+            ? accountMeta(programId, false, false)
+            : accountMeta(this.updateAuthority, false, false),
+          accountMeta(SystemProgram.programId, false, false),
+          accountMeta(programId, false, false),
+        ],
+        data: Buffer.concat([
+          // account state
+          Buffer.from([CORE_CREATE_V1_INSTRUCTION, 0]),
+          serializeString(TEST_METADATA.name),
+          serializeString(TEST_METADATA.uri),
+          // no plugins
+          Buffer.from([0]),
+        ]),
+      })
+    );
+
+    await this.provider.sendAndConfirm(tx, signers);
+  }
+
+  public static async create(
+    provider: AnchorProvider,
+    owner: MockWallet,
+    inCollection: boolean
+  ) {
+    const asset = new MockCoreAsset(provider, owner, inCollection);
+    await asset.init();
+    return asset;
+  }
+}
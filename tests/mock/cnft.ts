@@ -0,0 +1,310 @@
+import * as anchor from "@project-serum/anchor";
+import { AnchorProvider } from "@project-serum/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import { keccak_256 } from "js-sha3";
+
+import { MockWallet } from "./wallet";
+import { TEST_METADATA } from "./nft";
+import * as constants from "../constants";
+
+const MAX_DEPTH = 3;
+const MAX_BUFFER_SIZE = 8;
+// header, tree counters, change logs and rightmost proof
+const MERKLE_TREE_SIZE =
+  56 +
+  24 +
+  MAX_BUFFER_SIZE * (40 + 32 * MAX_DEPTH) +
+  (40 + 32 * MAX_DEPTH);
+
+const CREATE_TREE_DISCRIMINATOR = [165, 83, 136, 142, 89, 202, 47, 220];
+const MINT_V1_DISCRIMINATOR = [145, 98, 192, 118, 184, 147, 118, 104];
+
+export type CnftCreator = {
+  address: PublicKey;
+  verified: boolean;
+  share: number;
+};
+
+export type CnftMetadata = {
+  name: string;
+  symbol: string;
+  uri: string;
+  sellerFeeBasisPoints: number;
+  primarySaleHappened: boolean;
+  isMutable: boolean;
+  editionNonce: number | null;
+  tokenStandard: number | null;
+  collection: { verified: boolean; key: PublicKey } | null;
+  uses: null;
+  tokenProgramVersion: number;
+  creators: CnftCreator[];
+};
+
+function accountMeta(
+  pubkey: PublicKey,
+  isSigner: boolean,
+  isWritable: boolean
+) {
+  return { pubkey, isSigner, isWritable };
+}
+
+function keccak(...data: Buffer[]): Buffer {
+  return Buffer.from(keccak_256.arrayBuffer(Buffer.concat(data)));
+}
+
+function serializeString(value: string): Buffer {
+  const data = Buffer.from(value, "utf-8");
+  const len = Buffer.alloc(4);
+  len.writeUInt32LE(data.length);
+  return Buffer.concat([len, data]);
+}
+
+function serializeOption(value: Buffer | null): Buffer {
+  return value === null
+    ? Buffer.from([0])
+    : Buffer.concat([Buffer.from([1]), value]);
+}
+
+// Serializes the metadata as Bubblegum `MetadataArgs`.
+export function serializeMetadata(metadata: CnftMetadata): Buffer {
+  const fee = Buffer.alloc(2);
+  fee.writeUInt16LE(metadata.sellerFeeBasisPoints);
+  const creatorsLen = Buffer.alloc(4);
+  creatorsLen.writeUInt32LE(metadata.creators.length);
+
+  return Buffer.concat([
+    serializeString(metadata.name),
+    serializeString(metadata.symbol),
+    serializeString(metadata.uri),
+    fee,
+    Buffer.from([
+      metadata.primarySaleHappened ? 1 : 0,
+      metadata.isMutable ? 1 : 0,
+    ]),
+    serializeOption(
+      metadata.editionNonce === null
+        ? null
+        : Buffer.from([metadata.editionNonce])
+    ),
+    serializeOption(
+      metadata.tokenStandard === null
+        ? null
+        : Buffer.from([metadata.tokenStandard])
+    ),
+    serializeOption(
+      metadata.collection === null
+        ? null
+        : Buffer.concat([
+            Buffer.from([metadata.collection.verified ? 1 : 0]),
+            metadata.collection.key.toBuffer(),
+          ])
+    ),
+    serializeOption(null),
+    Buffer.from([metadata.tokenProgramVersion]),
+    creatorsLen,
+    ...metadata.creators.map((c) =>
+      Buffer.concat([
+        c.address.toBuffer(),
+        Buffer.from([c.verified ? 1 : 0, c.share]),
+      ])
+    ),
+  ]);
+}
+
+export function hashMetadata(metadata: CnftMetadata): Buffer {
+  const fee = Buffer.alloc(2);
+  fee.writeUInt16LE(metadata.sellerFeeBasisPoints);
+  return keccak(keccak(serializeMetadata(metadata)), fee);
+}
+
+export function hashCreators(creators: CnftCreator[]): Buffer {
+  return keccak(
+    ...creators.map((c) =>
+      Buffer.concat([
+        c.address.toBuffer(),
+        Buffer.from([c.verified ? 1 : 0, c.share]),
+      ])
+    )
+  );
+}
+
+// A compressed NFT minted alone into a new Bubblegum tree.
+export class MockCnft {
+  provider: AnchorProvider;
+  merkleTree: Keypair;
+  creator: MockWallet;
+  owner: PublicKey;
+  metadata: CnftMetadata;
+  nonce: anchor.BN;
+  index: number;
+
+  private _proof: PublicKey[] | undefined;
+  get proof(): PublicKey[] {
+    if (this._proof === undefined) {
+      throw new Error("proof undefined");
+    }
+    return this._proof;
+  }
+
+  private _root: Buffer | undefined;
+  get root(): Buffer {
+    if (this._root === undefined) {
+      throw new Error("root undefined");
+    }
+    return this._root;
+  }
+
+  constructor(provider: AnchorProvider, owner: PublicKey) {
+    this.provider = provider;
+    this.merkleTree = Keypair.generate();
+    this.owner = owner;
+    this.nonce = new anchor.BN(0);
+    this.index = 0;
+  }
+
+  public async init() {
+    this.creator = await MockWallet.createWithBalance(this.provider, 1);
+    this.metadata = {
+      name: TEST_METADATA.name,
+      symbol: TEST_METADATA.symbol,
+      uri: TEST_METADATA.uri,
+      sellerFeeBasisPoints: TEST_METADATA.sellerFeeBasisPoints,
+      primarySaleHappened: false,
+      isMutable: true,
+      editionNonce: null,
+      tokenStandard: 0,
+      collection: null,
+      uses: null,
+      tokenProgramVersion: 0,
+      creators: [
+        { address: this.creator.publicKey, verified: true, share: 100 },
+      ],
+    };
+
+    await this.createTree();
+    await this.mint();
+    await this.computeProof();
+  }
+
+  public async getTreeAuthority() {
+    return (
+      await PublicKey.findProgramAddress(
+        [this.merkleTree.publicKey.toBuffer()],
+        constants.BUBBLEGUM_PROGRAM_ID
+      )
+    )[0];
+  }
+
+  public async getAssetId() {
+    return (
+      await PublicKey.findProgramAddress(
+        [
+          Buffer.from("asset"),
+          this.merkleTree.publicKey.toBuffer(),
+          this.nonce.toArrayLike(Buffer, "le", 8),
+        ],
+        constants.BUBBLEGUM_PROGRAM_ID
+      )
+    )[0];
+  }
+
+  private async createTree() {
+    const lamports =
+      await this.provider.connection.getMinimumBalanceForRentExemption(
+        MERKLE_TREE_SIZE
+      );
+    const args = Buffer.alloc(9);
+    args.writeUInt32LE(MAX_DEPTH, 0);
+    args.writeUInt32LE(MAX_BUFFER_SIZE, 4);
+
+    const tx = new Transaction().add(
+      SystemProgram.createAccount({
+        fromPubkey: this.creator.publicKey,
+        newAccountPubkey: this.merkleTree.publicKey,
+        lamports,
+        space: MERKLE_TREE_SIZE,
+        programId: constants.ACCOUNT_COMPRESSION_PROGRAM_ID,
+      }),
+      new TransactionInstruction({
+        programId: constants.BUBBLEGUM_PROGRAM_ID,
+        keys: [
+          accountMeta(await this.getTreeAuthority(), false, true),
+          accountMeta(this.merkleTree.publicKey, false, true),
+          accountMeta(this.creator.publicKey, true, true),
+          accountMeta(this.creator.publicKey, true, false),
+          accountMeta(constants.NOOP_PROGRAM_ID, false, false),
+          accountMeta(constants.ACCOUNT_COMPRESSION_PROGRAM_ID, false, false),
+          accountMeta(SystemProgram.programId, false, false),
+        ],
+        data: Buffer.concat([Buffer.from(CREATE_TREE_DISCRIMINATOR), args]),
+      })
+    );
+
+    await this.provider.sendAndConfirm(tx, [
+      this.creator.payer,
+      this.merkleTree,
+    ]);
+  }
+
+  private async mint() {
+    const tx = new Transaction().add(
+      new TransactionInstruction({
+        programId: constants.BUBBLEGUM_PROGRAM_ID,
+        keys: [
+          accountMeta(await this.getTreeAuthority(), false, true),
+          accountMeta(this.owner, false, false),
+          accountMeta(this.owner, false, false),
+          accountMeta(this.merkleTree.publicKey, false, true),
+          accountMeta(this.creator.publicKey, true, true),
+          accountMeta(this.creator.publicKey, true, false),
+          accountMeta(constants.NOOP_PROGRAM_ID, false, false),
+          accountMeta(constants.ACCOUNT_COMPRESSION_PROGRAM_ID, false, false),
+          accountMeta(SystemProgram.programId, false, false),
+        ],
+        data: Buffer.concat([
+          Buffer.from(MINT_V1_DISCRIMINATOR),
+          serializeMetadata(this.metadata),
+        ]),
+      })
+    );
+
+    await this.provider.sendAndConfirm(tx, [this.creator.payer]);
+  }
+
+  // The tree holds only this leaf, so every sibling on its path is empty.
+  private async computeProof() {
+    const leaf = keccak(
+      Buffer.from([1]),
+      (await this.getAssetId()).toBuffer(),
+      this.owner.toBuffer(),
+      this.owner.toBuffer(),
+      this.nonce.toArrayLike(Buffer, "le", 8),
+      hashMetadata(this.metadata),
+      hashCreators(this.metadata.creators)
+    );
+
+    let node = leaf;
+    let empty = Buffer.alloc(32);
+    const proof: PublicKey[] = [];
+    for (let i = 0; i < MAX_DEPTH; i++) {
+      proof.push(new PublicKey(empty));
+      node = keccak(node, empty);
+      empty = keccak(empty, empty);
+    }
+
+    this._proof = proof;
+    this._root = node;
+  }
+
+  public static async create(provider: AnchorProvider, owner: PublicKey) {
+    const cnft = new MockCnft(provider, owner);
+    await cnft.init();
+    return cnft;
+  }
+}
@@ -0,0 +1,182 @@
+import * as anchor from "@project-serum/anchor";
+import { AnchorProvider, Program } from "@project-serum/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import { MerkleDistributor } from "../../target/types/merkle_distributor";
+import { MockGovernor } from "./governor";
+import { MockWallet } from "./wallet";
+import { DistributionTree } from "../utils/merkle";
+import * as constants from "../constants";
+
+export class MockDistributor {
+  provider: AnchorProvider;
+  program: Program<MerkleDistributor>;
+
+  private _address: PublicKey | undefined;
+  get address(): PublicKey {
+    if (this._address === undefined) {
+      throw new Error("address undefined");
+    }
+    return this._address;
+  }
+
+  base: Keypair;
+  governor: MockGovernor;
+  tree: DistributionTree;
+  payer: MockWallet;
+
+  constructor({ provider, governor, tree }: MockDistributorArgs) {
+    this.provider = provider;
+    this.program = anchor.workspace
+      .MerkleDistributor as Program<MerkleDistributor>;
+    this.base = Keypair.generate();
+    this.governor = governor;
+    this.tree = tree;
+  }
+
+  public async init() {
+    this.payer = await MockWallet.createWithBalance(this.provider, 1);
+    this._address = (await this.getDistributorAddress())[0];
+  }
+
+  private async createNewDistributorTx() {
+    return await this.program.methods
+      .newDistributor(
+        new anchor.BN(this.tree.claims.length),
+        this.tree.maxTotalClaim,
+        this.tree.root
+      )
+      .accounts({
+        payer: this.payer.publicKey,
+        base: this.base.publicKey,
+        distributor: this.address,
+        tokenVault: (await this.getTokenVaultAddress())[0],
+        tokenMint: this.governor.tokenMint.address,
+        locker: this.governor.locker,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .transaction();
+  }
+
+  private async createClaimTx(claimant: PublicKey, index: number) {
+    const claim = this.tree.claims[index];
+    const escrow = await this.getEscrowAddress(claimant);
+
+    return await this.program.methods
+      .claim(
+        new anchor.BN(index),
+        claim.amount,
+        claim.duration,
+        this.tree.proof(index)
+      )
+      .accounts({
+        payer: claimant,
+        claimant,
+        distributor: this.address,
+        tokenVault: (await this.getTokenVaultAddress())[0],
+        tokenMint: this.governor.tokenMint.address,
+        locker: this.governor.locker,
+        lockAuthority: (await this.getLockAuthority())[0],
+        escrow,
+        lockedTokens: await this.governor.tokenMint.getAssociatedTokenAddress(
+          escrow
+        ),
+        lockerProgram: this.governor.veHoneyProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .remainingAccounts([
+        {
+          pubkey: await this.governor.getWhitelistEntryAddress(
+            this.program.programId,
+            anchor.web3.SystemProgram.programId
+          ),
+          isSigner: false,
+          isWritable: true,
+        },
+      ])
+      .transaction();
+  }
+
+  public static async create(args: MockDistributorArgs) {
+    const distributor = new MockDistributor(args);
+    await distributor.init();
+    const tx = await distributor.createNewDistributorTx();
+    await distributor.provider.sendAndConfirm(
+      tx,
+      [distributor.payer.payer, distributor.base],
+      { skipPreflight: true }
+    );
+    await distributor.governor.tokenMint.mintToAddress(
+      (await distributor.getTokenVaultAddress())[0],
+      distributor.tree.maxTotalClaim
+    );
+    return distributor;
+  }
+
+  public async claim({ claimant, index }: ClaimArgs) {
+    const tx = await this.createClaimTx(claimant.publicKey, index);
+    return await this.provider.sendAndConfirm(tx, [claimant.payer], {
+      skipPreflight: true,
+    });
+  }
+
+  public async getDistributorAddress() {
+    return await PublicKey.findProgramAddress(
+      [Buffer.from(constants.DISTRIBUTOR_SEED), this.base.publicKey.toBuffer()],
+      this.program.programId
+    );
+  }
+
+  public async getTokenVaultAddress() {
+    return await PublicKey.findProgramAddress(
+      [Buffer.from(constants.TOKEN_VAULT_SEED), this.address.toBuffer()],
+      this.program.programId
+    );
+  }
+
+  public async getLockAuthority() {
+    return await PublicKey.findProgramAddress(
+      [
+        Buffer.from(constants.LOCK_AUTHORITY_SEED),
+        this.governor.locker.toBuffer(),
+      ],
+      this.program.programId
+    );
+  }
+
+  public async getEscrowAddress(owner: PublicKey) {
+    const [address] = await PublicKey.findProgramAddress(
+      [
+        Buffer.from(constants.ESCROW_SEED),
+        this.governor.locker.toBuffer(),
+        owner.toBuffer(),
+      ],
+      this.governor.veHoneyProgram.programId
+    );
+    return address;
+  }
+
+  public async fetch() {
+    return await this.program.account.merkleDistributor.fetch(this.address);
+  }
+}
+
+export type MockDistributorArgs = {
+  provider: AnchorProvider;
+  governor: MockGovernor;
+  tree: DistributionTree;
+};
+
+export type ClaimArgs = {
+  claimant: MockWallet;
+  index: number;
+};
@@ -53,9 +53,14 @@ export class MockGovernor {
     return this._governor;
   }
 
+  get rewardTreasuryMint(): MockMint {
+    return this.rewardMint ?? this.tokenMint;
+  }
+
   lockerBase: Keypair;
   tokenMint: MockMint;
   wlTokenMint: MockMint;
+  rewardMint: MockMint | undefined;
   lockerParams: LockerParams;
 
   governorBase: Keypair;
@@ -143,14 +148,14 @@ export class MockGovernor {
       .instruction();
   }
 
-  private async createInitTreasuryIx() {
+  private async createInitTreasuryIx(mint: MockMint) {
     return await this.veHoneyProgram.methods
       .initTreasury()
       .accounts({
         payer: this.wallet.publicKey,
         locker: this.locker,
-        treasury: await this.getTreasuryAddress(),
-        tokenMint: this.tokenMint.address,
+        treasury: await this.getTreasuryAddress(mint.address),
+        tokenMint: mint.address,
         governor: this.governor.governorKey,
         smartWallet: this.smartWallet.key,
         systemProgram: anchor.web3.SystemProgram.programId,
@@ -160,25 +165,43 @@ export class MockGovernor {
       .instruction();
   }
 
-  private async createApproveProgramLockPrivilegeIx() {
+  private async createApproveProgramLockPrivilegeIx(
+    params: WhitelistEntryParams,
+    executableId: PublicKey
+  ) {
     return await this.veHoneyProgram.methods
-      .approveProgramLockPrivilege()
+      .approveProgramLockPrivilege(params)
       .accounts({
         payer: this.wallet.publicKey,
         locker: await this.getLockerAddress(),
         whitelistEntry: await this.getWhitelistEntryAddress(
-          this.stakeProgram.programId,
+          executableId,
           anchor.web3.SystemProgram.programId
         ),
         governor: this.governor.governorKey,
         smartWallet: this.smartWallet.key,
-        executableId: this.stakeProgram.programId,
+        executableId,
         whitelistedOwner: anchor.web3.SystemProgram.programId,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
       .instruction();
   }
 
+  private async createSetProgramLockPrivilegeParamsIx(
+    whitelistEntry: PublicKey,
+    params: WhitelistEntryParams
+  ) {
+    return await this.veHoneyProgram.methods
+      .setProgramLockPrivilegeParams(params)
+      .accounts({
+        locker: this.locker,
+        whitelistEntry,
+        governor: this.governor.governorKey,
+        smartWallet: this.smartWallet.key,
+      })
+      .instruction();
+  }
+
   private async createRevokeProgramLockPrivilegeIx(whitelistEntry: PublicKey) {
     return await this.veHoneyProgram.methods
       .revokeProgramLockPrivilege()
@@ -192,9 +215,13 @@ export class MockGovernor {
       .instruction();
   }
 
-  private async createAddProofIx(address: PublicKey) {
+  private async createAddProofIx(
+    address: PublicKey,
+    proofType: number,
+    rewardParams: NftRewardParams | null
+  ) {
     return await this.veHoneyProgram.methods
-      .addProof(1)
+      .addProof(proofType, rewardParams)
       .accounts({
         payer: this.wallet.publicKey,
         locker: this.locker,
@@ -207,6 +234,23 @@ export class MockGovernor {
       .instruction();
   }
 
+  private async createAddMerkleProofIx(
+    root: number[],
+    rewardParams: NftRewardParams | null
+  ) {
+    return await this.veHoneyProgram.methods
+      .addMerkleProof(root, rewardParams)
+      .accounts({
+        payer: this.wallet.publicKey,
+        locker: this.locker,
+        proof: await this.getProofAddress(new PublicKey(root)),
+        governor: this.governor.governorKey,
+        smartWallet: this.smartWallet.key,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .instruction();
+  }
+
   private async createRemoveProofIx(address: PublicKey) {
     return await this.veHoneyProgram.methods
       .removeProof()
@@ -220,6 +264,203 @@ export class MockGovernor {
       .instruction();
   }
 
+  private async createSetRewardScheduleIx(schedule: NftRewardSchedule) {
+    return await this.veHoneyProgram.methods
+      .setRewardSchedule(schedule)
+      .accounts({
+        payer: this.wallet.publicKey,
+        locker: this.locker,
+        rewardSchedule: await this.getRewardScheduleAddress(),
+        governor: this.governor.governorKey,
+        smartWallet: this.smartWallet.key,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .instruction();
+  }
+
+  private async createMigrateReceiptIx(nftReceipt: PublicKey) {
+    return await this.veHoneyProgram.methods
+      .migrateReceipt()
+      .accounts({
+        payer: this.wallet.publicKey,
+        locker: this.locker,
+        nftReceipt,
+        governor: this.governor.governorKey,
+        smartWallet: this.smartWallet.key,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .instruction();
+  }
+
+  private async createRemoveRewardScheduleIx() {
+    return await this.veHoneyProgram.methods
+      .removeRewardSchedule()
+      .accounts({
+        locker: this.locker,
+        rewardSchedule: await this.getRewardScheduleAddress(),
+        fundsReceiver: this.wallet.publicKey,
+        governor: this.governor.governorKey,
+        smartWallet: this.smartWallet.key,
+      })
+      .instruction();
+  }
+
+  private async createSetVestTiersIx(tiers: VestTier[]) {
+    return await this.veHoneyProgram.methods
+      .setVestTiers(tiers)
+      .accounts({
+        payer: this.wallet.publicKey,
+        locker: this.locker,
+        vestTiers: await this.getVestTiersAddress(),
+        governor: this.governor.governorKey,
+        smartWallet: this.smartWallet.key,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .instruction();
+  }
+
+  private async createRemoveVestTiersIx() {
+    return await this.veHoneyProgram.methods
+      .removeVestTiers()
+      .accounts({
+        locker: this.locker,
+        vestTiers: await this.getVestTiersAddress(),
+        fundsReceiver: this.wallet.publicKey,
+        governor: this.governor.governorKey,
+        smartWallet: this.smartWallet.key,
+      })
+      .instruction();
+  }
+
+  private async createOpenCampaignIx(params: CampaignParams) {
+    return await this.veHoneyProgram.methods
+      .openCampaign(params)
+      .accounts({
+        payer: this.wallet.publicKey,
+        locker: this.locker,
+        campaign: await this.getCampaignAddress(),
+        treasury: await this.getTreasuryAddress(
+          this.rewardTreasuryMint.address
+        ),
+        governor: this.governor.governorKey,
+        smartWallet: this.smartWallet.key,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .instruction();
+  }
+
+  private async createSetCampaignStatusIx(
+    status: "pause" | "resume" | "close"
+  ) {
+    const methods = this.veHoneyProgram.methods;
+    const builder =
+      status === "pause"
+        ? methods.pauseCampaign()
+        : status === "resume"
+        ? methods.resumeCampaign()
+        : methods.closeCampaign();
+
+    return await builder
+      .accounts({
+        locker: this.locker,
+        campaign: await this.getCampaignAddress(),
+        governor: this.governor.governorKey,
+        smartWallet: this.smartWallet.key,
+      })
+      .instruction();
+  }
+
+  private async createRegisterWlOfferIx(
+    offerId: anchor.BN,
+    params: WlOfferParams
+  ) {
+    return await this.veHoneyProgram.methods
+      .registerWlOffer(offerId, params)
+      .accounts({
+        payer: this.wallet.publicKey,
+        locker: this.locker,
+        offer: await this.getWlOfferAddress(offerId),
+        governor: this.governor.governorKey,
+        smartWallet: this.smartWallet.key,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .instruction();
+  }
+
+  private async createSetWlOfferParamsIx(
+    offerId: anchor.BN,
+    params: WlOfferParams
+  ) {
+    return await this.veHoneyProgram.methods
+      .setWlOfferParams(params)
+      .accounts({
+        locker: this.locker,
+        offer: await this.getWlOfferAddress(offerId),
+        governor: this.governor.governorKey,
+        smartWallet: this.smartWallet.key,
+      })
+      .instruction();
+  }
+
+  private async createFundTreasuryTx(
+    funder: MockWallet,
+    amount: anchor.BN,
+    mint: MockMint
+  ) {
+    return await this.veHoneyProgram.methods
+      .fundTreasury(amount)
+      .accounts({
+        locker: this.locker,
+        treasury: await this.getTreasuryAddress(mint.address),
+        sourceTokens: await mint.getAssociatedTokenAddress(funder.publicKey),
+        sourceTokensAuthority: funder.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .transaction();
+  }
+
+  private async createWithdrawTreasuryTx(
+    authority: MockWallet,
+    destination: PublicKey,
+    amount: anchor.BN
+  ) {
+    return await this.veHoneyProgram.methods
+      .withdrawTreasury(amount)
+      .accounts({
+        locker: this.locker,
+        treasury: await this.getTreasuryAddress(),
+        destinationTokens: destination,
+        governor: this.governor.governorKey,
+        authority: authority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .transaction();
+  }
+
+  private async createSetTreasuryAuthorityIx(
+    treasuryAuthority: PublicKey | null
+  ) {
+    return await this.veHoneyProgram.methods
+      .setTreasuryAuthority(treasuryAuthority)
+      .accounts({
+        locker: this.locker,
+        governor: this.governor.governorKey,
+        smartWallet: this.smartWallet.key,
+      })
+      .instruction();
+  }
+
+  private async createSetRewardMintIx(rewardMint: PublicKey | null) {
+    return await this.veHoneyProgram.methods
+      .setRewardMint(rewardMint)
+      .accounts({
+        locker: this.locker,
+        governor: this.governor.governorKey,
+        smartWallet: this.smartWallet.key,
+      })
+      .instruction();
+  }
+
   private async createSetWlMintAuthorityTx() {
     return await this.veHoneyProgram.methods
       .setWlMintAuthority()
@@ -266,8 +507,23 @@ export class MockGovernor {
     });
   }
 
-  public async initTreasury() {
-    const ix = await this.createInitTreasuryIx();
+  public async initTreasury(mint: MockMint = this.tokenMint) {
+    const ix = await this.createInitTreasuryIx(mint);
+    return await this.executeTransactionBySmartWallet({
+      provider: this.governorSDK.provider,
+      smartWalletWrapper: this.smartWallet,
+      instructions: [ix],
+    });
+  }
+
+  public async approveProgramLockPrivilege(
+    params: WhitelistEntryParams = constants.DEFAULT_WHITELIST_ENTRY_PARAMS,
+    executableId: PublicKey = this.stakeProgram.programId
+  ) {
+    const ix = await this.createApproveProgramLockPrivilegeIx(
+      params,
+      executableId
+    );
     return await this.executeTransactionBySmartWallet({
       provider: this.governorSDK.provider,
       smartWalletWrapper: this.smartWallet,
@@ -275,8 +531,14 @@ export class MockGovernor {
     });
   }
 
-  public async approveProgramLockPrivilege() {
-    const ix = await this.createApproveProgramLockPrivilegeIx();
+  public async setProgramLockPrivilegeParams(
+    whitelistEntry: PublicKey,
+    params: WhitelistEntryParams
+  ) {
+    const ix = await this.createSetProgramLockPrivilegeParamsIx(
+      whitelistEntry,
+      params
+    );
     return await this.executeTransactionBySmartWallet({
       provider: this.governorSDK.provider,
       smartWalletWrapper: this.smartWallet,
@@ -293,8 +555,24 @@ export class MockGovernor {
     });
   }
 
-  public async addProof(address: PublicKey) {
-    const ix = await this.createAddProofIx(address);
+  public async addProof(
+    address: PublicKey,
+    proofType: number = constants.PROOF_TYPE_CREATOR,
+    rewardParams: NftRewardParams | null = null
+  ) {
+    const ix = await this.createAddProofIx(address, proofType, rewardParams);
+    return await this.executeTransactionBySmartWallet({
+      provider: this.governorSDK.provider,
+      smartWalletWrapper: this.smartWallet,
+      instructions: [ix],
+    });
+  }
+
+  public async addMerkleProof(
+    root: number[],
+    rewardParams: NftRewardParams | null = null
+  ) {
+    const ix = await this.createAddMerkleProofIx(root, rewardParams);
     return await this.executeTransactionBySmartWallet({
       provider: this.governorSDK.provider,
       smartWalletWrapper: this.smartWallet,
@@ -311,6 +589,142 @@ export class MockGovernor {
     });
   }
 
+  public async setRewardSchedule(schedule: NftRewardSchedule) {
+    const ix = await this.createSetRewardScheduleIx(schedule);
+    return await this.executeTransactionBySmartWallet({
+      provider: this.governorSDK.provider,
+      smartWalletWrapper: this.smartWallet,
+      instructions: [ix],
+    });
+  }
+
+  public async removeRewardSchedule() {
+    const ix = await this.createRemoveRewardScheduleIx();
+    return await this.executeTransactionBySmartWallet({
+      provider: this.governorSDK.provider,
+      smartWalletWrapper: this.smartWallet,
+      instructions: [ix],
+    });
+  }
+
+  public async setVestTiers(tiers: VestTier[]) {
+    const ix = await this.createSetVestTiersIx(tiers);
+    return await this.executeTransactionBySmartWallet({
+      provider: this.governorSDK.provider,
+      smartWalletWrapper: this.smartWallet,
+      instructions: [ix],
+    });
+  }
+
+  public async removeVestTiers() {
+    const ix = await this.createRemoveVestTiersIx();
+    return await this.executeTransactionBySmartWallet({
+      provider: this.governorSDK.provider,
+      smartWalletWrapper: this.smartWallet,
+      instructions: [ix],
+    });
+  }
+
+  public async openCampaign(params: CampaignParams) {
+    const ix = await this.createOpenCampaignIx(params);
+    return await this.executeTransactionBySmartWallet({
+      provider: this.governorSDK.provider,
+      smartWalletWrapper: this.smartWallet,
+      instructions: [ix],
+    });
+  }
+
+  public async pauseCampaign() {
+    const ix = await this.createSetCampaignStatusIx("pause");
+    return await this.executeTransactionBySmartWallet({
+      provider: this.governorSDK.provider,
+      smartWalletWrapper: this.smartWallet,
+      instructions: [ix],
+    });
+  }
+
+  public async resumeCampaign() {
+    const ix = await this.createSetCampaignStatusIx("resume");
+    return await this.executeTransactionBySmartWallet({
+      provider: this.governorSDK.provider,
+      smartWalletWrapper: this.smartWallet,
+      instructions: [ix],
+    });
+  }
+
+  public async closeCampaign() {
+    const ix = await this.createSetCampaignStatusIx("close");
+    return await this.executeTransactionBySmartWallet({
+      provider: this.governorSDK.provider,
+      smartWalletWrapper: this.smartWallet,
+      instructions: [ix],
+    });
+  }
+
+  public async registerWlOffer(offerId: anchor.BN, params: WlOfferParams) {
+    const ix = await this.createRegisterWlOfferIx(offerId, params);
+    return await this.executeTransactionBySmartWallet({
+      provider: this.governorSDK.provider,
+      smartWalletWrapper: this.smartWallet,
+      instructions: [ix],
+    });
+  }
+
+  public async setWlOfferParams(offerId: anchor.BN, params: WlOfferParams) {
+    const ix = await this.createSetWlOfferParamsIx(offerId, params);
+    return await this.executeTransactionBySmartWallet({
+      provider: this.governorSDK.provider,
+      smartWalletWrapper: this.smartWallet,
+      instructions: [ix],
+    });
+  }
+
+  public async fundTreasury(
+    funder: MockWallet,
+    amount: anchor.BN,
+    mint: MockMint = this.tokenMint
+  ) {
+    const tx = await this.createFundTreasuryTx(funder, amount, mint);
+    return await this.provider.sendAndConfirm(tx, [funder.payer], {
+      skipPreflight: true,
+    });
+  }
+
+  public async withdrawTreasury(
+    authority: MockWallet,
+    destination: PublicKey,
+    amount: anchor.BN
+  ) {
+    const tx = await this.createWithdrawTreasuryTx(
+      authority,
+      destination,
+      amount
+    );
+    return await this.provider.sendAndConfirm(tx, [authority.payer], {
+      skipPreflight: true,
+    });
+  }
+
+  public async setTreasuryAuthority(treasuryAuthority: PublicKey | null) {
+    const ix = await this.createSetTreasuryAuthorityIx(treasuryAuthority);
+    return await this.executeTransactionBySmartWallet({
+      provider: this.governorSDK.provider,
+      smartWalletWrapper: this.smartWallet,
+      instructions: [ix],
+    });
+  }
+
+  public async setRewardMint(rewardMint: MockMint | null) {
+    const ix = await this.createSetRewardMintIx(rewardMint?.address ?? null);
+    const sig = await this.executeTransactionBySmartWallet({
+      provider: this.governorSDK.provider,
+      smartWalletWrapper: this.smartWallet,
+      instructions: [ix],
+    });
+    this.rewardMint = rewardMint ?? undefined;
+    return sig;
+  }
+
   public async setWlMintAuthority() {
     const tx = await this.createSetWlMintAuthorityTx();
     const sig = await this.provider.sendAndConfirm(
@@ -330,6 +744,57 @@ export class MockGovernor {
     });
   }
 
+  public async migrateLocker() {
+    const tx = await this.veHoneyProgram.methods
+      .migrateLocker()
+      .accounts({
+        payer: this.wallet.publicKey,
+        locker: this.locker,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    return await this.provider.sendAndConfirm(tx, [this.wallet.payer], {
+      skipPreflight: true,
+    });
+  }
+
+  public async migrateWhitelistEntry(whitelistEntry: PublicKey) {
+    const tx = await this.veHoneyProgram.methods
+      .migrateWhitelistEntry()
+      .accounts({
+        payer: this.wallet.publicKey,
+        whitelistEntry,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    return await this.provider.sendAndConfirm(tx, [this.wallet.payer], {
+      skipPreflight: true,
+    });
+  }
+
+  public async migrateProof(address: PublicKey) {
+    const tx = await this.veHoneyProgram.methods
+      .migrateProof()
+      .accounts({
+        payer: this.wallet.publicKey,
+        proof: await this.getProofAddress(address),
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    return await this.provider.sendAndConfirm(tx, [this.wallet.payer], {
+      skipPreflight: true,
+    });
+  }
+
+  public async migrateReceipt(nftReceipt: PublicKey) {
+    const ix = await this.createMigrateReceiptIx(nftReceipt);
+    return await this.executeTransactionBySmartWallet({
+      provider: this.governorSDK.provider,
+      smartWalletWrapper: this.smartWallet,
+      instructions: [ix],
+    });
+  }
+
   public async fetchLocker() {
     return await this.veHoneyProgram.account.locker.fetchNullable(this.locker);
   }
@@ -351,6 +816,42 @@ export class MockGovernor {
     return await this.veHoneyProgram.account.proof.fetchNullable(proofAddress);
   }
 
+  public async fetchRewardSchedule() {
+    const rewardScheduleAddress = await this.getRewardScheduleAddress();
+
+    return await this.veHoneyProgram.account.rewardSchedule.fetchNullable(
+      rewardScheduleAddress
+    );
+  }
+
+  public async fetchVestTiers() {
+    return await this.veHoneyProgram.account.vestTiers.fetchNullable(
+      await this.getVestTiersAddress()
+    );
+  }
+
+  public async fetchCampaign() {
+    return await this.veHoneyProgram.account.campaign.fetchNullable(
+      await this.getCampaignAddress()
+    );
+  }
+
+  public async fetchBurnCounter(
+    seed: string,
+    campaignId: anchor.BN,
+    key: PublicKey
+  ) {
+    return await this.veHoneyProgram.account.burnCounter.fetchNullable(
+      await this.getBurnCounterAddress(seed, campaignId, key)
+    );
+  }
+
+  public async fetchWlOffer(offerId: anchor.BN) {
+    return await this.veHoneyProgram.account.wlOffer.fetchNullable(
+      await this.getWlOfferAddress(offerId)
+    );
+  }
+
   public async getLockerAddress() {
     const [address] = await PublicKey.findProgramAddress(
       [
@@ -362,12 +863,12 @@ export class MockGovernor {
     return address;
   }
 
-  public async getTreasuryAddress() {
+  public async getTreasuryAddress(mint: PublicKey = this.tokenMint.address) {
     const [address] = await PublicKey.findProgramAddress(
       [
         Buffer.from(constants.TREASURY_SEED),
         this.locker.toBuffer(),
-        this.tokenMint.address.toBuffer(),
+        mint.toBuffer(),
       ],
       this.veHoneyProgram.programId
     );
@@ -402,6 +903,59 @@ export class MockGovernor {
     return address;
   }
 
+  public async getRewardScheduleAddress() {
+    const [address] = await PublicKey.findProgramAddress(
+      [Buffer.from(constants.REWARD_SCHEDULE_SEED), this.locker.toBuffer()],
+      this.veHoneyProgram.programId
+    );
+    return address;
+  }
+
+  public async getVestTiersAddress() {
+    const [address] = await PublicKey.findProgramAddress(
+      [Buffer.from(constants.VEST_TIERS_SEED), this.locker.toBuffer()],
+      this.veHoneyProgram.programId
+    );
+    return address;
+  }
+
+  public async getCampaignAddress() {
+    const [address] = await PublicKey.findProgramAddress(
+      [Buffer.from(constants.CAMPAIGN_SEED), this.locker.toBuffer()],
+      this.veHoneyProgram.programId
+    );
+    return address;
+  }
+
+  public async getWlOfferAddress(offerId: anchor.BN) {
+    const [address] = await PublicKey.findProgramAddress(
+      [
+        Buffer.from(constants.WL_OFFER_SEED),
+        this.locker.toBuffer(),
+        offerId.toArrayLike(Buffer, "le", 8),
+      ],
+      this.veHoneyProgram.programId
+    );
+    return address;
+  }
+
+  public async getBurnCounterAddress(
+    seed: string,
+    campaignId: anchor.BN,
+    key: PublicKey
+  ) {
+    const [address] = await PublicKey.findProgramAddress(
+      [
+        Buffer.from(seed),
+        (await this.getCampaignAddress()).toBuffer(),
+        campaignId.toArrayLike(Buffer, "le", 8),
+        key.toBuffer(),
+      ],
+      this.veHoneyProgram.programId
+    );
+    return address;
+  }
+
   private async executeTransactionBySmartWallet({
     provider,
     smartWalletWrapper,
@@ -426,17 +980,17 @@ export class MockGovernor {
     return transactionKey;
   }
 
-  public async calcRewardAmountAt(at?: number) {
-    const locker = await this.fetchLocker();
+  public async calcRewardAmountAt(at?: number, params?: NftRewardParams) {
+    const rewardParams = params ?? (await this.fetchLocker()).params;
 
     let count = 0;
     let amount = new anchor.BN(0);
-    let amountPerUnit = locker.params.nftStakeBaseReward;
+    let amountPerUnit = rewardParams.nftStakeBaseReward;
 
-    let countAt = at ?? locker.params.nftStakeDurationCount;
+    let countAt = at ?? rewardParams.nftStakeDurationCount;
 
-    while (count < Math.min(countAt, locker.params.nftStakeDurationCount)) {
-      if (count >= locker.params.nftRewardHalvingStartsAt) {
+    while (count < Math.min(countAt, rewardParams.nftStakeDurationCount)) {
+      if (count >= rewardParams.nftRewardHalvingStartsAt) {
         amountPerUnit = amountPerUnit.divn(2);
       }
       amount = amount.add(amountPerUnit);
@@ -447,6 +1001,57 @@ export class MockGovernor {
   }
 }
 
+export type NftRewardParams = {
+  nftStakeDurationUnit: anchor.BN;
+  nftStakeBaseReward: anchor.BN;
+  nftStakeDurationCount: number;
+  nftRewardHalvingStartsAt: number;
+  wlTokenAmount: anchor.BN;
+};
+
+export type RewardSegment = {
+  periodCount: number;
+  rewardPerPeriod: anchor.BN;
+};
+
+export type NftRewardSchedule = {
+  linear: boolean;
+  segments: RewardSegment[];
+};
+
+export type VestTier = {
+  vestDuration: anchor.BN;
+  escrowDuration: anchor.BN;
+  rewardBps: number;
+};
+
+export type CampaignParams = {
+  startsAt: anchor.BN;
+  endsAt: anchor.BN;
+  rewardBudget: anchor.BN;
+  collectionBurnCap: anchor.BN;
+  escrowBurnCap: anchor.BN | null;
+};
+
+export type WlOfferKind =
+  | { allowlistSlot: { project: PublicKey } }
+  | {
+      tokenAllocation: {
+        mint: PublicKey;
+        amount: anchor.BN;
+        priceMint: PublicKey;
+        price: anchor.BN;
+      };
+    };
+
+export type WlOfferParams = {
+  kind: WlOfferKind;
+  wlCost: anchor.BN;
+  maxUnits: anchor.BN;
+  maxUnitsPerUser: anchor.BN;
+  expiresAt: anchor.BN;
+};
+
 export type LockerParams = {
   minStakeDuration: anchor.BN;
   maxStakeDuration: anchor.BN;
@@ -457,6 +1062,14 @@ export type LockerParams = {
   nftStakeBaseReward: anchor.BN;
   nftStakeDurationCount: number;
   nftRewardHalvingStartsAt: number;
+  nftCustodialRewardBps: number;
+};
+
+export type WhitelistEntryParams = {
+  maxLockedAmount: anchor.BN;
+  minDuration: anchor.BN;
+  maxDuration: anchor.BN;
+  expiresAt: anchor.BN;
 };
 
 export type GovernorParams = {
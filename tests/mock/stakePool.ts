@@ -223,6 +223,13 @@ export class MockStakePool {
     );
   }
 
+  public async getLockAuthority(locker: PublicKey) {
+    return await PublicKey.findProgramAddress(
+      [Buffer.from(constants.LOCK_AUTHORITY_SEED), locker.toBuffer()],
+      this.program.programId
+    );
+  }
+
   public async fetch() {
     return await this.program.account.poolInfo.fetch(this.address);
   }
@@ -19,7 +19,10 @@ import { MockWallet } from "./wallet";
 import * as constants from "../constants";
 import { MockGovernor } from "./governor";
 import { MockNFT } from "./nft";
+import { MockCnft } from "./cnft";
+import { MockCoreAsset, MockProgrammableNft } from "./asset";
 import { MockMint } from "./mint";
+import { MintAllowlistTree, MintMerkleProof } from "../utils/merkle";
 
 export class MockUser {
   provider: AnchorProvider;
@@ -70,6 +73,13 @@ export class MockUser {
     return undefined;
   }
 
+  get rewardMint(): MockMint | undefined {
+    if (this.governor) {
+      return this.governor.rewardTreasuryMint;
+    }
+    return undefined;
+  }
+
   get wlTokenMint(): MockMint | undefined {
     if (this.governor) {
       return this.governor.wlTokenMint;
@@ -191,7 +201,11 @@ export class MockUser {
       .transaction();
   }
 
-  private async createLockTx(amount: anchor.BN, duration: anchor.BN) {
+  private async createLockTx(
+    amount: anchor.BN,
+    duration: anchor.BN,
+    remainingAccounts: anchor.web3.AccountMeta[] = []
+  ) {
     let lockedTokens = await this.getLockedTokensAddress();
     let preInstruction: anchor.web3.TransactionInstruction | undefined =
       undefined;
@@ -221,7 +235,8 @@ export class MockUser {
         ),
         sourceTokensAuthority: this.wallet.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
-      });
+      })
+      .remainingAccounts(remainingAccounts);
 
     if (preInstruction) {
       txBuilder = txBuilder.preInstructions([preInstruction]);
@@ -230,6 +245,45 @@ export class MockUser {
     return await txBuilder.transaction();
   }
 
+  private async createLockForTx(
+    beneficiary: PublicKey,
+    amount: anchor.BN,
+    duration: anchor.BN,
+    remainingAccounts: anchor.web3.AccountMeta[] = []
+  ) {
+    const [escrow] = await PublicKey.findProgramAddress(
+      [
+        Buffer.from(constants.ESCROW_SEED),
+        this.governor.locker.toBuffer(),
+        beneficiary.toBuffer(),
+      ],
+      this.veHoneyProgram.programId
+    );
+
+    return await this.veHoneyProgram.methods
+      .lockFor(amount, duration)
+      .accounts({
+        payer: this.wallet.publicKey,
+        locker: this.governor.locker,
+        escrow,
+        escrowOwner: beneficiary,
+        lockedTokens: await this.governor.tokenMint.getAssociatedTokenAddress(
+          escrow
+        ),
+        tokenMint: this.governor.tokenMint.address,
+        funder: this.wallet.publicKey,
+        sourceTokens: await this.governor.tokenMint.getAssociatedTokenAddress(
+          this.wallet.publicKey
+        ),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .remainingAccounts(remainingAccounts)
+      .transaction();
+  }
+
   private async createVestTx(pTokenAmount: anchor.BN, duration: anchor.BN) {
     let lockedTokens = await this.tokenMint.getAssociatedTokenAddress(
       this.escrow
@@ -264,24 +318,22 @@ export class MockUser {
         tokenVault: (await this.poolInfo.getTokenVaultAddress())[0],
         authority: (await this.poolInfo.getVaultAuthority())[0],
         locker: this.governor.locker,
+        lockAuthority: (
+          await this.poolInfo.getLockAuthority(this.governor.locker)
+        )[0],
         escrow: this.escrow,
         lockedTokens: await this.getLockedTokensAddress(),
         lockerProgram: this.veHoneyProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .remainingAccounts([
-        {
-          pubkey: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
-          isSigner: false,
-          isWritable: false,
-        },
         {
           pubkey: await this.governor.getWhitelistEntryAddress(
             this.stakeProgram.programId,
             anchor.web3.SystemProgram.programId
           ),
           isSigner: false,
-          isWritable: false,
+          isWritable: true,
         },
       ]);
 
@@ -381,12 +433,20 @@ export class MockUser {
       .transaction();
   }
 
-  private async createLockNftTx({ duration, nft }: LockNftArgs) {
+  private async createLockNftTx({
+    duration,
+    nft,
+    toLedger,
+    allowlist,
+    vestTier,
+  }: LockNftArgs) {
     const creator = new PublicKey(
       nft.metadata.data.data.creators.at(0).address
     );
 
-    const proof = await this.governor.getProofAddress(creator);
+    const proof = await this.governor.getProofAddress(
+      allowlist ? new PublicKey(allowlist.root) : creator
+    );
     const nftMint = nft.mint.address;
     const nftMetadata = await Metadata.getPDA(nftMint);
     const nftEdition = await Edition.getPDA(nftMint);
@@ -418,25 +478,128 @@ export class MockUser {
       },
     ];
 
+    let collection: PublicKey | null = null;
     if (nft.metadata.data.collection && nft.metadata.data.collection.verified) {
+      collection = new PublicKey(nft.metadata.data.collection.key);
       remainingAccounts.push({
-        pubkey: new PublicKey(nft.metadata.data.collection.key),
+        pubkey: collection,
         isSigner: false,
         isWritable: true,
       });
     }
 
-    let lockedTokens = await this.getLockedTokensAddress();
+    let lockedTokens = await this.getRewardTokensAddress();
     let preInstruction: anchor.web3.TransactionInstruction[] = [];
 
     if (
-      (await this.tokenMint.tryGetAssociatedTokenAccount(this.escrow)) === null
+      (await this.rewardMint.tryGetAssociatedTokenAccount(this.escrow)) === null
     ) {
       preInstruction.push(
         Token.createAssociatedTokenAccountInstruction(
           ASSOCIATED_TOKEN_PROGRAM_ID,
           TOKEN_PROGRAM_ID,
-          this.tokenMint.address,
+          this.rewardMint.address,
+          lockedTokens,
+          this.escrow,
+          this.wallet.publicKey
+        )
+      );
+    }
+
+    let wlDestination = await this.getWLTokenAddress();
+
+    if (
+      (await this.wlTokenMint.tryGetAssociatedTokenAccount(
+        this.wallet.publicKey
+      )) === null
+    ) {
+      preInstruction.push(
+        Token.createAssociatedTokenAccountInstruction(
+          ASSOCIATED_TOKEN_PROGRAM_ID,
+          TOKEN_PROGRAM_ID,
+          this.wlTokenMint.address,
+          wlDestination,
+          this.wallet.publicKey,
+          this.wallet.publicKey
+        )
+      );
+    }
+
+    const escrowAccount = await this.fetchEscrow();
+    if (!escrowAccount) {
+      throw new Error("escrow undefined");
+    }
+
+    const accounts = {
+      payer: this.wallet.publicKey,
+      locker: this.governor.locker,
+      escrow: this.escrow,
+      escrowOwner: this.wallet.publicKey,
+      lockedTokens,
+      lockerTreasury: await this.governor.getTreasuryAddress(
+        this.rewardMint.address
+      ),
+      nftSource: await nft.mint.getAssociatedTokenAddress(
+        this.wallet.publicKey
+      ),
+      nftSourceAuthority: this.wallet.publicKey,
+      rewardSchedule: await this.governor.getRewardScheduleAddress(),
+      campaign: await this.governor.getCampaignAddress(),
+      ...(await this.getBurnCounterAddresses(collection ?? proof)),
+      wlTokenMint: this.wlTokenMint.address,
+      wlDestination,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    };
+
+    const txBuilder = toLedger
+      ? this.veHoneyProgram.methods.lockNftToLedger(duration).accounts({
+          ...accounts,
+          ledger: await this.getReceiptLedgerAddress(),
+        })
+      : this.veHoneyProgram.methods
+          .lockNft(
+            duration,
+            allowlist?.proof(nft.mint.address) ?? null,
+            vestTier ?? null
+          )
+          .accounts({
+            ...accounts,
+            receipt: await this.getReceiptAddress(escrowAccount.receiptCount),
+            vestTiers: await this.governor.getVestTiersAddress(),
+          });
+
+    return await txBuilder
+      .preInstructions([...preInstruction])
+      .remainingAccounts([...remainingAccounts])
+      .transaction();
+  }
+
+  private async createLockAssetTx({
+    duration,
+    proof,
+    nftSource,
+    burnAccounts,
+    collection,
+    merkleProof,
+  }: LockAssetArgs) {
+    const meta = (pubkey: PublicKey, isWritable: boolean) => ({
+      pubkey,
+      isSigner: false,
+      isWritable,
+    });
+
+    let lockedTokens = await this.getRewardTokensAddress();
+    let preInstruction: anchor.web3.TransactionInstruction[] = [];
+
+    if (
+      (await this.rewardMint.tryGetAssociatedTokenAccount(this.escrow)) === null
+    ) {
+      preInstruction.push(
+        Token.createAssociatedTokenAccountInstruction(
+          ASSOCIATED_TOKEN_PROGRAM_ID,
+          TOKEN_PROGRAM_ID,
+          this.rewardMint.address,
           lockedTokens,
           this.escrow,
           this.wallet.publicKey
@@ -469,135 +632,959 @@ export class MockUser {
     }
 
     return await this.veHoneyProgram.methods
-      .lockNft(duration)
+      .lockNft(duration, merkleProof ?? null, null)
       .accounts({
         payer: this.wallet.publicKey,
         locker: this.governor.locker,
         escrow: this.escrow,
         receipt: await this.getReceiptAddress(escrowAccount.receiptCount),
         escrowOwner: this.wallet.publicKey,
-        lockedTokens: await this.getLockedTokensAddress(),
-        lockerTreasury: await this.governor.getTreasuryAddress(),
-        nftSource: await nft.mint.getAssociatedTokenAddress(
-          this.wallet.publicKey
+        lockedTokens,
+        lockerTreasury: await this.governor.getTreasuryAddress(
+          this.rewardMint.address
         ),
+        nftSource,
         nftSourceAuthority: this.wallet.publicKey,
+        rewardSchedule: await this.governor.getRewardScheduleAddress(),
+        vestTiers: await this.governor.getVestTiersAddress(),
+        campaign: await this.governor.getCampaignAddress(),
+        ...(await this.getBurnCounterAddresses(collection ?? proof)),
         wlTokenMint: this.wlTokenMint.address,
         wlDestination,
         systemProgram: anchor.web3.SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .preInstructions([...preInstruction])
-      .remainingAccounts([...remainingAccounts])
+      .remainingAccounts([
+        meta(proof, false),
+        ...burnAccounts.map(([pubkey, isWritable]) => meta(pubkey, isWritable)),
+      ])
       .transaction();
   }
 
-  private async createClaimNftRewardTx(receiptId: anchor.BN) {
-    let destination = await this.tokenMint.getAssociatedTokenAddress(
-      this.wallet.publicKey
-    );
-    let preInstruction: anchor.web3.TransactionInstruction | undefined =
-      undefined;
+  private async createLockNftBatchTx({ duration, nfts }: LockNftBatchArgs) {
+    const remainingAccounts: anchor.web3.AccountMeta[] = [];
+    for (const nft of nfts) {
+      const creator = new PublicKey(
+        nft.metadata.data.data.creators.at(0).address
+      );
+      const nftMint = nft.mint.address;
+      const collection = nft.metadata.data.collection?.verified
+        ? new PublicKey(nft.metadata.data.collection.key)
+        : null;
+      remainingAccounts.push(
+        {
+          pubkey: await this.governor.getProofAddress(creator),
+          isSigner: false,
+          isWritable: false,
+        },
+        {
+          pubkey: await Metadata.getPDA(nftMint),
+          isSigner: false,
+          isWritable: true,
+        },
+        { pubkey: nftMint, isSigner: false, isWritable: true },
+        {
+          pubkey: await Edition.getPDA(nftMint),
+          isSigner: false,
+          isWritable: true,
+        },
+        {
+          pubkey: collection
+            ? await Metadata.getPDA(collection)
+            : MetadataProgram.PUBKEY,
+          isSigner: false,
+          isWritable: !!collection,
+        },
+        {
+          pubkey: (
+            await this.getBurnCounterAddresses(
+              collection ?? (await this.governor.getProofAddress(creator))
+            )
+          ).collectionBurns,
+          isSigner: false,
+          isWritable: true,
+        },
+        {
+          pubkey: await nft.mint.getAssociatedTokenAddress(
+            this.wallet.publicKey
+          ),
+          isSigner: false,
+          isWritable: true,
+        }
+      );
+    }
+
+    let lockedTokens = await this.getLockedTokensAddress();
+    let preInstruction: anchor.web3.TransactionInstruction[] = [];
 
     if (
-      (await this.tokenMint.tryGetAssociatedTokenAccount(
+      (await this.tokenMint.tryGetAssociatedTokenAccount(this.escrow)) === null
+    ) {
+      preInstruction.push(
+        Token.createAssociatedTokenAccountInstruction(
+          ASSOCIATED_TOKEN_PROGRAM_ID,
+          TOKEN_PROGRAM_ID,
+          this.tokenMint.address,
+          lockedTokens,
+          this.escrow,
+          this.wallet.publicKey
+        )
+      );
+    }
+
+    let wlDestination = await this.getWLTokenAddress();
+
+    if (
+      (await this.wlTokenMint.tryGetAssociatedTokenAccount(
         this.wallet.publicKey
       )) === null
     ) {
-      preInstruction = Token.createAssociatedTokenAccountInstruction(
-        ASSOCIATED_TOKEN_PROGRAM_ID,
-        TOKEN_PROGRAM_ID,
-        this.tokenMint.address,
-        destination,
-        this.wallet.publicKey,
-        this.wallet.publicKey
+      preInstruction.push(
+        Token.createAssociatedTokenAccountInstruction(
+          ASSOCIATED_TOKEN_PROGRAM_ID,
+          TOKEN_PROGRAM_ID,
+          this.wlTokenMint.address,
+          wlDestination,
+          this.wallet.publicKey,
+          this.wallet.publicKey
+        )
       );
     }
 
-    let txBuilder = this.veHoneyProgram.methods.claim().accounts({
-      locker: this.governor.locker,
-      escrow: this.escrow,
-      escrowOwner: this.wallet.publicKey,
-      lockedTokens: await this.getLockedTokensAddress(),
-      destinationTokens: destination,
-      nftReceipt: await this.getReceiptAddress(receiptId),
-      tokenProgram: TOKEN_PROGRAM_ID,
-    });
+    return await this.veHoneyProgram.methods
+      .lockNftBatch(duration)
+      .accounts({
+        payer: this.wallet.publicKey,
+        locker: this.governor.locker,
+        escrow: this.escrow,
+        ledger: await this.getReceiptLedgerAddress(),
+        escrowOwner: this.wallet.publicKey,
+        lockedTokens,
+        lockerTreasury: await this.governor.getTreasuryAddress(),
+        nftSourceAuthority: this.wallet.publicKey,
+        rewardSchedule: await this.governor.getRewardScheduleAddress(),
+        campaign: await this.governor.getCampaignAddress(),
+        escrowBurns: (
+          await this.getBurnCounterAddresses(PublicKey.default)
+        ).escrowBurns,
+        wlTokenMint: this.wlTokenMint.address,
+        wlDestination,
+        tokenMetadataProgram: MetadataProgram.PUBKEY,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .preInstructions([...preInstruction])
+      .remainingAccounts(remainingAccounts)
+      .transaction();
+  }
 
-    if (preInstruction) {
-      txBuilder = txBuilder.preInstructions([preInstruction]);
+  private async createLockCnftTx({ duration, cnft, vestTier }: LockCnftArgs) {
+    let lockedTokens = await this.getRewardTokensAddress();
+    let preInstruction: anchor.web3.TransactionInstruction[] = [];
+
+    if (
+      (await this.rewardMint.tryGetAssociatedTokenAccount(this.escrow)) === null
+    ) {
+      preInstruction.push(
+        Token.createAssociatedTokenAccountInstruction(
+          ASSOCIATED_TOKEN_PROGRAM_ID,
+          TOKEN_PROGRAM_ID,
+          this.rewardMint.address,
+          lockedTokens,
+          this.escrow,
+          this.wallet.publicKey
+        )
+      );
     }
 
-    return await txBuilder.transaction();
-  }
+    let wlDestination = await this.getWLTokenAddress();
+
+    if (
+      (await this.wlTokenMint.tryGetAssociatedTokenAccount(
+        this.wallet.publicKey
+      )) === null
+    ) {
+      preInstruction.push(
+        Token.createAssociatedTokenAccountInstruction(
+          ASSOCIATED_TOKEN_PROGRAM_ID,
+          TOKEN_PROGRAM_ID,
+          this.wlTokenMint.address,
+          wlDestination,
+          this.wallet.publicKey,
+          this.wallet.publicKey
+        )
+      );
+    }
+
+    const escrowAccount = await this.fetchEscrow();
+    if (!escrowAccount) {
+      throw new Error("escrow undefined");
+    }
+
+    const proof = await this.governor.getProofAddress(cnft.creator.publicKey);
 
-  private async createCloseNftReceiptIx(receiptId: anchor.BN) {
     return await this.veHoneyProgram.methods
-      .closeReceipt()
+      .lockCnft(
+        duration,
+        [...cnft.root],
+        cnft.nonce,
+        cnft.index,
+        cnft.metadata,
+        vestTier ?? null
+      )
       .accounts({
+        payer: this.wallet.publicKey,
         locker: this.governor.locker,
         escrow: this.escrow,
-        nftReceipt: await this.getReceiptAddress(receiptId),
+        receipt: await this.getReceiptAddress(escrowAccount.receiptCount),
         escrowOwner: this.wallet.publicKey,
-        fundsReceiver: this.wallet.publicKey,
+        lockedTokens,
+        lockerTreasury: await this.governor.getTreasuryAddress(
+          this.rewardMint.address
+        ),
+        proof,
+        leafOwner: this.wallet.publicKey,
+        leafDelegate: this.wallet.publicKey,
+        treeAuthority: await cnft.getTreeAuthority(),
+        merkleTree: cnft.merkleTree.publicKey,
+        rewardSchedule: await this.governor.getRewardScheduleAddress(),
+        vestTiers: await this.governor.getVestTiersAddress(),
+        campaign: await this.governor.getCampaignAddress(),
+        ...(await this.getBurnCounterAddresses(
+          cnft.metadata.collection?.verified
+            ? cnft.metadata.collection.key
+            : proof
+        )),
+        wlTokenMint: this.wlTokenMint.address,
+        wlDestination,
+        logWrapper: constants.NOOP_PROGRAM_ID,
+        compressionProgram: constants.ACCOUNT_COMPRESSION_PROGRAM_ID,
+        bubblegumProgram: constants.BUBBLEGUM_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
       })
-      .instruction();
+      .preInstructions([...preInstruction])
+      .remainingAccounts(
+        cnft.proof.map((pubkey) => ({
+          pubkey,
+          isSigner: false,
+          isWritable: false,
+        }))
+      )
+      .transaction();
   }
 
-  public async deposit({ amount, owner }: DepositArgs) {
-    const tx = await this.createDepositTx(amount, owner?.publicKey);
-    const sig = await this.provider.sendAndConfirm(
-      tx,
-      [owner?.payer, this.wallet.payer].filter((s) => s !== undefined),
-      {
-        skipPreflight: true,
-      }
+  private async createLockNftCustodialTx({
+    duration,
+    nft,
+    vestTier,
+  }: LockNftArgs) {
+    const creator = new PublicKey(
+      nft.metadata.data.data.creators.at(0).address
     );
-    return sig;
+    const nftMint = nft.mint.address;
+    const remainingAccounts = [
+      {
+        pubkey: await this.governor.getProofAddress(creator),
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: MetadataProgram.PUBKEY,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: await Metadata.getPDA(nftMint),
+        isSigner: false,
+        isWritable: false,
+      },
+    ];
+
+    let preInstruction: anchor.web3.TransactionInstruction[] = [];
+
+    if (
+      (await this.rewardMint.tryGetAssociatedTokenAccount(this.escrow)) === null
+    ) {
+      preInstruction.push(
+        Token.createAssociatedTokenAccountInstruction(
+          ASSOCIATED_TOKEN_PROGRAM_ID,
+          TOKEN_PROGRAM_ID,
+          this.rewardMint.address,
+          await this.getRewardTokensAddress(),
+          this.escrow,
+          this.wallet.publicKey
+        )
+      );
+    }
+
+    const escrowAccount = await this.fetchEscrow();
+    if (!escrowAccount) {
+      throw new Error("escrow undefined");
+    }
+
+    return await this.veHoneyProgram.methods
+      .lockNftCustodial(duration, vestTier ?? null)
+      .accounts({
+        payer: this.wallet.publicKey,
+        locker: this.governor.locker,
+        escrow: this.escrow,
+        receipt: await this.getReceiptAddress(escrowAccount.receiptCount),
+        escrowOwner: this.wallet.publicKey,
+        lockedTokens: await this.getRewardTokensAddress(),
+        lockerTreasury: await this.governor.getTreasuryAddress(
+          this.rewardMint.address
+        ),
+        nftMint,
+        nftSource: await nft.mint.getAssociatedTokenAddress(
+          this.wallet.publicKey
+        ),
+        nftSourceAuthority: this.wallet.publicKey,
+        rewardSchedule: await this.governor.getRewardScheduleAddress(),
+        vestTiers: await this.governor.getVestTiersAddress(),
+        campaign: await this.governor.getCampaignAddress(),
+        ...(await this.getBurnCounterAddresses(
+          nft.metadata.data.collection?.verified
+            ? new PublicKey(nft.metadata.data.collection.key)
+            : await this.governor.getProofAddress(creator)
+        )),
+        nftVault: await this.getNftVaultAddress(nftMint),
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .preInstructions([...preInstruction])
+      .remainingAccounts([...remainingAccounts])
+      .transaction();
+  }
+
+  private async createWithdrawNftTx(receiptId: anchor.BN, nft: MockNFT) {
+    return await this.veHoneyProgram.methods
+      .withdrawNft()
+      .accounts({
+        locker: this.governor.locker,
+        nftReceipt: await this.getReceiptAddress(receiptId),
+        receiptOwner: this.wallet.publicKey,
+        nftVault: await this.getNftVaultAddress(nft.mint.address),
+        nftDestination: await nft.mint.getAssociatedTokenAddress(
+          this.wallet.publicKey
+        ),
+        fundsReceiver: this.wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .transaction();
+  }
+
+  private async createClaimNftRewardTx(
+    receiptId: anchor.BN,
+    mint: MockMint,
+    authority: PublicKey
+  ) {
+    const destination = await mint.getAssociatedTokenAddress(
+      this.wallet.publicKey
+    );
+    const preInstructions: anchor.web3.TransactionInstruction[] = [];
+
+    if (
+      (await mint.tryGetAssociatedTokenAccount(
+        this.wallet.publicKey
+      )) === null
+    ) {
+      preInstructions.push(
+        Token.createAssociatedTokenAccountInstruction(
+          ASSOCIATED_TOKEN_PROGRAM_ID,
+          TOKEN_PROGRAM_ID,
+          mint.address,
+          destination,
+          this.wallet.publicKey,
+          authority
+        )
+      );
+    }
+
+    const claim = {
+      locker: this.governor.locker,
+      escrow: this.escrow,
+      escrowOwner: authority,
+      lockedTokens: await mint.getAssociatedTokenAddress(this.escrow),
+      destinationTokens: destination,
+      nftReceipt: await this.findReceiptAddress(receiptId),
+      tokenProgram: TOKEN_PROGRAM_ID,
+    };
+
+    if (authority.equals(this.wallet.publicKey)) {
+      return await this.veHoneyProgram.methods
+        .claim()
+        .accounts(claim)
+        .preInstructions(preInstructions)
+        .transaction();
+    }
+
+    return await this.veHoneyProgram.methods
+      .operatorClaim()
+      .accounts({
+        claim,
+        operatorApproval: await this.getOperatorApprovalAddress(authority),
+      })
+      .preInstructions(preInstructions)
+      .transaction();
+  }
+
+  private async createClaimAndRelockTx(
+    receiptId: anchor.BN,
+    duration: anchor.BN | null,
+    authority: PublicKey
+  ) {
+    return await this.veHoneyProgram.methods
+      .claimAndRelock(duration)
+      .accounts({
+        locker: this.governor.locker,
+        escrow: this.escrow,
+        escrowOwner: authority,
+        operatorApproval: await this.getOperatorApprovalAddress(authority),
+        nftReceipt: await this.getReceiptAddress(receiptId),
+      })
+      .transaction();
+  }
+
+  private async createExtendLockTx(
+    duration: anchor.BN,
+    authority: PublicKey,
+    remainingAccounts: anchor.web3.AccountMeta[]
+  ) {
+    return await this.veHoneyProgram.methods
+      .extendLock(duration)
+      .accounts({
+        locker: this.governor.locker,
+        escrow: this.escrow,
+        escrowOwner: authority,
+        operatorApproval: await this.getOperatorApprovalAddress(authority),
+      })
+      .remainingAccounts(remainingAccounts)
+      .transaction();
+  }
+
+  private async createApproveOperatorTx(
+    operator: PublicKey,
+    actions: number,
+    expiresAt: anchor.BN
+  ) {
+    return await this.veHoneyProgram.methods
+      .approveOperator(actions, expiresAt)
+      .accounts({
+        payer: this.wallet.publicKey,
+        escrow: this.escrow,
+        escrowOwner: this.wallet.publicKey,
+        operator,
+        operatorApproval: await this.getOperatorApprovalAddress(operator),
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+  }
+
+  private async createRevokeOperatorTx(operator: PublicKey) {
+    return await this.veHoneyProgram.methods
+      .revokeOperator()
+      .accounts({
+        escrow: this.escrow,
+        escrowOwner: this.wallet.publicKey,
+        operatorApproval: await this.getOperatorApprovalAddress(operator),
+        fundsReceiver: this.wallet.publicKey,
+      })
+      .transaction();
+  }
+
+  private async createProposeEscrowTransferTx(newOwner: PublicKey) {
+    return await this.veHoneyProgram.methods
+      .proposeEscrowTransfer()
+      .accounts({
+        payer: this.wallet.publicKey,
+        escrow: this.escrow,
+        escrowOwner: this.wallet.publicKey,
+        escrowTransfer: await this.getEscrowTransferAddress(),
+        newOwner,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+  }
+
+  private async createCancelEscrowTransferTx() {
+    return await this.veHoneyProgram.methods
+      .cancelEscrowTransfer()
+      .accounts({
+        escrow: this.escrow,
+        escrowOwner: this.wallet.publicKey,
+        escrowTransfer: await this.getEscrowTransferAddress(),
+        fundsReceiver: this.wallet.publicKey,
+      })
+      .transaction();
+  }
+
+  /**
+   * Accepts the transfer of the escrow of `prevOwner`, passing `receipts`, or
+   * every live receipt account of the escrow by default.
+   */
+  private async createAcceptEscrowTransferTx(
+    prevOwner: MockUser,
+    receipts?: PublicKey[]
+  ) {
+    receipts ??= (await prevOwner.fetchOwnedReceipts()).map(
+      (receipt) => receipt.publicKey
+    );
+    const remainingAccounts = receipts.map((pubkey) => ({
+      pubkey,
+      isSigner: false,
+      isWritable: true,
+    }));
+
+    const newLockedTokens = await this.getLockedTokensAddress();
+    const preInstructions: anchor.web3.TransactionInstruction[] = [];
+
+    if (
+      (await this.tokenMint.tryGetAssociatedTokenAccount(this.escrow)) === null
+    ) {
+      preInstructions.push(
+        Token.createAssociatedTokenAccountInstruction(
+          ASSOCIATED_TOKEN_PROGRAM_ID,
+          TOKEN_PROGRAM_ID,
+          this.tokenMint.address,
+          newLockedTokens,
+          this.escrow,
+          this.wallet.publicKey
+        )
+      );
+    }
+
+    const newRewardTokens = await this.getRewardTokensAddress();
+    if (
+      !this.rewardMint.address.equals(this.tokenMint.address) &&
+      (await this.rewardMint.tryGetAssociatedTokenAccount(this.escrow)) ===
+        null
+    ) {
+      preInstructions.push(
+        Token.createAssociatedTokenAccountInstruction(
+          ASSOCIATED_TOKEN_PROGRAM_ID,
+          TOKEN_PROGRAM_ID,
+          this.rewardMint.address,
+          newRewardTokens,
+          this.escrow,
+          this.wallet.publicKey
+        )
+      );
+    }
+
+    return await this.veHoneyProgram.methods
+      .acceptEscrowTransfer()
+      .accounts({
+        payer: this.wallet.publicKey,
+        locker: this.governor.locker,
+        escrow: prevOwner.escrow,
+        prevOwner: prevOwner.wallet.publicKey,
+        escrowTransfer: await prevOwner.getEscrowTransferAddress(),
+        newEscrow: this.escrow,
+        newOwner: this.wallet.publicKey,
+        lockedTokens: await prevOwner.getLockedTokensAddress(),
+        newLockedTokens,
+        rewardTokens: await prevOwner.getRewardTokensAddress(),
+        newRewardTokens,
+        ledger: await prevOwner.getReceiptLedgerAddress(),
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .remainingAccounts(remainingAccounts)
+      .preInstructions(preInstructions)
+      .transaction();
+  }
+
+  private async createRedeemWlTx(offerId: anchor.BN, units: anchor.BN) {
+    const offer = await this.governor.getWlOfferAddress(offerId);
+    return await this.veHoneyProgram.methods
+      .redeemWl(units)
+      .accounts({
+        payer: this.wallet.publicKey,
+        locker: this.governor.locker,
+        offer,
+        redemption: await this.getWlRedemptionAddress(offer),
+        owner: this.wallet.publicKey,
+        wlTokenMint: this.wlTokenMint.address,
+        wlSource: await this.getWLTokenAddress(),
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .transaction();
+  }
+
+  private async createClaimAllNftRewardsTx(
+    receiptIds: anchor.BN[],
+    closeEnded: boolean,
+    mint: MockMint
+  ) {
+    let destination = await mint.getAssociatedTokenAddress(
+      this.wallet.publicKey
+    );
+    let preInstruction: anchor.web3.TransactionInstruction | undefined =
+      undefined;
+
+    if (
+      (await mint.tryGetAssociatedTokenAccount(
+        this.wallet.publicKey
+      )) === null
+    ) {
+      preInstruction = Token.createAssociatedTokenAccountInstruction(
+        ASSOCIATED_TOKEN_PROGRAM_ID,
+        TOKEN_PROGRAM_ID,
+        mint.address,
+        destination,
+        this.wallet.publicKey,
+        this.wallet.publicKey
+      );
+    }
+
+    const receipts = await Promise.all(
+      receiptIds.map((receiptId) => this.getReceiptAddress(receiptId))
+    );
+
+    let txBuilder = this.veHoneyProgram.methods
+      .claimAll(closeEnded)
+      .accounts({
+        locker: this.governor.locker,
+        escrow: this.escrow,
+        escrowOwner: this.wallet.publicKey,
+        operatorApproval: await this.getOperatorApprovalAddress(
+          this.wallet.publicKey
+        ),
+        lockedTokens: await mint.getAssociatedTokenAddress(this.escrow),
+        destinationTokens: destination,
+        fundsReceiver: this.wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .remainingAccounts(
+        receipts.map((pubkey) => ({
+          pubkey,
+          isSigner: false,
+          isWritable: true,
+        }))
+      );
+
+    if (preInstruction) {
+      txBuilder = txBuilder.preInstructions([preInstruction]);
+    }
+
+    return await txBuilder.transaction();
+  }
+
+  private async createCloseNftReceiptIx(receiptId: anchor.BN) {
+    return await this.veHoneyProgram.methods
+      .closeReceipt()
+      .accounts({
+        locker: this.governor.locker,
+        escrow: this.escrow,
+        nftReceipt: await this.getReceiptAddress(receiptId),
+        escrowOwner: this.wallet.publicKey,
+        fundsReceiver: this.wallet.publicKey,
+      })
+      .instruction();
+  }
+
+  public async deposit({ amount, owner }: DepositArgs) {
+    const tx = await this.createDepositTx(amount, owner?.publicKey);
+    const sig = await this.provider.sendAndConfirm(
+      tx,
+      [owner?.payer, this.wallet.payer].filter((s) => s !== undefined),
+      {
+        skipPreflight: true,
+      }
+    );
+    return sig;
+  }
+
+  public async claim(args?: ClaimArgs) {
+    const tx = await this.createClaimTx(args?.owner?.publicKey);
+    const sig = await this.provider.sendAndConfirm(
+      tx,
+      [args?.owner?.payer, this.wallet.payer].filter((s) => s !== undefined),
+      { skipPreflight: true }
+    );
+    return sig;
+  }
+
+  public async initEscrow() {
+    const tx = await this.createInitEscrowTx();
+    const sig = await this.provider.sendAndConfirm(tx, [this.wallet.payer], {
+      skipPreflight: true,
+    });
+    return sig;
+  }
+
+  public async setVoteDelegate({ newDelegate, owner }: SetVoteDelegateArgs) {
+    const tx = await this.createSetVoteDelegateTx(
+      newDelegate.publicKey,
+      owner?.publicKey
+    );
+    const sig = await this.provider.sendAndConfirm(
+      tx,
+      [owner?.payer ?? this.wallet.payer],
+      { skipPreflight: true }
+    );
+    this._voteDelegate = newDelegate;
+    return sig;
+  }
+
+  public async lock({ amount, duration, remainingAccounts }: LockArgs) {
+    const tx = await this.createLockTx(amount, duration, remainingAccounts);
+    const sig = await this.provider.sendAndConfirm(tx, [this.wallet.payer], {
+      skipPreflight: true,
+    });
+    return sig;
+  }
+
+  public async lockFor({
+    beneficiary,
+    amount,
+    duration,
+    remainingAccounts,
+  }: LockForArgs) {
+    const tx = await this.createLockForTx(
+      beneficiary,
+      amount,
+      duration,
+      remainingAccounts
+    );
+    const sig = await this.provider.sendAndConfirm(tx, [this.wallet.payer], {
+      skipPreflight: true,
+    });
+    return sig;
+  }
+
+  public async lockNft(args: LockNftArgs) {
+    const tx = await this.createLockNftTx(args);
+    const sig = await this.provider.sendAndConfirm(tx, [this.wallet.payer], {
+      skipPreflight: true,
+    });
+    return sig;
+  }
+
+  public async lockProgrammableNft({
+    duration,
+    nft,
+    proof,
+    allowlist,
+  }: LockProgrammableNftArgs) {
+    const collection = nft.collection?.mint.address;
+    const tx = await this.createLockAssetTx({
+      duration,
+      proof,
+      nftSource: nft.token,
+      burnAccounts: [
+        [MetadataProgram.PUBKEY, false],
+        [nft.metadata, true],
+        [nft.mint.publicKey, true],
+        [nft.edition, true],
+        [nft.tokenRecord, true],
+        [anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY, false],
+        ...(collection
+          ? [
+              [await Metadata.getPDA(collection), true] as [
+                PublicKey,
+                boolean
+              ],
+            ]
+          : []),
+      ],
+      collection,
+      merkleProof: allowlist?.proof(nft.mint.publicKey),
+    });
+    const sig = await this.provider.sendAndConfirm(tx, [this.wallet.payer], {
+      skipPreflight: true,
+    });
+    return sig;
+  }
+
+  public async lockCoreAsset({ duration, asset, proof }: LockCoreAssetArgs) {
+    const collection = asset.collection?.publicKey;
+    const tx = await this.createLockAssetTx({
+      duration,
+      proof,
+      nftSource: asset.asset.publicKey,
+      burnAccounts: [
+        [constants.MPL_CORE_PROGRAM_ID, false],
+        ...(collection ? [[collection, true] as [PublicKey, boolean]] : []),
+      ],
+      collection,
+    });
+    const sig = await this.provider.sendAndConfirm(tx, [this.wallet.payer], {
+      skipPreflight: true,
+    });
+    return sig;
+  }
+
+  public async lockNftBatch({ duration, nfts }: LockNftBatchArgs) {
+    const tx = await this.createLockNftBatchTx({ duration, nfts });
+    const sig = await this.provider.sendAndConfirm(tx, [this.wallet.payer], {
+      skipPreflight: true,
+    });
+    return sig;
+  }
+
+  public async lockCnft(args: LockCnftArgs) {
+    const tx = await this.createLockCnftTx(args);
+    const sig = await this.provider.sendAndConfirm(tx, [this.wallet.payer], {
+      skipPreflight: true,
+    });
+    return sig;
+  }
+
+  public async lockNftCustodial(args: LockNftArgs) {
+    const tx = await this.createLockNftCustodialTx(args);
+    const sig = await this.provider.sendAndConfirm(tx, [this.wallet.payer], {
+      skipPreflight: true,
+    });
+    return sig;
   }
 
-  public async claim(args?: ClaimArgs) {
-    const tx = await this.createClaimTx(args?.owner?.publicKey);
-    const sig = await this.provider.sendAndConfirm(
-      tx,
-      [args?.owner?.payer, this.wallet.payer].filter((s) => s !== undefined),
-      { skipPreflight: true }
+  public async migrateEscrow(closedReceiptIds: anchor.BN[] = []) {
+    const closedReceipts = await Promise.all(
+      closedReceiptIds.map((receiptId) => this.getReceiptAddress(receiptId))
     );
+    const tx = await this.veHoneyProgram.methods
+      .migrateEscrow(closedReceiptIds)
+      .accounts({
+        payer: this.wallet.publicKey,
+        escrow: this.escrow,
+        escrowOwner: this.wallet.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .remainingAccounts(
+        closedReceipts.map((pubkey) => ({
+          pubkey,
+          isSigner: false,
+          isWritable: false,
+        }))
+      )
+      .transaction();
+    const sig = await this.provider.sendAndConfirm(tx, [this.wallet.payer], {
+      skipPreflight: true,
+    });
     return sig;
   }
 
-  public async initEscrow() {
-    const tx = await this.createInitEscrowTx();
+  public async initReceiptLedger() {
+    const tx = await this.veHoneyProgram.methods
+      .initReceiptLedger()
+      .accounts({
+        payer: this.wallet.publicKey,
+        locker: this.governor.locker,
+        escrow: this.escrow,
+        ledger: await this.getReceiptLedgerAddress(),
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
     const sig = await this.provider.sendAndConfirm(tx, [this.wallet.payer], {
       skipPreflight: true,
     });
     return sig;
   }
 
-  public async setVoteDelegate({ newDelegate, owner }: SetVoteDelegateArgs) {
-    const tx = await this.createSetVoteDelegateTx(
-      newDelegate.publicKey,
-      owner?.publicKey
-    );
-    const sig = await this.provider.sendAndConfirm(
-      tx,
-      [owner?.payer ?? this.wallet.payer],
-      { skipPreflight: true }
+  public async migrateReceiptToLedger(receiptId: anchor.BN) {
+    const tx = await this.veHoneyProgram.methods
+      .migrateReceiptToLedger()
+      .accounts({
+        payer: this.wallet.publicKey,
+        locker: this.governor.locker,
+        escrow: this.escrow,
+        ledger: await this.getReceiptLedgerAddress(),
+        escrowOwner: this.wallet.publicKey,
+        nftReceipt: await this.getReceiptAddress(receiptId),
+        fundsReceiver: this.wallet.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    const sig = await this.provider.sendAndConfirm(tx, [this.wallet.payer], {
+      skipPreflight: true,
+    });
+    return sig;
+  }
+
+  public async claimLedgerReceipt(index: number) {
+    const destination = await this.tokenMint.getAssociatedTokenAddress(
+      this.wallet.publicKey
     );
-    this._voteDelegate = newDelegate;
+    let txBuilder = this.veHoneyProgram.methods
+      .claimLedgerReceipt(new anchor.BN(index))
+      .accounts({
+        locker: this.governor.locker,
+        escrow: this.escrow,
+        escrowOwner: this.wallet.publicKey,
+        operatorApproval: await this.getOperatorApprovalAddress(
+          this.wallet.publicKey
+        ),
+        lockedTokens: await this.getLockedTokensAddress(),
+        destinationTokens: destination,
+        ledger: await this.getReceiptLedgerAddress(),
+        tokenProgram: TOKEN_PROGRAM_ID,
+      });
+
+    if (
+      (await this.tokenMint.tryGetAssociatedTokenAccount(
+        this.wallet.publicKey
+      )) === null
+    ) {
+      txBuilder = txBuilder.preInstructions([
+        Token.createAssociatedTokenAccountInstruction(
+          ASSOCIATED_TOKEN_PROGRAM_ID,
+          TOKEN_PROGRAM_ID,
+          this.tokenMint.address,
+          destination,
+          this.wallet.publicKey,
+          this.wallet.publicKey
+        ),
+      ]);
+    }
+
+    const tx = await txBuilder.transaction();
+    const sig = await this.provider.sendAndConfirm(tx, [this.wallet.payer], {
+      skipPreflight: true,
+    });
+    return sig;
+  }
+
+  public async closeLedgerReceipt(index: number) {
+    const tx = await this.veHoneyProgram.methods
+      .closeLedgerReceipt(new anchor.BN(index))
+      .accounts({
+        locker: this.governor.locker,
+        escrow: this.escrow,
+        ledger: await this.getReceiptLedgerAddress(),
+        escrowOwner: this.wallet.publicKey,
+        fundsReceiver: this.wallet.publicKey,
+      })
+      .transaction();
+    const sig = await this.provider.sendAndConfirm(tx, [this.wallet.payer], {
+      skipPreflight: true,
+    });
     return sig;
   }
 
-  public async lock({ amount, duration }: LockArgs) {
-    const tx = await this.createLockTx(amount, duration);
+  public async closeReceiptLedger() {
+    const tx = await this.veHoneyProgram.methods
+      .closeReceiptLedger()
+      .accounts({
+        locker: this.governor.locker,
+        escrow: this.escrow,
+        ledger: await this.getReceiptLedgerAddress(),
+        escrowOwner: this.wallet.publicKey,
+        fundsReceiver: this.wallet.publicKey,
+      })
+      .transaction();
     const sig = await this.provider.sendAndConfirm(tx, [this.wallet.payer], {
       skipPreflight: true,
     });
     return sig;
   }
 
-  public async lockNft({ duration, nft }: LockNftArgs) {
-    const tx = await this.createLockNftTx({ duration, nft });
+  public async withdrawNft({ receiptId, nft }: WithdrawNftArgs) {
+    const tx = await this.createWithdrawNftTx(receiptId, nft);
     const sig = await this.provider.sendAndConfirm(tx, [this.wallet.payer], {
       skipPreflight: true,
     });
@@ -636,8 +1623,122 @@ export class MockUser {
     return sig;
   }
 
-  public async claimNftReward(receiptId: anchor.BN) {
-    const tx = await this.createClaimNftRewardTx(receiptId);
+  /** Claims the reward of a receipt, signed by the owner or by `operator`. */
+  public async claimNftReward(
+    receiptId: anchor.BN,
+    mint: MockMint = this.tokenMint,
+    operator: MockWallet = this.wallet
+  ) {
+    const tx = await this.createClaimNftRewardTx(
+      receiptId,
+      mint,
+      operator.publicKey
+    );
+    const sig = await this.provider.sendAndConfirm(tx, [operator.payer], {
+      skipPreflight: true,
+    });
+    return sig;
+  }
+
+  /** Claims and relocks, signed by the owner or by `operator`. */
+  public async claimAndRelock(
+    receiptId: anchor.BN,
+    duration: anchor.BN | null = null,
+    operator: MockWallet = this.wallet
+  ) {
+    const tx = await this.createClaimAndRelockTx(
+      receiptId,
+      duration,
+      operator.publicKey
+    );
+    const sig = await this.provider.sendAndConfirm(tx, [operator.payer], {
+      skipPreflight: true,
+    });
+    return sig;
+  }
+
+  /** Extends the lock, signed by the owner or by `operator`. */
+  public async extendLock(
+    duration: anchor.BN,
+    operator: MockWallet = this.wallet,
+    remainingAccounts: anchor.web3.AccountMeta[] = []
+  ) {
+    const tx = await this.createExtendLockTx(
+      duration,
+      operator.publicKey,
+      remainingAccounts
+    );
+    const sig = await this.provider.sendAndConfirm(tx, [operator.payer], {
+      skipPreflight: true,
+    });
+    return sig;
+  }
+
+  public async approveOperator(
+    operator: PublicKey,
+    actions: number,
+    expiresAt: anchor.BN = new anchor.BN(0)
+  ) {
+    const tx = await this.createApproveOperatorTx(operator, actions, expiresAt);
+    const sig = await this.provider.sendAndConfirm(tx, [this.wallet.payer], {
+      skipPreflight: true,
+    });
+    return sig;
+  }
+
+  public async revokeOperator(operator: PublicKey) {
+    const tx = await this.createRevokeOperatorTx(operator);
+    const sig = await this.provider.sendAndConfirm(tx, [this.wallet.payer], {
+      skipPreflight: true,
+    });
+    return sig;
+  }
+
+  public async proposeEscrowTransfer(newOwner: PublicKey) {
+    const tx = await this.createProposeEscrowTransferTx(newOwner);
+    const sig = await this.provider.sendAndConfirm(tx, [this.wallet.payer], {
+      skipPreflight: true,
+    });
+    return sig;
+  }
+
+  public async cancelEscrowTransfer() {
+    const tx = await this.createCancelEscrowTransferTx();
+    const sig = await this.provider.sendAndConfirm(tx, [this.wallet.payer], {
+      skipPreflight: true,
+    });
+    return sig;
+  }
+
+  public async acceptEscrowTransfer(
+    prevOwner: MockUser,
+    receipts?: PublicKey[]
+  ) {
+    const tx = await this.createAcceptEscrowTransferTx(prevOwner, receipts);
+    const sig = await this.provider.sendAndConfirm(tx, [this.wallet.payer], {
+      skipPreflight: true,
+    });
+    return sig;
+  }
+
+  public async redeemWl(offerId: anchor.BN, units: anchor.BN) {
+    const tx = await this.createRedeemWlTx(offerId, units);
+    const sig = await this.provider.sendAndConfirm(tx, [this.wallet.payer], {
+      skipPreflight: true,
+    });
+    return sig;
+  }
+
+  public async claimAllNftRewards(
+    receiptIds: anchor.BN[],
+    closeEnded: boolean = false,
+    mint: MockMint = this.tokenMint
+  ) {
+    const tx = await this.createClaimAllNftRewardsTx(
+      receiptIds,
+      closeEnded,
+      mint
+    );
     const sig = await this.provider.sendAndConfirm(tx, [this.wallet.payer], {
       skipPreflight: true,
     });
@@ -694,6 +1795,80 @@ export class MockUser {
     return address;
   }
 
+  /**
+   * Burn counters of `collection` and of the escrow in the current campaign;
+   * they are ignored without a campaign.
+   */
+  public async getBurnCounterAddresses(collection: PublicKey) {
+    const campaignId =
+      (await this.governor.fetchCampaign())?.campaignId ?? new anchor.BN(0);
+
+    return {
+      collectionBurns: await this.governor.getBurnCounterAddress(
+        constants.COLLECTION_BURNS_SEED,
+        campaignId,
+        collection
+      ),
+      escrowBurns: await this.governor.getBurnCounterAddress(
+        constants.ESCROW_BURNS_SEED,
+        campaignId,
+        this.escrow
+      ),
+    };
+  }
+
+  public async getWlRedemptionAddress(offer: PublicKey) {
+    const [address] = await PublicKey.findProgramAddress(
+      [
+        Buffer.from(constants.WL_REDEMPTION_SEED),
+        offer.toBuffer(),
+        this.wallet.publicKey.toBuffer(),
+      ],
+      this.veHoneyProgram.programId
+    );
+    return address;
+  }
+
+  public async getOperatorApprovalAddress(operator: PublicKey) {
+    const [address] = await PublicKey.findProgramAddress(
+      [
+        Buffer.from(constants.OPERATOR_APPROVAL_SEED),
+        this.escrow.toBuffer(),
+        operator.toBuffer(),
+      ],
+      this.veHoneyProgram.programId
+    );
+    return address;
+  }
+
+  public async getEscrowTransferAddress() {
+    const [address] = await PublicKey.findProgramAddress(
+      [Buffer.from(constants.ESCROW_TRANSFER_SEED), this.escrow.toBuffer()],
+      this.veHoneyProgram.programId
+    );
+    return address;
+  }
+
+  public async getReceiptLedgerAddress() {
+    const [address] = await PublicKey.findProgramAddress(
+      [Buffer.from(constants.RECEIPT_LEDGER_SEED), this.escrow.toBuffer()],
+      this.veHoneyProgram.programId
+    );
+    return address;
+  }
+
+  public async getNftVaultAddress(nftMint: PublicKey) {
+    const [address] = await PublicKey.findProgramAddress(
+      [
+        Buffer.from(constants.NFT_VAULT_SEED),
+        this.governor.locker.toBuffer(),
+        nftMint.toBuffer(),
+      ],
+      this.veHoneyProgram.programId
+    );
+    return address;
+  }
+
   public async getLockedTokensAddress() {
     if (this.tokenMint) {
       return await this.tokenMint.getAssociatedTokenAddress(this.escrow);
@@ -701,6 +1876,13 @@ export class MockUser {
     return null;
   }
 
+  public async getRewardTokensAddress() {
+    if (this.rewardMint) {
+      return await this.rewardMint.getAssociatedTokenAddress(this.escrow);
+    }
+    return null;
+  }
+
   public async getWLTokenAddress() {
     if (this.wlTokenMint) {
       return await this.wlTokenMint.getAssociatedTokenAddress(
@@ -716,13 +1898,75 @@ export class MockUser {
     );
   }
 
+  public async fetchWlRedemption(offerId: anchor.BN) {
+    return await this.veHoneyProgram.account.wlRedemption.fetchNullable(
+      await this.getWlRedemptionAddress(
+        await this.governor.getWlOfferAddress(offerId)
+      )
+    );
+  }
+
   public async fetchEscrow() {
     return await this.veHoneyProgram.account.escrow.fetchNullable(this.escrow);
   }
 
+  public async fetchReceipt(receiptId: anchor.BN) {
+    return await this.veHoneyProgram.account.nftReceipt.fetchNullable(
+      await this.getReceiptAddress(receiptId)
+    );
+  }
+
   public async fetchReceipts() {
     return await this.veHoneyProgram.account.nftReceipt.all();
   }
+
+  /**
+   * Receipt accounts of this wallet in the locker, by receipt id; receipts
+   * taken over with an escrow keep the address of the owner that locked them.
+   */
+  public async fetchOwnedReceipts() {
+    const receipts = await this.veHoneyProgram.account.nftReceipt.all([
+      {
+        memcmp: { offset: 8 + 8 + 32, bytes: this.wallet.publicKey.toBase58() },
+      },
+    ]);
+    return receipts
+      .filter((receipt) => receipt.account.locker.equals(this.governor.locker))
+      .sort((a, b) => a.account.receiptId.cmp(b.account.receiptId));
+  }
+
+  /** Address of receipt `receiptId` of this wallet, wherever it was locked. */
+  public async findReceiptAddress(receiptId: anchor.BN) {
+    const receipt = (await this.fetchOwnedReceipts()).find((receipt) =>
+      receipt.account.receiptId.eq(receiptId)
+    );
+    return receipt?.publicKey ?? (await this.getReceiptAddress(receiptId));
+  }
+
+  public async fetchReceiptLedger() {
+    return await this.veHoneyProgram.account.receiptLedger.fetchNullable(
+      await this.getReceiptLedgerAddress()
+    );
+  }
+
+  // Records follow the header of the ledger, each laid out as its IDL type.
+  public async fetchLedgerReceipts() {
+    const info = await this.provider.connection.getAccountInfo(
+      await this.getReceiptLedgerAddress()
+    );
+    if (!info) {
+      return [];
+    }
+
+    const recordLen = 304;
+    const records = info.data.subarray(8 + 104);
+    return Array.from({ length: records.length / recordLen }, (_, i) =>
+      this.veHoneyProgram.coder.types.decode(
+        "LedgerReceipt",
+        records.subarray(i * recordLen, (i + 1) * recordLen)
+      )
+    );
+  }
 }
 
 export type MockUserArgs = {
@@ -748,9 +1992,55 @@ export type SetVoteDelegateArgs = {
 export type LockArgs = {
   amount: anchor.BN;
   duration: anchor.BN;
+  remainingAccounts?: anchor.web3.AccountMeta[];
+};
+
+export type LockForArgs = LockArgs & {
+  beneficiary: PublicKey;
 };
 
 export type LockNftArgs = {
   duration: anchor.BN;
   nft: MockNFT;
+  toLedger?: boolean;
+  allowlist?: MintAllowlistTree;
+  vestTier?: number;
+};
+
+type LockAssetArgs = {
+  duration: anchor.BN;
+  proof: PublicKey;
+  nftSource: PublicKey;
+  burnAccounts: [PublicKey, boolean][];
+  collection?: PublicKey;
+  merkleProof?: MintMerkleProof;
+};
+
+export type LockProgrammableNftArgs = {
+  duration: anchor.BN;
+  nft: MockProgrammableNft;
+  proof: PublicKey;
+  allowlist?: MintAllowlistTree;
+};
+
+export type LockCoreAssetArgs = {
+  duration: anchor.BN;
+  asset: MockCoreAsset;
+  proof: PublicKey;
+};
+
+export type LockNftBatchArgs = {
+  duration: anchor.BN;
+  nfts: MockNFT[];
+};
+
+export type LockCnftArgs = {
+  duration: anchor.BN;
+  cnft: MockCnft;
+  vestTier?: number;
+};
+
+export type WithdrawNftArgs = {
+  receiptId: anchor.BN;
+  nft: MockNFT;
 };
@@ -3,7 +3,11 @@ import { PublicKey } from "@solana/web3.js";
 import { assert } from "chai";
 
 import { StakePoolParams } from "../mock/stakePool";
-import { LockerParams } from "../mock/governor";
+import {
+  LockerParams,
+  NftRewardParams,
+  WhitelistEntryParams,
+} from "../mock/governor";
 
 export type CheckStakePoolArgs = {
   poolInfo: any;
@@ -50,6 +54,9 @@ export type CheckWhitelistEntryArgs = {
   locker: PublicKey;
   programId: PublicKey;
   owner: PublicKey;
+  params?: WhitelistEntryParams;
+  lockedAmount?: anchor.BN;
+  lockCount?: anchor.BN;
 };
 
 export type CheckEscrowArgs = {
@@ -78,6 +85,10 @@ export type CheckNftReceiptArgs = {
   locker: PublicKey;
   owner: PublicKey;
   claimedAmount: anchor.BN;
+  nftMint?: PublicKey;
+  custodial?: boolean;
+  withdrawn?: boolean;
+  rewardParams?: NftRewardParams;
 };
 
 export function checkStakePool({
@@ -173,6 +184,11 @@ export function checkLocker({
     params.proposalActivationMinVotes,
     "params.proposalActivationMinVotes"
   );
+  assert.strictEqual(
+    account.params.nftCustodialRewardBps,
+    params.nftCustodialRewardBps,
+    "params.nftCustodialRewardBps"
+  );
 }
 
 export function checkWhitelistEntry({
@@ -180,10 +196,37 @@ export function checkWhitelistEntry({
   locker,
   programId,
   owner,
+  params,
+  lockedAmount,
+  lockCount,
 }: CheckWhitelistEntryArgs) {
   checkPublicKey(account.locker, locker, "locker");
   checkPublicKey(account.programId, programId, "programId");
   checkPublicKey(account.owner, owner, "owner");
+  if (params) {
+    checkBN(
+      account.params.maxLockedAmount,
+      params.maxLockedAmount,
+      "params.maxLockedAmount"
+    );
+    checkBN(
+      account.params.minDuration,
+      params.minDuration,
+      "params.minDuration"
+    );
+    checkBN(
+      account.params.maxDuration,
+      params.maxDuration,
+      "params.maxDuration"
+    );
+    checkBN(account.params.expiresAt, params.expiresAt, "params.expiresAt");
+  }
+  if (lockedAmount) {
+    checkBN(account.lockedAmount, lockedAmount, "lockedAmount");
+  }
+  if (lockCount) {
+    checkBN(account.lockCount, lockCount, "lockCount");
+  }
 }
 
 export function checkEscrow({
@@ -224,11 +267,51 @@ export function checkNftReceipt({
   locker,
   owner,
   claimedAmount,
+  nftMint,
+  custodial,
+  withdrawn,
+  rewardParams,
 }: CheckNftReceiptArgs) {
   checkBN(account.receiptId, receiptId, "receiptId");
   checkPublicKey(account.locker, locker, "locker");
   checkPublicKey(account.owner, owner, "owner");
   checkBN(account.claimedAmount, claimedAmount, "claimedAmount");
+  if (nftMint) {
+    checkPublicKey(account.nftMint, nftMint, "nftMint");
+  }
+  if (custodial !== undefined) {
+    assert.strictEqual(account.custodial, custodial, "custodial");
+  }
+  if (withdrawn !== undefined) {
+    assert.strictEqual(account.withdrawn, withdrawn, "withdrawn");
+  }
+  if (rewardParams) {
+    checkBN(
+      account.rewardParams.nftStakeDurationUnit,
+      rewardParams.nftStakeDurationUnit,
+      "rewardParams.nftStakeDurationUnit"
+    );
+    checkBN(
+      account.rewardParams.nftStakeBaseReward,
+      rewardParams.nftStakeBaseReward,
+      "rewardParams.nftStakeBaseReward"
+    );
+    assert.strictEqual(
+      account.rewardParams.nftStakeDurationCount,
+      rewardParams.nftStakeDurationCount,
+      "rewardParams.nftStakeDurationCount"
+    );
+    assert.strictEqual(
+      account.rewardParams.nftRewardHalvingStartsAt,
+      rewardParams.nftRewardHalvingStartsAt,
+      "rewardParams.nftRewardHalvingStartsAt"
+    );
+    checkBN(
+      account.rewardParams.wlTokenAmount,
+      rewardParams.wlTokenAmount,
+      "rewardParams.wlTokenAmount"
+    );
+  }
 }
 
 export function checkPublicKey(
@@ -0,0 +1,121 @@
+import * as anchor from "@project-serum/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { createHash } from "crypto";
+
+export type DistributionClaim = {
+  claimant: PublicKey;
+  amount: anchor.BN;
+  duration: anchor.BN;
+};
+
+function sha256(...chunks: Buffer[]): Buffer {
+  const hash = createHash("sha256");
+  chunks.forEach((chunk) => hash.update(chunk));
+  return hash.digest();
+}
+
+export function leafHash(index: number, claim: DistributionClaim): Buffer {
+  return sha256(
+    new anchor.BN(index).toArrayLike(Buffer, "le", 8),
+    claim.claimant.toBuffer(),
+    claim.amount.toArrayLike(Buffer, "le", 8),
+    claim.duration.toTwos(64).toArrayLike(Buffer, "le", 8)
+  );
+}
+
+function nodeHash(a: Buffer, b: Buffer): Buffer {
+  return Buffer.compare(a, b) <= 0 ? sha256(a, b) : sha256(b, a);
+}
+
+/**
+ * Tree of leaf hashes, hashed the same way as the programs and
+ * `tools/merkle_tree` verify proofs.
+ */
+export class MerkleTree {
+  layers: Buffer[][];
+
+  constructor(leaves: Buffer[]) {
+    this.layers = [leaves];
+    while (this.layers[this.layers.length - 1].length > 1) {
+      const layer = this.layers[this.layers.length - 1];
+      const next: Buffer[] = [];
+      for (let i = 0; i < layer.length; i += 2) {
+        next.push(
+          i + 1 < layer.length ? nodeHash(layer[i], layer[i + 1]) : layer[i]
+        );
+      }
+      this.layers.push(next);
+    }
+  }
+
+  get root(): number[] {
+    return [...this.layers[this.layers.length - 1][0]];
+  }
+
+  public proof(index: number): number[][] {
+    const proof: number[][] = [];
+    for (const layer of this.layers.slice(0, -1)) {
+      const sibling = layer[index ^ 1];
+      if (sibling) {
+        proof.push([...sibling]);
+      }
+      index = Math.floor(index / 2);
+    }
+    return proof;
+  }
+}
+
+export class DistributionTree extends MerkleTree {
+  claims: DistributionClaim[];
+
+  constructor(claims: DistributionClaim[]) {
+    super(claims.map((claim, index) => leafHash(index, claim)));
+    this.claims = claims;
+  }
+
+  get maxTotalClaim(): anchor.BN {
+    return this.claims.reduce(
+      (total, claim) => total.add(claim.amount),
+      new anchor.BN(0)
+    );
+  }
+}
+
+export type MintMerkleProof = {
+  weightBps: number;
+  path: number[][];
+};
+
+export function mintLeafHash(mint: PublicKey, weightBps: number): Buffer {
+  return sha256(
+    mint.toBuffer(),
+    new anchor.BN(weightBps).toArrayLike(Buffer, "le", 2)
+  );
+}
+
+export class MintAllowlistTree {
+  leaves: { mint: PublicKey; weightBps: number }[];
+  tree: MerkleTree;
+
+  constructor(leaves: { mint: PublicKey; weightBps?: number }[]) {
+    this.leaves = leaves.map(({ mint, weightBps }) => ({
+      mint,
+      weightBps: weightBps ?? 10_000,
+    }));
+    this.tree = new MerkleTree(
+      this.leaves.map(({ mint, weightBps }) => mintLeafHash(mint, weightBps))
+    );
+  }
+
+  get root(): number[] {
+    return this.tree.root;
+  }
+
+  public proof(mint: PublicKey): MintMerkleProof {
+    const index = this.leaves.findIndex((leaf) => leaf.mint.equals(mint));
+    return {
+      weightBps: this.leaves[index].weightBps,
+      path: this.tree.proof(index),
+    };
+  }
+}
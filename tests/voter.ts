@@ -10,13 +10,18 @@ import * as constants from "./constants";
 import { MockUser } from "./mock/user";
 import { MockGovernor } from "./mock/governor";
 import {
+  checkBN,
   checkEscrow,
   checkLocker,
   checkNftReceipt,
   checkTokenAccount,
+  checkWhitelistEntry,
 } from "./utils/check";
 import { sleep } from "./utils/util";
 import { MockNFT } from "./mock/nft";
+import { MintAllowlistTree } from "./utils/merkle";
+import { MockCnft } from "./mock/cnft";
+import { MockCoreAsset, MockProgrammableNft } from "./mock/asset";
 
 describe("locked voters", () => {
   const provider = AnchorProvider.env();
@@ -89,6 +94,23 @@ describe("locked voters", () => {
     });
   });
 
+  it("accounts created in the current layout need no migration", async () => {
+    const migrateLockerWithFail = governor.migrateLocker();
+    await expect(migrateLockerWithFail).to.eventually.be.rejectedWith(
+      'failed ({"err":{"InstructionError":[0,{"Custom":7134}]}})'
+    );
+
+    const user = await MockUser.create({
+      provider,
+      poolInfo: stakePool,
+      governor,
+    });
+    const migrateEscrowWithFail = user.migrateEscrow();
+    await expect(migrateEscrowWithFail).to.eventually.be.rejectedWith(
+      'failed ({"err":{"InstructionError":[0,{"Custom":7134}]}})'
+    );
+  });
+
   it("invalid escrow owner cannot change vote delegate", async () => {
     const user = await MockUser.create({
       provider,
@@ -158,6 +180,93 @@ describe("locked voters", () => {
     });
   });
 
+  it("direct-lock works with the instructions sysvar while whitelistEnabled is set", async () => {
+    await governor.setLockerParams({
+      ...constants.DEFAULT_LOCKER_PARAMS,
+      whitelistEnabled: true,
+    });
+    const user = await MockUser.create({
+      provider,
+      poolInfo: stakePool,
+      governor,
+    });
+    const lockAmount = new anchor.BN(10_000_000);
+    await tokenMint.mintTo(user.wallet, lockAmount);
+
+    const lockWithFail = user.lock({
+      amount: lockAmount,
+      duration: new anchor.BN(4),
+    });
+
+    await expect(lockWithFail).to.eventually.be.rejectedWith(
+      'failed ({"err":{"InstructionError":[1,{"Custom":7200}]}})'
+    );
+
+    await user.lock({
+      amount: lockAmount,
+      duration: new anchor.BN(4),
+      remainingAccounts: [
+        {
+          pubkey: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+          isSigner: false,
+          isWritable: false,
+        },
+      ],
+    });
+
+    const lockedTokensAccount = await tokenMint.tryGetAssociatedTokenAccount(
+      user.escrow
+    );
+
+    checkTokenAccount({
+      account: lockedTokensAccount,
+      mint: tokenMint.address,
+      amount: lockAmount,
+    });
+
+    const extendWithFail = user.extendLock(new anchor.BN(5));
+
+    await expect(extendWithFail).to.eventually.be.rejectedWith(
+      'failed ({"err":{"InstructionError":[0,{"Custom":7200}]}})'
+    );
+
+    await user.extendLock(new anchor.BN(5), user.wallet, [
+      {
+        pubkey: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        isSigner: false,
+        isWritable: false,
+      },
+    ]);
+
+    // direct calls may still pass a whitelist entry after the sysvar
+    await tokenMint.mintTo(user.wallet, lockAmount);
+    await user.lock({
+      amount: lockAmount,
+      duration: new anchor.BN(5),
+      remainingAccounts: [
+        {
+          pubkey: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+          isSigner: false,
+          isWritable: false,
+        },
+        {
+          pubkey: await governor.getWhitelistEntryAddress(
+            governor.veHoneyProgram.programId,
+            anchor.web3.SystemProgram.programId
+          ),
+          isSigner: false,
+          isWritable: false,
+        },
+      ],
+    });
+
+    checkTokenAccount({
+      account: await tokenMint.tryGetAssociatedTokenAccount(user.escrow),
+      mint: tokenMint.address,
+      amount: lockAmount.muln(2),
+    });
+  });
+
   it("duration must be in range from min-max stake duration", async () => {
     const minStakeDuration = new anchor.BN(5);
     const maxStakeDuration = new anchor.BN(15);
@@ -226,6 +335,77 @@ describe("locked voters", () => {
     );
   });
 
+  it("funder can lock for a beneficiary without an escrow", async () => {
+    const minStakeDuration = new anchor.BN(10);
+    const maxStakeDuration = new anchor.BN(100);
+    await governor.setLockerParams({
+      ...constants.DEFAULT_LOCKER_PARAMS,
+      whitelistEnabled: false,
+      minStakeDuration,
+      maxStakeDuration,
+    });
+    const funder = await MockUser.create({
+      provider,
+      poolInfo: stakePool,
+      governor,
+    });
+    const beneficiary = await MockWallet.createWithBalance(provider, 1);
+    const lockAmount = new anchor.BN(10_000_000);
+    await tokenMint.mintTo(funder.wallet, lockAmount);
+
+    await funder.lockFor({
+      beneficiary: beneficiary.publicKey,
+      amount: lockAmount.divn(2),
+      duration: new anchor.BN(30),
+    });
+
+    const [escrow] = await anchor.web3.PublicKey.findProgramAddress(
+      [
+        Buffer.from(constants.ESCROW_SEED),
+        governor.locker.toBuffer(),
+        beneficiary.publicKey.toBuffer(),
+      ],
+      governor.veHoneyProgram.programId
+    );
+    const escrowAccount =
+      await governor.veHoneyProgram.account.escrow.fetch(escrow);
+    assert.strictEqual(
+      escrowAccount.owner.toBase58(),
+      beneficiary.publicKey.toBase58()
+    );
+    assert.strictEqual(
+      escrowAccount.voteDelegate.toBase58(),
+      beneficiary.publicKey.toBase58()
+    );
+    checkTokenAccount({
+      account: await tokenMint.tryGetAssociatedTokenAccount(escrow),
+      mint: tokenMint.address,
+      amount: lockAmount.divn(2),
+    });
+
+    await sleep(3000);
+
+    const shortenWithFail = funder.lockFor({
+      beneficiary: beneficiary.publicKey,
+      amount: lockAmount.divn(2),
+      duration: new anchor.BN(10),
+    });
+    await expect(shortenWithFail).to.eventually.be.rejectedWith(
+      'failed ({"err":{"InstructionError":[0,{"Custom":7106}]}})'
+    );
+
+    await funder.lockFor({
+      beneficiary: beneficiary.publicKey,
+      amount: lockAmount.divn(2),
+      duration: new anchor.BN(60),
+    });
+    checkTokenAccount({
+      account: await tokenMint.tryGetAssociatedTokenAccount(escrow),
+      mint: tokenMint.address,
+      amount: lockAmount,
+    });
+  });
+
   it("escrow can be exited & closed", async () => {
     const minStakeDuration = new anchor.BN(1);
     const maxStakeDuration = new anchor.BN(5);
@@ -422,6 +602,56 @@ describe("locked voters", () => {
       amount: expectedTokenAmount,
     });
   });
+  it("vest respects the whitelist entry limits", async () => {
+    const whitelistEntry = await governor.getWhitelistEntryAddress(
+      governor.stakeProgram.programId,
+      anchor.web3.SystemProgram.programId
+    );
+    const whitelistEntryAccount = await governor.fetchWhitelistEntry();
+    const vestAmount = new anchor.BN(10_000_000);
+    const vestDuration = new anchor.BN(7_689_600);
+
+    await governor.setProgramLockPrivilegeParams(whitelistEntry, {
+      ...constants.DEFAULT_WHITELIST_ENTRY_PARAMS,
+      maxLockedAmount: whitelistEntryAccount.lockedAmount.add(vestAmount),
+    });
+
+    const user = await MockUser.create({
+      provider,
+      poolInfo: stakePool,
+      governor,
+    });
+    await pTokenMint.mintTo(user.wallet, vestAmount);
+    const vestWithFail = user.vest({
+      amount: vestAmount,
+      duration: vestDuration,
+    });
+
+    await expect(vestWithFail).to.eventually.be.rejectedWith(
+      'failed ({"err":{"InstructionError":[1,{"Custom":7206}]}})'
+    );
+
+    await governor.setProgramLockPrivilegeParams(
+      whitelistEntry,
+      constants.DEFAULT_WHITELIST_ENTRY_PARAMS
+    );
+    await user.vest({ amount: vestAmount, duration: vestDuration });
+
+    checkWhitelistEntry({
+      account: await governor.fetchWhitelistEntry(),
+      locker: governor.locker,
+      programId: governor.stakeProgram.programId,
+      owner: anchor.web3.SystemProgram.programId,
+      lockedAmount: whitelistEntryAccount.lockedAmount.add(vestAmount.muln(2)),
+      lockCount: whitelistEntryAccount.lockCount.addn(1),
+    });
+
+    // entries created with their limits need no migration
+    const migrateWithFail = governor.migrateWhitelistEntry(whitelistEntry);
+    await expect(migrateWithFail).to.eventually.be.rejectedWith(
+      'failed ({"err":{"InstructionError":[0,{"Custom":7134}]}})'
+    );
+  });
 });
 
 describe("NFT locked voter", () => {
@@ -492,8 +722,15 @@ describe("NFT locked voter", () => {
     await nft.mintTo(user.wallet, new anchor.BN(1));
     await nft.createMasterEdition();
 
-    await governor.addProof(
-      new anchor.web3.PublicKey(nft.metadata.data.data.creators.at(0).address)
+    const creator = new anchor.web3.PublicKey(
+      nft.metadata.data.data.creators.at(0).address
+    );
+    await governor.addProof(creator);
+
+    // proofs created with their reward terms need no migration
+    const migrateProofWithFail = governor.migrateProof(creator);
+    await expect(migrateProofWithFail).to.eventually.be.rejectedWith(
+      'failed ({"err":{"InstructionError":[0,{"Custom":7134}]}})'
     );
 
     const receiptId = 0;
@@ -572,13 +809,21 @@ describe("NFT locked voter", () => {
       owner: user.wallet.publicKey,
       claimedAmount: new anchor.BN(0),
     });
+    assert.ok(receipt.account.nftMint.equals(nft.mint.address));
+    assert.ok(
+      receipt.account.proof.equals(await governor.getProofAddress(creator))
+    );
+    assert.strictEqual(
+      receipt.account.proofType,
+      constants.PROOF_TYPE_CREATOR
+    );
+    assert.strictEqual(receipt.account.collection, null);
   });
 
-  it("NFT locked voter can claim rewards since 1 unit duration later", async () => {
-    let treasuryAmount = new anchor.BN(1_000_000_000_000);
+  it("user can lock NFT with the reward terms of its proof", async () => {
     await tokenMint.mintToAddress(
       await governor.getTreasuryAddress(),
-      treasuryAmount
+      new anchor.BN(1_000_000_000_000)
     );
     const user = await MockUser.create({
       provider,
@@ -587,76 +832,85 @@ describe("NFT locked voter", () => {
     await nft.mintTo(user.wallet, new anchor.BN(1));
     await nft.createMasterEdition();
 
+    const rewardParams = {
+      nftStakeDurationUnit: new anchor.BN(2),
+      nftStakeBaseReward: new anchor.BN(7_500_000_000),
+      nftStakeDurationCount: 10,
+      nftRewardHalvingStartsAt: 3,
+      wlTokenAmount: new anchor.BN(3),
+    };
     await governor.addProof(
-      new anchor.web3.PublicKey(nft.metadata.data.data.creators.at(0).address)
+      new anchor.web3.PublicKey(nft.metadata.data.data.creators.at(0).address),
+      constants.PROOF_TYPE_CREATOR,
+      rewardParams
     );
 
-    const receiptId = 0;
-
     await user.lockNft({
       duration: new anchor.BN(20),
       nft,
     });
 
-    await sleep(2000);
-
-    await user.claimNftReward(new anchor.BN(receiptId));
-
-    const [escrow, lockedTokens, userToken] = await Promise.all([
+    const rewardAmount = await governor.calcRewardAmountAt(
+      undefined,
+      rewardParams
+    );
+    const [escrow, userWlTokens] = await Promise.all([
       user.fetchEscrow(),
-      tokenMint.getAssociatedTokenAccount(user.escrow),
-      tokenMint.getAssociatedTokenAccount(user.wallet.publicKey),
+      wlTokenMint.getAssociatedTokenAccount(user.wallet.publicKey),
     ]);
 
-    const receipt = (await user.fetchReceipts()).find(
-      (r) =>
-        r.account.receiptId.eqn(receiptId) &&
-        r.account.owner.equals(user.wallet.publicKey)
-    );
-
-    const rewardAmount = await governor.calcRewardAmountAt();
-    const claimAmount = await governor.calcRewardAmountAt(1);
-    const remainingAmount = rewardAmount.sub(claimAmount);
-
-    checkEscrow({
-      account: escrow,
-      locker: governor.locker,
-      owner: user.wallet.publicKey,
-      tokens: await user.getLockedTokensAddress(),
-      amount: remainingAmount,
-      escrowStartedAt: escrow.escrowStartedAt,
-      escrowEndsAt: escrow.escrowEndsAt,
-      receiptCount: new anchor.BN(receiptId + 1),
-      amountToReceipt: remainingAmount,
-      voteDelegate: user.wallet.publicKey,
-    });
-
+    checkBN(escrow.amount, rewardAmount, "escrow.amount");
     checkTokenAccount({
-      account: lockedTokens,
-      mint: tokenMint.address,
-      amount: remainingAmount,
+      account: userWlTokens,
+      mint: wlTokenMint.address,
+      amount: new anchor.BN(3 * 10 ** 6),
     });
 
-    checkTokenAccount({
-      account: userToken,
-      mint: tokenMint.address,
-      amount: claimAmount,
+    // later changes of the locker params do not affect the receipt
+    await governor.setLockerParams({
+      ...constants.DEFAULT_LOCKER_PARAMS,
+      nftStakeBaseReward: new anchor.BN(1),
     });
 
+    const receipts = await user.fetchReceipts();
     checkNftReceipt({
-      account: receipt.account,
-      receiptId: new anchor.BN(receiptId),
+      account: receipts[0].account,
+      receiptId: new anchor.BN(0),
       locker: governor.locker,
       owner: user.wallet.publicKey,
-      claimedAmount: claimAmount,
+      claimedAmount: new anchor.BN(0),
+      rewardParams,
+    });
+
+    // only the smart wallet migrates receipts
+    const nftReceipt = await user.getReceiptAddress(new anchor.BN(0));
+    const tx = await governor.veHoneyProgram.methods
+      .migrateReceipt()
+      .accounts({
+        payer: user.wallet.publicKey,
+        locker: governor.locker,
+        nftReceipt,
+        governor: governor.governor.governorKey,
+        smartWallet: user.wallet.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    const migrateWithFail = provider.sendAndConfirm(tx, [user.wallet.payer], {
+      skipPreflight: true,
     });
+    await expect(migrateWithFail).to.eventually.be.rejectedWith(
+      'failed ({"err":{"InstructionError":[0,{"Custom":7301}]}})'
+    );
+
+    // receipts created with their reward terms need no migration
+    await expect(governor.migrateReceipt(nftReceipt)).to.eventually.be
+      .rejected;
   });
 
-  it("after claimed for all receipts, can close escrow", async () => {
-    let treasuryAmount = new anchor.BN(1_000_000_000_000);
+  it("user can lock NFT with the reward schedule of the locker", async () => {
     await tokenMint.mintToAddress(
       await governor.getTreasuryAddress(),
-      treasuryAmount
+      new anchor.BN(1_000_000_000_000)
     );
     const user = await MockUser.create({
       provider,
@@ -664,86 +918,1354 @@ describe("NFT locked voter", () => {
     });
     await nft.mintTo(user.wallet, new anchor.BN(1));
     await nft.createMasterEdition();
-
     await governor.addProof(
       new anchor.web3.PublicKey(nft.metadata.data.data.creators.at(0).address)
     );
 
-    const receiptId = 0;
+    const schedule = {
+      linear: false,
+      segments: [
+        { periodCount: 2, rewardPerPeriod: new anchor.BN(1_000) },
+        { periodCount: 3, rewardPerPeriod: new anchor.BN(500) },
+      ],
+    };
+    await governor.setRewardSchedule(schedule);
+
+    const rewardSchedule = await governor.fetchRewardSchedule();
+    assert.ok(rewardSchedule.locker.equals(governor.locker));
+    assert.strictEqual(rewardSchedule.schedule.segments.length, 2);
 
     await user.lockNft({
       duration: new anchor.BN(20),
       nft,
     });
 
-    await sleep(21000);
+    const escrow = await user.fetchEscrow();
+    checkBN(escrow.amount, new anchor.BN(3_500), "escrow.amount");
 
-    await user.claimNftReward(new anchor.BN(receiptId));
+    // later changes of the schedule do not affect the receipt
+    await governor.removeRewardSchedule();
+    assert.strictEqual(await governor.fetchRewardSchedule(), null);
 
-    let [escrow, lockedTokens, userToken] = await Promise.all([
-      user.fetchEscrow(),
-      tokenMint.getAssociatedTokenAccount(user.escrow),
-      tokenMint.getAssociatedTokenAccount(user.wallet.publicKey),
-    ]);
+    const receipts = await user.fetchReceipts();
+    const receipt = receipts[0].account;
+    assert.strictEqual(receipt.rewardSchedule.linear, false);
+    assert.strictEqual(receipt.rewardSchedule.segments.length, 2);
+    checkBN(
+      receipt.vestEndsAt.sub(receipt.vestStartedAt),
+      new anchor.BN(10),
+      "receipt.vestEndsAt"
+    );
+  });
 
-    const receipt = (await user.fetchReceipts()).find(
-      (r) =>
-        r.account.receiptId.eqn(receiptId) &&
-        r.account.owner.equals(user.wallet.publicKey)
+  it("user can lock NFT in custody and withdraw it at vest end", async () => {
+    await tokenMint.mintToAddress(
+      await governor.getTreasuryAddress(),
+      new anchor.BN(1_000_000_000_000)
     );
+    const user = await MockUser.create({
+      provider,
+      governor,
+    });
+    await nft.mintTo(user.wallet, new anchor.BN(1));
 
-    const rewardAmount = await governor.calcRewardAmountAt();
-    const claimAmount = rewardAmount;
-    const remainingAmount = new anchor.BN(0);
+    await governor.addProof(
+      new anchor.web3.PublicKey(nft.metadata.data.data.creators.at(0).address)
+    );
 
-    checkEscrow({
-      account: escrow,
-      locker: governor.locker,
-      owner: user.wallet.publicKey,
-      tokens: await user.getLockedTokensAddress(),
-      amount: remainingAmount,
-      escrowStartedAt: escrow.escrowStartedAt,
-      escrowEndsAt: escrow.escrowEndsAt,
-      receiptCount: new anchor.BN(receiptId + 1),
-      amountToReceipt: remainingAmount,
-      voteDelegate: user.wallet.publicKey,
+    const receiptId = new anchor.BN(0);
+
+    await user.lockNftCustodial({
+      duration: new anchor.BN(20),
+      nft,
     });
 
+    const rewardAmount = (await governor.calcRewardAmountAt())
+      .muln(constants.DEFAULT_LOCKER_PARAMS.nftCustodialRewardBps)
+      .divn(constants.MAX_BPS);
+    const [escrow, nftVault, userNft] = await Promise.all([
+      user.fetchEscrow(),
+      nft.mint.getTokenAccount(await user.getNftVaultAddress(nft.mint.address)),
+      nft.mint.getAssociatedTokenAccount(user.wallet.publicKey),
+    ]);
+
+    checkBN(escrow.amount, rewardAmount, "escrow.amount");
     checkTokenAccount({
-      account: lockedTokens,
-      mint: tokenMint.address,
-      amount: remainingAmount,
+      account: nftVault,
+      mint: nft.mint.address,
+      amount: new anchor.BN(1),
     });
-
     checkTokenAccount({
-      account: userToken,
-      mint: tokenMint.address,
-      amount: claimAmount,
+      account: userNft,
+      mint: nft.mint.address,
+      amount: new anchor.BN(0),
     });
 
+    const receipts = await user.fetchReceipts();
+    const receipt = receipts.find((r) => r.account.receiptId.eq(receiptId));
     checkNftReceipt({
       account: receipt.account,
-      receiptId: new anchor.BN(receiptId),
+      receiptId,
       locker: governor.locker,
       owner: user.wallet.publicKey,
-      claimedAmount: claimAmount,
+      claimedAmount: new anchor.BN(0),
+      nftMint: nft.mint.address,
+      custodial: true,
+      withdrawn: false,
     });
 
-    await user.closeReceipt(new anchor.BN(receiptId));
-    await user.closeEscrow();
+    const withdrawWithFail = user.withdrawNft({ receiptId, nft });
+    await expect(withdrawWithFail).to.eventually.be.rejectedWith(
+      'failed ({"err":{"InstructionError":[0,{"Custom":7110}]}})'
+    );
 
-    [escrow, lockedTokens] = await Promise.all([
-      user.fetchEscrow(),
-      tokenMint.tryGetAssociatedTokenAccount(user.escrow),
+    await sleep(21000);
+
+    await user.withdrawNft({ receiptId, nft });
+
+    checkTokenAccount({
+      account: await nft.mint.getAssociatedTokenAccount(user.wallet.publicKey),
+      mint: nft.mint.address,
+      amount: new anchor.BN(1),
+    });
+    assert.strictEqual(
+      await provider.connection.getAccountInfo(
+        await user.getNftVaultAddress(nft.mint.address)
+      ),
+      null
+    );
+  });
+
+  it("user can lock programmable NFT", async () => {
+    await tokenMint.mintToAddress(
+      await governor.getTreasuryAddress(),
+      new anchor.BN(1_000_000_000_000)
+    );
+    const user = await MockUser.create({
+      provider,
+      governor,
+    });
+    const pnft = await MockProgrammableNft.create(provider, user.wallet);
+    const mint = pnft.mint.publicKey;
+    await governor.addProof(mint, constants.PROOF_TYPE_MINT);
+
+    await user.lockProgrammableNft({
+      duration: new anchor.BN(20),
+      nft: pnft,
+      proof: await governor.getProofAddress(mint),
+    });
+
+    const escrow = await user.fetchEscrow();
+    checkBN(escrow.amount, await governor.calcRewardAmountAt(), "amount");
+
+    const receipts = await user.fetchReceipts();
+    assert.ok(receipts[0].account.nftMint.equals(mint));
+    assert.strictEqual(
+      receipts[0].account.proofType,
+      constants.PROOF_TYPE_MINT
+    );
+
+    // the token account and token record are closed by the burn
+    const [token, tokenRecord] = await Promise.all([
+      provider.connection.getAccountInfo(pnft.token),
+      provider.connection.getAccountInfo(pnft.tokenRecord),
     ]);
+    assert.strictEqual(token, null);
+    assert.strictEqual(tokenRecord, null);
+  });
 
-    assert.strictEqual(escrow, null);
-    assert.strictEqual(lockedTokens, null);
+  it("user can lock programmable NFT by its verified collection", async () => {
+    await tokenMint.mintToAddress(
+      await governor.getTreasuryAddress(),
+      new anchor.BN(1_000_000_000_000)
+    );
+    const user = await MockUser.create({
+      provider,
+      governor,
+    });
+    const collectionNft = await MockNFT.create(provider);
+    await collectionNft.createMasterEdition();
+    const collection = collectionNft.mint.address;
+    const pnft = await MockProgrammableNft.create(
+      provider,
+      user.wallet,
+      collectionNft
+    );
+    await governor.addProof(collection, constants.PROOF_TYPE_COLLECTION);
+
+    await user.lockProgrammableNft({
+      duration: new anchor.BN(20),
+      nft: pnft,
+      proof: await governor.getProofAddress(collection),
+    });
+
+    const receipts = await user.fetchReceipts();
+    assert.ok(receipts[0].account.nftMint.equals(pnft.mint.publicKey));
     assert.strictEqual(
-      (await user.fetchReceipts()).filter((r) =>
+      receipts[0].account.proofType,
+      constants.PROOF_TYPE_COLLECTION
+    );
+    assert.ok(receipts[0].account.collection.equals(collection));
+  });
+
+  it("user can lock programmable NFT allowlisted by a merkle root", async () => {
+    await tokenMint.mintToAddress(
+      await governor.getTreasuryAddress(),
+      new anchor.BN(1_000_000_000_000)
+    );
+    const user = await MockUser.create({
+      provider,
+      governor,
+    });
+    const collectionNft = await MockNFT.create(provider);
+    await collectionNft.createMasterEdition();
+    const pnft = await MockProgrammableNft.create(
+      provider,
+      user.wallet,
+      collectionNft
+    );
+
+    const allowlist = new MintAllowlistTree([
+      { mint: anchor.web3.Keypair.generate().publicKey },
+      { mint: pnft.mint.publicKey },
+    ]);
+    await governor.addMerkleProof(allowlist.root);
+
+    await user.lockProgrammableNft({
+      duration: new anchor.BN(20),
+      nft: pnft,
+      proof: await governor.getProofAddress(
+        new anchor.web3.PublicKey(allowlist.root)
+      ),
+      allowlist,
+    });
+
+    // the receipt keeps the collection of the NFT for campaign caps
+    const receipt = (await user.fetchReceipts())[0].account;
+    assert.strictEqual(receipt.proofType, constants.PROOF_TYPE_MERKLE);
+    assert.ok(receipt.collection.equals(collectionNft.mint.address));
+  });
+
+  it("user can lock Metaplex Core assets by address or collection", async () => {
+    await tokenMint.mintToAddress(
+      await governor.getTreasuryAddress(),
+      new anchor.BN(1_000_000_000_000)
+    );
+    const user = await MockUser.create({
+      provider,
+      governor,
+    });
+    const asset = await MockCoreAsset.create(provider, user.wallet, false);
+    const address = asset.asset.publicKey;
+
+    // an update authority set to an address is not a creator of the asset
+    await governor.addProof(asset.updateAuthority);
+    const lockWithFail = user.lockCoreAsset({
+      duration: new anchor.BN(20),
+      asset,
+      proof: await governor.getProofAddress(asset.updateAuthority),
+    });
+    await expect(lockWithFail).to.eventually.be.rejectedWith(
+      'failed ({"err":{"InstructionError":[2,{"Custom":7009}]}})'
+    );
+
+    await governor.addProof(address, constants.PROOF_TYPE_MINT);
+    await user.lockCoreAsset({
+      duration: new anchor.BN(20),
+      asset,
+      proof: await governor.getProofAddress(address),
+    });
+
+    const collected = await MockCoreAsset.create(provider, user.wallet, true);
+    const collection = collected.collection.publicKey;
+    await governor.addProof(collection, constants.PROOF_TYPE_COLLECTION);
+    await user.lockCoreAsset({
+      duration: new anchor.BN(20),
+      asset: collected,
+      proof: await governor.getProofAddress(collection),
+    });
+
+    const receipts = (await user.fetchReceipts()).sort((a, b) =>
+      a.account.receiptId.cmp(b.account.receiptId)
+    );
+    assert.ok(receipts[0].account.nftMint.equals(address));
+    assert.strictEqual(
+      receipts[0].account.proofType,
+      constants.PROOF_TYPE_MINT
+    );
+    assert.strictEqual(receipts[0].account.collection, null);
+    assert.ok(receipts[1].account.nftMint.equals(collected.asset.publicKey));
+    assert.strictEqual(
+      receipts[1].account.proofType,
+      constants.PROOF_TYPE_COLLECTION
+    );
+    assert.ok(receipts[1].account.collection.equals(collection));
+
+    // burnt assets are left without data
+    for (const burnt of [address, collected.asset.publicKey]) {
+      const info = await provider.connection.getAccountInfo(burnt);
+      assert.ok(info === null || info.data.length <= 1);
+    }
+  });
+
+  it("user can lock compressed NFT and earn WL tokens", async () => {
+    await tokenMint.mintToAddress(
+      await governor.getTreasuryAddress(),
+      new anchor.BN(1_000_000_000_000)
+    );
+    const user = await MockUser.create({
+      provider,
+      governor,
+    });
+    const cnft = await MockCnft.create(provider, user.wallet.publicKey);
+
+    const lockWithFail = user.lockCnft({
+      duration: new anchor.BN(20),
+      cnft,
+    });
+    await expect(lockWithFail).to.eventually.be.rejectedWith(
+      'failed ({"err":{"InstructionError":[0,{"Custom":7009}]}})'
+    );
+
+    await governor.addProof(cnft.creator.publicKey);
+
+    await user.lockCnft({
+      duration: new anchor.BN(20),
+      cnft,
+    });
+
+    const rewardAmount = await governor.calcRewardAmountAt();
+    const [escrow, userWlTokens] = await Promise.all([
+      user.fetchEscrow(),
+      wlTokenMint.getAssociatedTokenAccount(user.wallet.publicKey),
+    ]);
+
+    checkBN(escrow.amount, rewardAmount, "escrow.amount");
+    checkTokenAccount({
+      account: userWlTokens,
+      mint: wlTokenMint.address,
+      amount: new anchor.BN(1 * 10 ** 6),
+    });
+
+    const receipts = await user.fetchReceipts();
+    checkNftReceipt({
+      account: receipts[0].account,
+      receiptId: new anchor.BN(0),
+      locker: governor.locker,
+      owner: user.wallet.publicKey,
+      claimedAmount: new anchor.BN(0),
+      nftMint: await cnft.getAssetId(),
+      custodial: false,
+    });
+
+    // the leaf is burnt, so it cannot be locked twice
+    const relockWithFail = user.lockCnft({
+      duration: new anchor.BN(20),
+      cnft,
+    });
+    await expect(relockWithFail).to.eventually.be.rejected;
+  });
+
+  it("NFT locked voter can claim rewards since 1 unit duration later", async () => {
+    let treasuryAmount = new anchor.BN(1_000_000_000_000);
+    await tokenMint.mintToAddress(
+      await governor.getTreasuryAddress(),
+      treasuryAmount
+    );
+    const user = await MockUser.create({
+      provider,
+      governor,
+    });
+    await nft.mintTo(user.wallet, new anchor.BN(1));
+    await nft.createMasterEdition();
+
+    await governor.addProof(
+      new anchor.web3.PublicKey(nft.metadata.data.data.creators.at(0).address)
+    );
+
+    const receiptId = 0;
+
+    await user.lockNft({
+      duration: new anchor.BN(20),
+      nft,
+    });
+
+    await sleep(2000);
+
+    await user.claimNftReward(new anchor.BN(receiptId));
+
+    const [escrow, lockedTokens, userToken] = await Promise.all([
+      user.fetchEscrow(),
+      tokenMint.getAssociatedTokenAccount(user.escrow),
+      tokenMint.getAssociatedTokenAccount(user.wallet.publicKey),
+    ]);
+
+    const receipt = (await user.fetchReceipts()).find(
+      (r) =>
+        r.account.receiptId.eqn(receiptId) &&
         r.account.owner.equals(user.wallet.publicKey)
-      ).length,
-      0
+    );
+
+    const rewardAmount = await governor.calcRewardAmountAt();
+    const claimAmount = await governor.calcRewardAmountAt(1);
+    const remainingAmount = rewardAmount.sub(claimAmount);
+
+    checkEscrow({
+      account: escrow,
+      locker: governor.locker,
+      owner: user.wallet.publicKey,
+      tokens: await user.getLockedTokensAddress(),
+      amount: remainingAmount,
+      escrowStartedAt: escrow.escrowStartedAt,
+      escrowEndsAt: escrow.escrowEndsAt,
+      receiptCount: new anchor.BN(receiptId + 1),
+      amountToReceipt: remainingAmount,
+      voteDelegate: user.wallet.publicKey,
+    });
+
+    checkTokenAccount({
+      account: lockedTokens,
+      mint: tokenMint.address,
+      amount: remainingAmount,
+    });
+
+    checkTokenAccount({
+      account: userToken,
+      mint: tokenMint.address,
+      amount: claimAmount,
+    });
+
+    checkNftReceipt({
+      account: receipt.account,
+      receiptId: new anchor.BN(receiptId),
+      locker: governor.locker,
+      owner: user.wallet.publicKey,
+      claimedAmount: claimAmount,
+    });
+  });
+
+  it("user can claim NFT reward and relock it into the escrow", async () => {
+    await tokenMint.mintToAddress(
+      await governor.getTreasuryAddress(),
+      new anchor.BN(1_000_000_000_000)
+    );
+    const user = await MockUser.create({
+      provider,
+      governor,
+    });
+    await nft.mintTo(user.wallet, new anchor.BN(1));
+    await nft.createMasterEdition();
+    await governor.addProof(
+      new anchor.web3.PublicKey(nft.metadata.data.data.creators.at(0).address)
+    );
+
+    await user.lockNft({ duration: new anchor.BN(20), nft });
+    const lockedEscrow = await user.fetchEscrow();
+
+    await sleep(2000);
+
+    await user.claimAndRelock(new anchor.BN(0), new anchor.BN(4));
+
+    const [escrow, lockedTokens, receipts] = await Promise.all([
+      user.fetchEscrow(),
+      tokenMint.getAssociatedTokenAccount(user.escrow),
+      user.fetchReceipts(),
+    ]);
+
+    const rewardAmount = await governor.calcRewardAmountAt();
+    const claimAmount = await governor.calcRewardAmountAt(1);
+
+    checkBN(escrow.amount, rewardAmount, "escrow.amount");
+    checkBN(
+      escrow.amountToReceipt,
+      rewardAmount.sub(claimAmount),
+      "escrow.amountToReceipt"
+    );
+    assert.ok(escrow.escrowEndsAt.gt(lockedEscrow.escrowEndsAt));
+    checkBN(lockedTokens.amount, rewardAmount, "lockedTokens.amount");
+    checkBN(
+      receipts[0].account.claimedAmount,
+      claimAmount,
+      "receipt.claimedAmount"
+    );
+  });
+
+  it("after claimed for all receipts, can close escrow", async () => {
+    let treasuryAmount = new anchor.BN(1_000_000_000_000);
+    await tokenMint.mintToAddress(
+      await governor.getTreasuryAddress(),
+      treasuryAmount
+    );
+    const user = await MockUser.create({
+      provider,
+      governor,
+    });
+    await nft.mintTo(user.wallet, new anchor.BN(1));
+    await nft.createMasterEdition();
+
+    await governor.addProof(
+      new anchor.web3.PublicKey(nft.metadata.data.data.creators.at(0).address)
+    );
+
+    const receiptId = 0;
+
+    await user.lockNft({
+      duration: new anchor.BN(20),
+      nft,
+    });
+
+    await sleep(21000);
+
+    await user.claimNftReward(new anchor.BN(receiptId));
+
+    let [escrow, lockedTokens, userToken] = await Promise.all([
+      user.fetchEscrow(),
+      tokenMint.getAssociatedTokenAccount(user.escrow),
+      tokenMint.getAssociatedTokenAccount(user.wallet.publicKey),
+    ]);
+
+    const receipt = (await user.fetchReceipts()).find(
+      (r) =>
+        r.account.receiptId.eqn(receiptId) &&
+        r.account.owner.equals(user.wallet.publicKey)
+    );
+
+    const rewardAmount = await governor.calcRewardAmountAt();
+    const claimAmount = rewardAmount;
+    const remainingAmount = new anchor.BN(0);
+
+    checkEscrow({
+      account: escrow,
+      locker: governor.locker,
+      owner: user.wallet.publicKey,
+      tokens: await user.getLockedTokensAddress(),
+      amount: remainingAmount,
+      escrowStartedAt: escrow.escrowStartedAt,
+      escrowEndsAt: escrow.escrowEndsAt,
+      receiptCount: new anchor.BN(receiptId + 1),
+      amountToReceipt: remainingAmount,
+      voteDelegate: user.wallet.publicKey,
+    });
+
+    checkTokenAccount({
+      account: lockedTokens,
+      mint: tokenMint.address,
+      amount: remainingAmount,
+    });
+
+    checkTokenAccount({
+      account: userToken,
+      mint: tokenMint.address,
+      amount: claimAmount,
+    });
+
+    checkNftReceipt({
+      account: receipt.account,
+      receiptId: new anchor.BN(receiptId),
+      locker: governor.locker,
+      owner: user.wallet.publicKey,
+      claimedAmount: claimAmount,
+    });
+
+    await user.closeReceipt(new anchor.BN(receiptId));
+    await user.closeEscrow();
+
+    [escrow, lockedTokens] = await Promise.all([
+      user.fetchEscrow(),
+      tokenMint.tryGetAssociatedTokenAccount(user.escrow),
+    ]);
+
+    assert.strictEqual(escrow, null);
+    assert.strictEqual(lockedTokens, null);
+    assert.strictEqual(
+      (await user.fetchReceipts()).filter((r) =>
+        r.account.owner.equals(user.wallet.publicKey)
+      ).length,
+      0
+    );
+  });
+
+  it("user can claim all receipts at once and close the ended ones", async () => {
+    await tokenMint.mintToAddress(
+      await governor.getTreasuryAddress(),
+      new anchor.BN(1_000_000_000_000)
+    );
+    const user = await MockUser.create({
+      provider,
+      governor,
+    });
+    const otherNft = await MockNFT.create(provider);
+    for (const mockNft of [nft, otherNft]) {
+      await mockNft.mintTo(user.wallet, new anchor.BN(1));
+      await mockNft.createMasterEdition();
+      await governor.addProof(
+        new anchor.web3.PublicKey(
+          mockNft.metadata.data.data.creators.at(0).address
+        )
+      );
+      await user.lockNft({
+        duration: new anchor.BN(20),
+        nft: mockNft,
+      });
+    }
+
+    const receiptIds = [new anchor.BN(0), new anchor.BN(1)];
+
+    // the same receipt cannot be claimed twice in a call
+    const claimWithFail = user.claimAllNftRewards([
+      receiptIds[0],
+      receiptIds[0],
+    ]);
+    await expect(claimWithFail).to.eventually.be.rejectedWith(
+      '{"Custom":7116}'
+    );
+
+    await sleep(21000);
+
+    await user.claimAllNftRewards(receiptIds, true);
+
+    const rewardAmount = await governor.calcRewardAmountAt();
+    const [escrow, userToken] = await Promise.all([
+      user.fetchEscrow(),
+      tokenMint.getAssociatedTokenAccount(user.wallet.publicKey),
+    ]);
+
+    checkBN(escrow.amount, new anchor.BN(0), "escrow.amount");
+    checkTokenAccount({
+      account: userToken,
+      mint: tokenMint.address,
+      amount: rewardAmount.muln(2),
+    });
+    assert.strictEqual(
+      (await user.fetchReceipts()).filter((r) =>
+        r.account.owner.equals(user.wallet.publicKey)
+      ).length,
+      0
+    );
+  });
+
+  it("user can keep NFT receipts in a ledger of the escrow", async () => {
+    await tokenMint.mintToAddress(
+      await governor.getTreasuryAddress(),
+      new anchor.BN(1_000_000_000_000)
+    );
+    const user = await MockUser.create({
+      provider,
+      governor,
+    });
+    const otherNft = await MockNFT.create(provider);
+    for (const mockNft of [nft, otherNft]) {
+      await mockNft.mintTo(user.wallet, new anchor.BN(1));
+      await mockNft.createMasterEdition();
+      await governor.addProof(
+        new anchor.web3.PublicKey(
+          mockNft.metadata.data.data.creators.at(0).address
+        )
+      );
+    }
+
+    // a receipt account is folded into the ledger
+    await user.lockNft({ duration: new anchor.BN(20), nft });
+    await user.initReceiptLedger();
+    await user.migrateReceiptToLedger(new anchor.BN(0));
+    await user.lockNft({
+      duration: new anchor.BN(20),
+      nft: otherNft,
+      toLedger: true,
+    });
+
+    const ledger = await user.fetchReceiptLedger();
+    const records = await user.fetchLedgerReceipts();
+    checkBN(ledger.receiptCount, new anchor.BN(2), "ledger.receiptCount");
+    checkBN(records[0].receiptId, new anchor.BN(0), "records[0].receiptId");
+    checkBN(records[1].receiptId, new anchor.BN(1), "records[1].receiptId");
+    assert.strictEqual(
+      (await user.fetchReceipts()).filter((r) =>
+        r.account.owner.equals(user.wallet.publicKey)
+      ).length,
+      0
+    );
+
+    await sleep(21000);
+
+    await user.claimLedgerReceipt(0);
+    await user.claimLedgerReceipt(1);
+
+    const rewardAmount = await governor.calcRewardAmountAt();
+    const [escrow, userToken] = await Promise.all([
+      user.fetchEscrow(),
+      tokenMint.getAssociatedTokenAccount(user.wallet.publicKey),
+    ]);
+    checkBN(escrow.amount, new anchor.BN(0), "escrow.amount");
+    checkTokenAccount({
+      account: userToken,
+      mint: tokenMint.address,
+      amount: rewardAmount.muln(2),
+    });
+
+    // a non-empty ledger cannot be closed
+    await expect(user.closeReceiptLedger()).to.eventually.be.rejectedWith(
+      '{"Custom":7117}'
+    );
+
+    await user.closeLedgerReceipt(0);
+    await user.closeLedgerReceipt(0);
+    await user.closeReceiptLedger();
+
+    assert.strictEqual(await user.fetchReceiptLedger(), null);
+  });
+
+  it("user can lock several NFTs in one instruction", async () => {
+    await tokenMint.mintToAddress(
+      await governor.getTreasuryAddress(),
+      new anchor.BN(1_000_000_000_000)
+    );
+    const user = await MockUser.create({
+      provider,
+      governor,
+    });
+    const nfts = [nft, await MockNFT.create(provider)];
+    for (const mockNft of nfts) {
+      await mockNft.mintTo(user.wallet, new anchor.BN(1));
+      await mockNft.createMasterEdition();
+      await governor.addProof(
+        new anchor.web3.PublicKey(
+          mockNft.metadata.data.data.creators.at(0).address
+        )
+      );
+    }
+
+    await user.initReceiptLedger();
+    await user.lockNftBatch({ duration: new anchor.BN(20), nfts });
+
+    const rewardAmount = await governor.calcRewardAmountAt();
+    const [escrow, ledger, records, userWlTokens] = await Promise.all([
+      user.fetchEscrow(),
+      user.fetchReceiptLedger(),
+      user.fetchLedgerReceipts(),
+      wlTokenMint.getAssociatedTokenAccount(user.wallet.publicKey),
+    ]);
+
+    checkBN(escrow.amount, rewardAmount.muln(2), "escrow.amount");
+    checkBN(escrow.receiptCount, new anchor.BN(2), "escrow.receiptCount");
+    checkBN(ledger.receiptCount, new anchor.BN(2), "ledger.receiptCount");
+    records.forEach((record, i) => {
+      checkBN(record.receiptId, new anchor.BN(i), "record.receiptId");
+      assert.ok(record.nftMint.equals(nfts[i].mint.address));
+    });
+    checkTokenAccount({
+      account: userWlTokens,
+      mint: wlTokenMint.address,
+      amount: new anchor.BN(2 * 10 ** 6),
+    });
+  });
+
+  it("user can lock NFTs within the caps of a campaign", async () => {
+    await tokenMint.mintToAddress(
+      await governor.getTreasuryAddress(),
+      new anchor.BN(1_000_000_000_000)
+    );
+    const user = await MockUser.create({
+      provider,
+      governor,
+    });
+    const nfts = [nft, await MockNFT.create(provider)];
+    for (const mockNft of nfts) {
+      await mockNft.mintTo(user.wallet, new anchor.BN(1));
+      await mockNft.createMasterEdition();
+      await governor.addProof(
+        new anchor.web3.PublicKey(
+          mockNft.metadata.data.data.creators.at(0).address
+        )
+      );
+    }
+
+    const rewardAmount = await governor.calcRewardAmountAt();
+    const now = Math.floor(Date.now() / 1000);
+    await governor.openCampaign({
+      startsAt: new anchor.BN(now - 60),
+      endsAt: new anchor.BN(now + 3600),
+      rewardBudget: rewardAmount.muln(10),
+      collectionBurnCap: new anchor.BN(5),
+      escrowBurnCap: new anchor.BN(1),
+    });
+
+    await user.lockNft({ duration: new anchor.BN(20), nft: nfts[0] });
+
+    let campaign = await governor.fetchCampaign();
+    checkBN(campaign.campaignId, new anchor.BN(1), "campaign.campaignId");
+    checkBN(campaign.burnCount, new anchor.BN(1), "campaign.burnCount");
+    checkBN(campaign.rewardSpent, rewardAmount, "campaign.rewardSpent");
+
+    const escrowBurns = await governor.fetchBurnCounter(
+      constants.ESCROW_BURNS_SEED,
+      campaign.campaignId,
+      user.escrow
+    );
+    checkBN(escrowBurns.burnCount, new anchor.BN(1), "escrowBurns.burnCount");
+
+    const lockOverEscrowCap = user.lockNft({
+      duration: new anchor.BN(20),
+      nft: nfts[1],
+    });
+    await expect(lockOverEscrowCap).to.eventually.be.rejectedWith(
+      'failed ({"err":{"InstructionError":[0,{"Custom":7122}]}})'
+    );
+
+    // the other lock paths count against the campaign too
+    await user.initReceiptLedger();
+    const lockBatchOverEscrowCap = user.lockNftBatch({
+      duration: new anchor.BN(20),
+      nfts: [nfts[1]],
+    });
+    await expect(lockBatchOverEscrowCap).to.eventually.be.rejectedWith(
+      'failed ({"err":{"InstructionError":[0,{"Custom":7122}]}})'
+    );
+
+    await governor.pauseCampaign();
+    campaign = await governor.fetchCampaign();
+    assert.ok("paused" in campaign.status);
+
+    const lockWhilePaused = user.lockNft({
+      duration: new anchor.BN(20),
+      nft: nfts[1],
+    });
+    await expect(lockWhilePaused).to.eventually.be.rejectedWith(
+      'failed ({"err":{"InstructionError":[0,{"Custom":7118}]}})'
+    );
+
+    await governor.closeCampaign();
+    campaign = await governor.fetchCampaign();
+    assert.ok("closed" in campaign.status);
+
+    // a closed campaign no longer bounds the locks
+    await user.lockNft({ duration: new anchor.BN(20), nft: nfts[1] });
+
+    campaign = await governor.fetchCampaign();
+    checkBN(campaign.burnCount, new anchor.BN(1), "campaign.burnCount");
+  });
+
+  it("user can lock NFT allowlisted by a merkle root", async () => {
+    await tokenMint.mintToAddress(
+      await governor.getTreasuryAddress(),
+      new anchor.BN(1_000_000_000_000)
+    );
+    const user = await MockUser.create({
+      provider,
+      governor,
+    });
+    await nft.mintTo(user.wallet, new anchor.BN(1));
+    await nft.createMasterEdition();
+
+    const allowlist = new MintAllowlistTree([
+      { mint: anchor.web3.Keypair.generate().publicKey },
+      { mint: nft.mint.address, weightBps: 5_000 },
+      { mint: anchor.web3.Keypair.generate().publicKey },
+    ]);
+    await governor.addMerkleProof(allowlist.root);
+
+    await user.lockNft({ duration: new anchor.BN(20), nft, allowlist });
+
+    const rewardAmount = await governor.calcRewardAmountAt();
+    const [escrow, receipts] = await Promise.all([
+      user.fetchEscrow(),
+      user.fetchReceipts(),
+    ]);
+    const receipt = receipts[0].account;
+
+    checkBN(escrow.amount, rewardAmount.divn(2), "escrow.amount");
+    assert.strictEqual(receipt.rewardBps, 5_000);
+    assert.strictEqual(receipt.proofType, constants.PROOF_TYPE_MERKLE);
+    assert.ok(
+      receipt.proof.equals(
+        await governor.getProofAddress(
+          new anchor.web3.PublicKey(allowlist.root)
+        )
+      )
+    );
+  });
+
+  it("user vests NFT rewards in the reward mint", async () => {
+    const rewardMint = await MockMint.create(provider, 6);
+    await governor.setRewardMint(rewardMint);
+    await governor.initTreasury(rewardMint);
+    await rewardMint.mintToAddress(
+      await governor.getTreasuryAddress(rewardMint.address),
+      new anchor.BN(1_000_000_000_000)
+    );
+
+    const user = await MockUser.create({
+      provider,
+      governor,
+    });
+    await nft.mintTo(user.wallet, new anchor.BN(1));
+    await nft.createMasterEdition();
+    await governor.addProof(
+      new anchor.web3.PublicKey(nft.metadata.data.data.creators.at(0).address)
+    );
+
+    await user.lockNft({ duration: new anchor.BN(20), nft });
+
+    const rewardAmount = await governor.calcRewardAmountAt();
+    let [locker, escrow, receipts, rewardTokens] = await Promise.all([
+      governor.fetchLocker(),
+      user.fetchEscrow(),
+      user.fetchReceipts(),
+      rewardMint.getAssociatedTokenAccount(user.escrow),
+    ]);
+
+    checkBN(locker.lockedSupply, new anchor.BN(0), "locker.lockedSupply");
+    checkBN(escrow.amount, new anchor.BN(0), "escrow.amount");
+    checkBN(
+      escrow.rewardAmountToReceipt,
+      rewardAmount,
+      "escrow.rewardAmountToReceipt"
+    );
+    checkBN(rewardTokens.amount, rewardAmount, "rewardTokens.amount");
+    assert.ok(receipts[0].account.rewardMint.equals(rewardMint.address));
+
+    await sleep(21000);
+
+    await user.claimNftReward(new anchor.BN(0), rewardMint);
+
+    let userRewardTokens;
+    [escrow, rewardTokens, userRewardTokens] = await Promise.all([
+      user.fetchEscrow(),
+      rewardMint.getAssociatedTokenAccount(user.escrow),
+      rewardMint.getAssociatedTokenAccount(user.wallet.publicKey),
+    ]);
+
+    checkBN(
+      escrow.rewardAmountToReceipt,
+      new anchor.BN(0),
+      "escrow.rewardAmountToReceipt"
+    );
+    checkBN(rewardTokens.amount, new anchor.BN(0), "rewardTokens.amount");
+    checkBN(userRewardTokens.amount, rewardAmount, "userRewardTokens.amount");
+  });
+
+  it("treasury keeps the rewards reserved by a campaign", async () => {
+    const [funder, treasuryAuthority] = await Promise.all([
+      MockWallet.createWithBalance(provider, 1),
+      MockWallet.createWithBalance(provider, 1),
+    ]);
+    const amount = new anchor.BN(1_000_000);
+    const rewardBudget = amount.divn(4);
+    const treasuryAddress = await governor.getTreasuryAddress();
+
+    await tokenMint.mintTo(funder, amount);
+    await governor.fundTreasury(funder, amount);
+    checkBN(
+      (await tokenMint.getTokenAccount(treasuryAddress)).amount,
+      amount,
+      "treasury.amount"
+    );
+
+    const now = Math.floor(Date.now() / 1000);
+    await governor.openCampaign({
+      startsAt: new anchor.BN(now - 60),
+      endsAt: new anchor.BN(now + 3600),
+      rewardBudget,
+      collectionBurnCap: new anchor.BN(5),
+      escrowBurnCap: null,
+    });
+    checkBN(
+      (await governor.fetchLocker()).reservedRewards,
+      rewardBudget,
+      "locker.reservedRewards"
+    );
+
+    await governor.setTreasuryAuthority(treasuryAuthority.publicKey);
+    const destination = (
+      await tokenMint.getOrCreateAssociatedTokenAccount(
+        treasuryAuthority.publicKey
+      )
+    ).address;
+
+    const withdrawReserved = governor.withdrawTreasury(
+      treasuryAuthority,
+      destination,
+      amount
+    );
+    await expect(withdrawReserved).to.eventually.be.rejectedWith(
+      'failed ({"err":{"InstructionError":[0,{"Custom":7125}]}})'
+    );
+
+    await governor.withdrawTreasury(
+      treasuryAuthority,
+      destination,
+      amount.sub(rewardBudget)
+    );
+    checkBN(
+      (await tokenMint.getTokenAccount(treasuryAddress)).amount,
+      rewardBudget,
+      "treasury.amount"
+    );
+
+    await governor.closeCampaign();
+    checkBN(
+      (await governor.fetchLocker()).reservedRewards,
+      new anchor.BN(0),
+      "locker.reservedRewards"
+    );
+  });
+
+  it("user can lock NFT into a shorter vest tier", async () => {
+    await tokenMint.mintToAddress(
+      await governor.getTreasuryAddress(),
+      new anchor.BN(1_000_000_000_000)
+    );
+    await governor.setVestTiers([
+      {
+        vestDuration: new anchor.BN(10),
+        escrowDuration: new anchor.BN(2),
+        rewardBps: 5_000,
+      },
+    ]);
+    const user = await MockUser.create({
+      provider,
+      governor,
+    });
+    await nft.mintTo(user.wallet, new anchor.BN(1));
+    await nft.createMasterEdition();
+    await governor.addProof(
+      new anchor.web3.PublicKey(nft.metadata.data.data.creators.at(0).address)
+    );
+
+    const lockWithFail = user.lockNft({
+      duration: new anchor.BN(10),
+      nft,
+      vestTier: 1,
+    });
+    await expect(lockWithFail).to.eventually.be.rejectedWith(
+      'failed ({"err":{"InstructionError":[2,{"Custom":7027}]}})'
+    );
+
+    await user.lockNft({ duration: new anchor.BN(10), nft, vestTier: 0 });
+
+    const rewardAmount = await governor.calcRewardAmountAt();
+    const [escrow, receipts] = await Promise.all([
+      user.fetchEscrow(),
+      user.fetchReceipts(),
+    ]);
+    const receipt = receipts[0].account;
+
+    checkBN(escrow.amount, rewardAmount.divn(2), "escrow.amount");
+    checkBN(
+      escrow.escrowEndsAt.sub(escrow.escrowStartedAt),
+      new anchor.BN(2),
+      "escrow duration"
+    );
+    checkBN(
+      receipt.vestEndsAt.sub(receipt.vestStartedAt),
+      new anchor.BN(10),
+      "vest duration"
+    );
+    assert.strictEqual(receipt.rewardBps, 5_000);
+    assert.strictEqual(receipt.vestTier.rewardBps, 5_000);
+
+    // custodial locks take a vest tier too
+    const custodialNft = await MockNFT.create(provider);
+    await custodialNft.mintTo(user.wallet, new anchor.BN(1));
+    await custodialNft.createMasterEdition();
+    await governor.addProof(
+      new anchor.web3.PublicKey(
+        custodialNft.metadata.data.data.creators.at(0).address
+      )
+    );
+    await user.lockNftCustodial({
+      duration: new anchor.BN(10),
+      nft: custodialNft,
+      vestTier: 0,
+    });
+
+    const custodialReceipt = await user.fetchReceipt(new anchor.BN(1));
+    checkBN(
+      custodialReceipt.vestEndsAt.sub(custodialReceipt.vestStartedAt),
+      new anchor.BN(10),
+      "custodial vest duration"
+    );
+    assert.strictEqual(custodialReceipt.vestTier.rewardBps, 5_000);
+  });
+
+  it("user can redeem WL tokens against an offer", async () => {
+    await tokenMint.mintToAddress(
+      await governor.getTreasuryAddress(),
+      new anchor.BN(1_000_000_000_000)
+    );
+    const user = await MockUser.create({
+      provider,
+      governor,
+    });
+    await nft.mintTo(user.wallet, new anchor.BN(1));
+    await nft.createMasterEdition();
+    await governor.addProof(
+      new anchor.web3.PublicKey(nft.metadata.data.data.creators.at(0).address)
+    );
+    await user.lockNft({ duration: new anchor.BN(20), nft });
+
+    const offerId = new anchor.BN(0);
+    await governor.registerWlOffer(offerId, {
+      kind: {
+        allowlistSlot: { project: anchor.web3.Keypair.generate().publicKey },
+      },
+      wlCost: new anchor.BN(1),
+      maxUnits: new anchor.BN(0),
+      maxUnitsPerUser: new anchor.BN(1),
+      expiresAt: new anchor.BN(0),
+    });
+
+    await user.redeemWl(offerId, new anchor.BN(1));
+
+    const [offer, redemption, userWlTokens] = await Promise.all([
+      governor.fetchWlOffer(offerId),
+      user.fetchWlRedemption(offerId),
+      wlTokenMint.getAssociatedTokenAccount(user.wallet.publicKey),
+    ]);
+
+    checkBN(offer.redeemedUnits, new anchor.BN(1), "offer.redeemedUnits");
+    checkBN(redemption.redeemedUnits, new anchor.BN(1), "redeemedUnits");
+    checkBN(redemption.wlBurned, new anchor.BN(1), "wlBurned");
+    checkBN(userWlTokens.amount, new anchor.BN(0), "userWlTokens.amount");
+
+    const redeemWithFail = user.redeemWl(offerId, new anchor.BN(1));
+    await expect(redeemWithFail).to.eventually.be.rejectedWith(
+      'failed ({"err":{"InstructionError":[0,{"Custom":7130}]}})'
+    );
+  });
+
+  it("user can transfer escrow to a new wallet", async () => {
+    await tokenMint.mintToAddress(
+      await governor.getTreasuryAddress(),
+      new anchor.BN(1_000_000_000_000)
+    );
+    const [user, newUser] = await Promise.all([
+      MockUser.create({ provider, governor }),
+      MockUser.create({ provider, governor }),
+    ]);
+    await nft.mintTo(user.wallet, new anchor.BN(1));
+    await nft.createMasterEdition();
+    await governor.addProof(
+      new anchor.web3.PublicKey(nft.metadata.data.data.creators.at(0).address)
+    );
+    await user.lockNft({ duration: new anchor.BN(20), nft });
+    const prevEscrow = await user.fetchEscrow();
+
+    await user.proposeEscrowTransfer(newUser.wallet.publicKey);
+    await newUser.acceptEscrowTransfer(user);
+
+    const [oldEscrow, escrow, lockedTokens, receipt] = await Promise.all([
+      user.fetchEscrow(),
+      newUser.fetchEscrow(),
+      tokenMint.getAssociatedTokenAccount(newUser.escrow),
+      user.fetchReceipt(new anchor.BN(0)),
+    ]);
+
+    assert.strictEqual(oldEscrow, null);
+    assert.ok(escrow.owner.equals(newUser.wallet.publicKey));
+    assert.ok(escrow.voteDelegate.equals(newUser.wallet.publicKey));
+    checkBN(escrow.amount, prevEscrow.amount, "escrow.amount");
+    checkBN(lockedTokens.amount, prevEscrow.amount, "lockedTokens.amount");
+    checkBN(escrow.escrowEndsAt, prevEscrow.escrowEndsAt, "escrowEndsAt");
+    checkBN(escrow.receiptCount, prevEscrow.receiptCount, "receiptCount");
+    assert.ok(receipt.owner.equals(newUser.wallet.publicKey));
+  });
+
+  it("escrow transfer moves the rewards held in the reward mint", async () => {
+    const rewardMint = await MockMint.create(provider, 6);
+    await governor.setRewardMint(rewardMint);
+    await governor.initTreasury(rewardMint);
+    await rewardMint.mintToAddress(
+      await governor.getTreasuryAddress(rewardMint.address),
+      new anchor.BN(1_000_000_000_000)
+    );
+    const [user, newUser] = await Promise.all([
+      MockUser.create({ provider, governor }),
+      MockUser.create({ provider, governor }),
+    ]);
+    await nft.mintTo(user.wallet, new anchor.BN(1));
+    await nft.createMasterEdition();
+    await governor.addProof(
+      new anchor.web3.PublicKey(nft.metadata.data.data.creators.at(0).address)
+    );
+    await user.lockNft({ duration: new anchor.BN(20), nft });
+    const rewardAmount = await governor.calcRewardAmountAt();
+
+    await user.proposeEscrowTransfer(newUser.wallet.publicKey);
+    await newUser.acceptEscrowTransfer(user);
+
+    const [escrow, rewardTokens, oldRewardTokens] = await Promise.all([
+      newUser.fetchEscrow(),
+      rewardMint.getAssociatedTokenAccount(newUser.escrow),
+      rewardMint.tryGetAssociatedTokenAccount(user.escrow),
+    ]);
+
+    checkBN(
+      escrow.rewardAmountToReceipt,
+      rewardAmount,
+      "escrow.rewardAmountToReceipt"
+    );
+    checkBN(rewardTokens.amount, rewardAmount, "rewardTokens.amount");
+    assert.strictEqual(oldRewardTokens, null);
+
+    await sleep(21000);
+
+    await newUser.claimNftReward(new anchor.BN(0), rewardMint);
+
+    const userRewardTokens = await rewardMint.getAssociatedTokenAccount(
+      newUser.wallet.publicKey
+    );
+    checkBN(userRewardTokens.amount, rewardAmount, "userRewardTokens.amount");
+  });
+
+  it("escrow can be transferred twice and keep its old receipts", async () => {
+    await tokenMint.mintToAddress(
+      await governor.getTreasuryAddress(),
+      new anchor.BN(1_000_000_000_000)
+    );
+    const [user, secondUser, thirdUser] = await Promise.all([
+      MockUser.create({ provider, governor }),
+      MockUser.create({ provider, governor }),
+      MockUser.create({ provider, governor }),
+    ]);
+    await nft.mintTo(user.wallet, new anchor.BN(1));
+    await nft.createMasterEdition();
+    await governor.addProof(
+      new anchor.web3.PublicKey(nft.metadata.data.data.creators.at(0).address)
+    );
+    await user.lockNft({ duration: new anchor.BN(20), nft });
+
+    await user.proposeEscrowTransfer(secondUser.wallet.publicKey);
+    await secondUser.acceptEscrowTransfer(user);
+
+    // the receipt keeps the address of the first owner, and must be given
+    await secondUser.proposeEscrowTransfer(thirdUser.wallet.publicKey);
+    const acceptWithFail = thirdUser.acceptEscrowTransfer(secondUser, []);
+    await expect(acceptWithFail).to.eventually.be.rejectedWith(
+      'failed ({"err":{"InstructionError":[1,{"Custom":7007}]}})'
+    );
+    await thirdUser.acceptEscrowTransfer(secondUser);
+
+    const receiptAddress = await user.getReceiptAddress(new anchor.BN(0));
+    let receipts = await thirdUser.fetchOwnedReceipts();
+    assert.strictEqual(receipts.length, 1);
+    assert.ok(receipts[0].publicKey.equals(receiptAddress));
+
+    await sleep(2000);
+
+    await thirdUser.claimNftReward(new anchor.BN(0));
+
+    receipts = await thirdUser.fetchOwnedReceipts();
+    assert.ok(receipts[0].account.claimedAmount.gtn(0));
+  });
+
+  it("approved operator can relock for the owner", async () => {
+    await tokenMint.mintToAddress(
+      await governor.getTreasuryAddress(),
+      new anchor.BN(1_000_000_000_000)
+    );
+    const user = await MockUser.create({
+      provider,
+      governor,
+    });
+    const operator = await MockWallet.createWithBalance(provider, 1);
+    await nft.mintTo(user.wallet, new anchor.BN(1));
+    await nft.createMasterEdition();
+    await governor.addProof(
+      new anchor.web3.PublicKey(nft.metadata.data.data.creators.at(0).address)
+    );
+    await user.lockNft({ duration: new anchor.BN(20), nft });
+
+    const approveWithFail = user.approveOperator(
+      operator.publicKey,
+      constants.OPERATOR_ACTION_RELOCK,
+      new anchor.BN(1)
+    );
+    await expect(approveWithFail).to.eventually.be.rejectedWith(
+      'failed ({"err":{"InstructionError":[0,{"Custom":7133}]}})'
+    );
+
+    await user.approveOperator(
+      operator.publicKey,
+      constants.OPERATOR_ACTION_RELOCK
+    );
+
+    const extendWithFail = user.extendLock(new anchor.BN(4), operator);
+    await expect(extendWithFail).to.eventually.be.rejectedWith(
+      'failed ({"err":{"InstructionError":[0,{"Custom":7132}]}})'
+    );
+
+    await sleep(2000);
+
+    await user.claimAndRelock(new anchor.BN(0), new anchor.BN(4), operator);
+
+    const receipt = await user.fetchReceipt(new anchor.BN(0));
+    assert.ok(receipt.claimedAmount.gtn(0));
+
+    await user.revokeOperator(operator.publicKey);
+
+    const relockWithFail = user.claimAndRelock(
+      new anchor.BN(0),
+      new anchor.BN(4),
+      operator
+    );
+    await expect(relockWithFail).to.eventually.be.rejectedWith(
+      'failed ({"err":{"InstructionError":[0,{"Custom":7132}]}})'
+    );
+  });
+
+  it("approved operator can claim to the owner", async () => {
+    await tokenMint.mintToAddress(
+      await governor.getTreasuryAddress(),
+      new anchor.BN(1_000_000_000_000)
+    );
+    const user = await MockUser.create({
+      provider,
+      governor,
+    });
+    const operator = await MockWallet.createWithBalance(provider, 1);
+    await nft.mintTo(user.wallet, new anchor.BN(1));
+    await nft.createMasterEdition();
+    await governor.addProof(
+      new anchor.web3.PublicKey(nft.metadata.data.data.creators.at(0).address)
+    );
+    await user.lockNft({ duration: new anchor.BN(20), nft });
+    await user.approveOperator(
+      operator.publicKey,
+      constants.OPERATOR_ACTION_CLAIM
+    );
+
+    await sleep(2000);
+
+    await user.claimNftReward(new anchor.BN(0), tokenMint, operator);
+
+    const receipt = await user.fetchReceipt(new anchor.BN(0));
+    assert.ok(receipt.claimedAmount.gtn(0));
+
+    const destination = await tokenMint.tryGetAssociatedTokenAccount(
+      user.wallet.publicKey
+    );
+    checkTokenAccount({
+      account: destination,
+      mint: tokenMint.address,
+      amount: receipt.claimedAmount,
+    });
+  });
+
+  it("user cannot lock NFT when the treasury runs short", async () => {
+    const user = await MockUser.create({
+      provider,
+      governor,
+    });
+    await nft.mintTo(user.wallet, new anchor.BN(1));
+    await nft.createMasterEdition();
+    await governor.addProof(
+      new anchor.web3.PublicKey(nft.metadata.data.data.creators.at(0).address)
+    );
+
+    const lockWithFail = user.lockNft({ duration: new anchor.BN(20), nft });
+    await expect(lockWithFail).to.eventually.be.rejectedWith(
+      'failed ({"err":{"InstructionError":[2,{"Custom":7125}]}})'
     );
   });
 });
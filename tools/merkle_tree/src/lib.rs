@@ -0,0 +1,163 @@
+//! Off-chain builder of [merkle_distributor] trees.
+//!
+//! Claims are read from a CSV with a `claimant,amount,duration` header, the index of
+//! each claim being its position in the file.
+
+use anchor_lang::prelude::Pubkey;
+use merkle_distributor::merkle_proof::{self, leaf_hash};
+use std::fmt::Write;
+use std::str::FromStr;
+
+/// A leaf of the distribution tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Claim {
+    pub index: u64,
+    pub claimant: Pubkey,
+    pub amount: u64,
+    pub duration: i64,
+}
+
+impl Claim {
+    pub fn hash(&self) -> [u8; 32] {
+        leaf_hash(self.index, &self.claimant, self.amount, self.duration)
+    }
+}
+
+/// Parses the claims of a CSV file.
+pub fn parse_csv(input: &str) -> Result<Vec<Claim>, String> {
+    let mut lines = input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+
+    match lines.next() {
+        Some((_, header)) if split(header) == ["claimant", "amount", "duration"] => {}
+        _ => return Err("expected a `claimant,amount,duration` header".to_string()),
+    }
+
+    lines
+        .enumerate()
+        .map(|(index, (line_number, line))| {
+            let error = |field: &str| format!("line {}: invalid {}", line_number + 1, field);
+            match split(line)[..] {
+                [claimant, amount, duration] => Ok(Claim {
+                    index: index as u64,
+                    claimant: Pubkey::from_str(claimant).map_err(|_| error("claimant"))?,
+                    amount: amount.parse().map_err(|_| error("amount"))?,
+                    duration: duration.parse().map_err(|_| error("duration"))?,
+                }),
+                _ => Err(format!("line {}: expected 3 fields", line_number + 1)),
+            }
+        })
+        .collect()
+}
+
+fn split(line: &str) -> Vec<&str> {
+    line.split(',').map(str::trim).collect()
+}
+
+/// Merkle tree of the claims, hashed the same way [merkle_distributor] verifies proofs.
+pub struct MerkleTree {
+    pub claims: Vec<Claim>,
+    tree: merkle_proof::MerkleTree,
+}
+
+impl MerkleTree {
+    pub fn new(claims: Vec<Claim>) -> Result<Self, String> {
+        if claims.is_empty() {
+            return Err("no claims".to_string());
+        }
+
+        let tree = merkle_proof::MerkleTree::new(claims.iter().map(Claim::hash).collect());
+
+        Ok(Self { claims, tree })
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.tree.root()
+    }
+
+    pub fn max_total_claim(&self) -> u64 {
+        self.claims.iter().map(|claim| claim.amount).sum()
+    }
+
+    /// Sibling hashes from the leaf at `index` up to the root.
+    pub fn proof(&self, index: usize) -> Vec<[u8; 32]> {
+        self.tree.proof(index)
+    }
+
+    /// Serializes the root and the proof of every claim as JSON.
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        writeln!(json, "{{").unwrap();
+        writeln!(json, "  \"root\": {},", hash_json(&self.root())).unwrap();
+        writeln!(json, "  \"maxNumNodes\": {},", self.claims.len()).unwrap();
+        writeln!(json, "  \"maxTotalClaim\": \"{}\",", self.max_total_claim()).unwrap();
+        writeln!(json, "  \"claims\": [").unwrap();
+        for (i, claim) in self.claims.iter().enumerate() {
+            let proof = self
+                .proof(i)
+                .iter()
+                .map(hash_json)
+                .collect::<Vec<_>>()
+                .join(", ");
+            write!(
+                json,
+                "    {{ \"index\": {}, \"claimant\": \"{}\", \"amount\": \"{}\", \"duration\": \"{}\", \"proof\": [{}] }}",
+                claim.index, claim.claimant, claim.amount, claim.duration, proof
+            )
+            .unwrap();
+            writeln!(json, "{}", if i + 1 < self.claims.len() { "," } else { "" }).unwrap();
+        }
+        writeln!(json, "  ]").unwrap();
+        write!(json, "}}").unwrap();
+        json
+    }
+}
+
+fn hash_json(hash: &[u8; 32]) -> String {
+    format!(
+        "[{}]",
+        hash.iter()
+            .map(u8::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_claim_verifies_on_chain() {
+        let claimants: Vec<_> = (0..7).map(|_| Pubkey::new_unique()).collect();
+        let csv = claimants.iter().enumerate().fold(
+            "claimant,amount,duration\n".to_string(),
+            |csv, (i, claimant)| {
+                csv + &format!("{},{},{}\n", claimant, 1_000 * (i + 1), 86_400 * i)
+            },
+        );
+        let tree = MerkleTree::new(parse_csv(&csv).unwrap()).unwrap();
+
+        for (i, claim) in tree.claims.iter().enumerate() {
+            let proof = tree.proof(i);
+
+            assert!(merkle_proof::verify(
+                &proof,
+                &tree.root(),
+                leaf_hash(claim.index, &claim.claimant, claim.amount, claim.duration)
+            ));
+            assert!(!merkle_proof::verify(
+                &proof,
+                &tree.root(),
+                leaf_hash(
+                    claim.index,
+                    &claim.claimant,
+                    claim.amount + 1,
+                    claim.duration
+                )
+            ));
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use merkle_tree::{parse_csv, MerkleTree};
+use std::{env, fs, process};
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 3 {
+        eprintln!("usage: {} <claims.csv> <output.json>", args[0]);
+        process::exit(1);
+    }
+
+    let tree = fs::read_to_string(&args[1])
+        .map_err(|e| e.to_string())
+        .and_then(|input| parse_csv(&input))
+        .and_then(MerkleTree::new)
+        .unwrap_or_else(|e| {
+            eprintln!("{}: {}", args[1], e);
+            process::exit(1);
+        });
+
+    if let Err(e) = fs::write(&args[2], tree.to_json()) {
+        eprintln!("{}: {}", args[2], e);
+        process::exit(1);
+    }
+
+    println!("root: {:?}", tree.root());
+    println!("max num nodes: {}", tree.claims.len());
+    println!("max total claim: {}", tree.max_total_claim());
+}